@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use age::x25519::{Identity, Recipient};
+
+use crate::BackupError;
+
+/// Encrypts `plaintext` to `recipient` (an `age1...` public key), producing
+/// an age-encrypted payload. Used to seal a backup manifest (or, once a
+/// real archive pipeline exists, the archive itself) before it's written
+/// to shared storage.
+pub fn encrypt(plaintext: &[u8], recipient: &str) -> Result<Vec<u8>, BackupError> {
+    let recipient = Recipient::from_str(recipient)
+        .map_err(|err| BackupError::InvalidRecipient(err.to_string()))?;
+    age::encrypt(&recipient, plaintext)
+        .map_err(|err| BackupError::Encryption(err.to_string()))
+}
+
+/// Decrypts an age-encrypted payload with `identity` (an
+/// `AGE-SECRET-KEY-1...` private key), the inverse of [`encrypt`].
+pub fn decrypt(ciphertext: &[u8], identity: &str) -> Result<Vec<u8>, BackupError> {
+    let identity = Identity::from_str(identity)
+        .map_err(|err| BackupError::InvalidIdentity(err.to_string()))?;
+    age::decrypt(&identity, ciphertext).map_err(|err| BackupError::Decryption(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use age::secrecy::ExposeSecret;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_plaintext_through_encrypt_and_decrypt() {
+        let identity = Identity::generate();
+        let recipient = identity.to_public().to_string();
+
+        let ciphertext = encrypt(b"sensitive config", &recipient).unwrap();
+        let plaintext = decrypt(&ciphertext, identity.to_string().expose_secret()).unwrap();
+
+        assert_eq!(plaintext, b"sensitive config");
+    }
+
+    #[test]
+    fn rejects_a_malformed_recipient() {
+        assert!(matches!(
+            encrypt(b"data", "not-a-recipient"),
+            Err(BackupError::InvalidRecipient(_))
+        ));
+    }
+
+    #[test]
+    fn fails_to_decrypt_with_the_wrong_identity() {
+        let recipient = Identity::generate().to_public().to_string();
+        let ciphertext = encrypt(b"data", &recipient).unwrap();
+
+        let wrong_identity = Identity::generate().to_string();
+        assert!(matches!(
+            decrypt(&ciphertext, wrong_identity.expose_secret()),
+            Err(BackupError::Decryption(_))
+        ));
+    }
+}
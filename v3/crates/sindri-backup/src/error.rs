@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BackupError {
+    #[error("backup manifest not found at {0}")]
+    ManifestMissing(PathBuf),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Extension(#[from] sindri_extensions::ExtensionError),
+
+    #[error("restore destination {0} is not writable: {1}")]
+    NotWritable(PathBuf, #[source] std::io::Error),
+
+    #[error("not enough free space to restore: need {needed} bytes, have {available} bytes")]
+    InsufficientSpace { needed: u64, available: u64 },
+
+    #[error("backup manifest at {0} is encrypted; pass the matching identity to decrypt it")]
+    ManifestEncrypted(PathBuf),
+
+    #[error("invalid age recipient: {0}")]
+    InvalidRecipient(String),
+
+    #[error("invalid age identity: {0}")]
+    InvalidIdentity(String),
+
+    #[error("failed to encrypt backup manifest: {0}")]
+    Encryption(String),
+
+    #[error("failed to decrypt backup manifest: {0}")]
+    Decryption(String),
+
+    #[error(transparent)]
+    Provider(#[from] sindri_providers::ProviderError),
+}
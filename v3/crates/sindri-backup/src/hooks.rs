@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// A single post-restore command, e.g. `mise install` parsed into
+/// `{ command: "mise", args: ["install"] }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hook {
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+impl Hook {
+    /// The command as a single display string, e.g. for logging or a
+    /// dry-run listing.
+    pub fn label(&self) -> String {
+        if self.args.is_empty() {
+            self.command.clone()
+        } else {
+            format!("{} {}", self.command, self.args.join(" "))
+        }
+    }
+}
+
+/// Hooks to run after a successful restore, and the environment they run
+/// with. Hooks never roll back an already-committed restore — a failure
+/// is reported in [`PostRestoreReport::failed`], not propagated as an
+/// error.
+#[derive(Debug, Clone, Default)]
+pub struct RestoreOptions {
+    pub hooks: Vec<Hook>,
+    pub env: BTreeMap<String, String>,
+}
+
+/// Outcome of [`run_post_restore_hooks`]. `planned` always lists every
+/// hook in order, even in a dry run where nothing actually ran.
+#[derive(Debug, Clone, Default)]
+pub struct PostRestoreReport {
+    pub planned: Vec<String>,
+    pub succeeded: Vec<String>,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Runs `options.hooks` in order inside `dest`, streaming each hook's
+/// stdout/stderr directly rather than capturing it. A hook that fails
+/// (nonzero exit or fails to start) is recorded in the report and the
+/// remaining hooks still run — restore already committed, so there's
+/// nothing to roll back. With `dry_run: true`, hooks are listed in
+/// [`PostRestoreReport::planned`] but none are actually run.
+pub fn run_post_restore_hooks(
+    dest: &Path,
+    options: &RestoreOptions,
+    dry_run: bool,
+) -> PostRestoreReport {
+    let mut report = PostRestoreReport {
+        planned: options.hooks.iter().map(Hook::label).collect(),
+        ..PostRestoreReport::default()
+    };
+
+    if dry_run {
+        return report;
+    }
+
+    for hook in &options.hooks {
+        let label = hook.label();
+        tracing::info!(hook = %label, "running post-restore hook");
+
+        let status = Command::new(&hook.command)
+            .args(&hook.args)
+            .current_dir(dest)
+            .envs(&options.env)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status();
+
+        match status {
+            Ok(status) if status.success() => report.succeeded.push(label),
+            Ok(status) => {
+                tracing::warn!(hook = %label, %status, "post-restore hook failed");
+                report.failed.push((label, format!("exited with {status}")));
+            }
+            Err(err) => {
+                tracing::warn!(hook = %label, %err, "post-restore hook failed to start");
+                report.failed.push((label, err.to_string()));
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dry_run_lists_hooks_without_running_them() {
+        let options = RestoreOptions {
+            hooks: vec![Hook { command: "false".to_string(), args: Vec::new() }],
+            env: BTreeMap::new(),
+        };
+        let report = run_post_restore_hooks(Path::new("."), &options, true);
+
+        assert_eq!(report.planned, vec!["false".to_string()]);
+        assert!(report.succeeded.is_empty());
+        assert!(report.failed.is_empty());
+    }
+
+    #[test]
+    fn a_failing_hook_is_reported_and_later_hooks_still_run() {
+        let options = RestoreOptions {
+            hooks: vec![
+                Hook { command: "false".to_string(), args: Vec::new() },
+                Hook { command: "true".to_string(), args: Vec::new() },
+            ],
+            env: BTreeMap::new(),
+        };
+        let report = run_post_restore_hooks(Path::new("."), &options, false);
+
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "false");
+        assert_eq!(report.succeeded, vec!["true".to_string()]);
+    }
+}
@@ -0,0 +1,30 @@
+//! Backup and restore operations.
+
+mod encryption;
+mod error;
+mod hooks;
+mod lifecycle;
+mod manager;
+mod manifest;
+mod preconditions;
+mod profile;
+mod progress;
+mod remote;
+mod restore;
+mod rows;
+
+pub use encryption::{decrypt, encrypt};
+pub use error::BackupError;
+pub use hooks::{run_post_restore_hooks, Hook, PostRestoreReport, RestoreOptions};
+pub use lifecycle::{list_backups, prune_backups, BackupEntry, PruneBackupsReport, RetentionWindow};
+pub use manager::{ExtensionReinstall, RestoreHooks, RestoreManager, RestoreOutcome};
+pub use manifest::{
+    read_manifest, read_manifest_with_identity, write_manifest, write_manifest_encrypted,
+    BackupManifest, ExtensionInfo, ENCRYPTED_MANIFEST_SUFFIX, MANIFEST_FILE,
+};
+pub use preconditions::validate_restore_preconditions;
+pub use profile::{BackupProfile, EffectivePatterns, ALWAYS_EXCLUDE, NEVER_RESTORE};
+pub use progress::{RestoreProgress, RestoreStage, TracingRestoreProgress};
+pub use remote::{backup_to_provider, restore_from_provider};
+pub use restore::{reinstall_extensions, BackupExtensionSource, ExtensionSource, ReinstallReport};
+pub use rows::BackupRow;
@@ -0,0 +1,195 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{read_manifest, BackupError, BackupManifest};
+
+/// A backup directory paired with its parsed manifest.
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    pub dir: PathBuf,
+    pub manifest: BackupManifest,
+}
+
+/// Lists every immediate subdirectory of `root` that holds a valid backup
+/// manifest, oldest first. Directories with no manifest (or anything else
+/// that isn't a backup) are skipped rather than treated as an error.
+pub fn list_backups(root: &Path) -> Result<Vec<BackupEntry>, BackupError> {
+    let mut entries = Vec::new();
+    let read_dir = match fs::read_dir(root) {
+        Ok(read_dir) => read_dir,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(entries),
+        Err(source) => return Err(BackupError::Io(source)),
+    };
+
+    for item in read_dir {
+        let dir = item?.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        match read_manifest(&dir) {
+            Ok(manifest) => entries.push(BackupEntry { dir, manifest }),
+            Err(BackupError::ManifestMissing(_)) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    entries.sort_by_key(|entry| entry.manifest.created_at);
+    Ok(entries)
+}
+
+/// Retention rule applied by [`prune_backups`]: a backup is deleted if it
+/// falls outside `[since, until]` (whichever bounds are set) or if it isn't
+/// among the `keep_last` most recent backups.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetentionWindow {
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub keep_last: Option<usize>,
+}
+
+/// Outcome of [`prune_backups`]. In a dry run, `removed` lists what *would*
+/// be deleted without deleting anything.
+#[derive(Debug, Default, Clone)]
+pub struct PruneBackupsReport {
+    pub removed: Vec<PathBuf>,
+    pub freed_bytes: u64,
+}
+
+/// Deletes backups under `root` that fall outside `window`, reading each
+/// manifest's `created_at` rather than trusting directory names or mtimes.
+/// With `dry_run: true`, reports what would be deleted without touching
+/// disk.
+pub fn prune_backups(
+    root: &Path,
+    window: &RetentionWindow,
+    dry_run: bool,
+) -> Result<PruneBackupsReport, BackupError> {
+    let mut entries = list_backups(root)?;
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.manifest.created_at));
+
+    let is_outside_window = |entry: &BackupEntry| {
+        window
+            .since
+            .is_some_and(|since| entry.manifest.created_at < since)
+            || window.until.is_some_and(|until| entry.manifest.created_at > until)
+    };
+
+    // `keep_last` only protects the most recent backups that are *already*
+    // inside the window — it never resurrects a backup the window alone
+    // would have dropped.
+    let in_window_rank: Vec<usize> = {
+        let mut rank = 0;
+        entries
+            .iter()
+            .map(|entry| {
+                if is_outside_window(entry) {
+                    usize::MAX
+                } else {
+                    let this_rank = rank;
+                    rank += 1;
+                    this_rank
+                }
+            })
+            .collect()
+    };
+
+    let mut report = PruneBackupsReport::default();
+    for (entry, rank) in entries.iter().zip(in_window_rank) {
+        let outside_window = rank == usize::MAX;
+        let beyond_retained_count = !outside_window && window.keep_last.is_some_and(|keep| rank >= keep);
+
+        if !outside_window && !beyond_retained_count {
+            continue;
+        }
+
+        report.freed_bytes += entry.manifest.uncompressed_bytes;
+        report.removed.push(entry.dir.clone());
+        if !dry_run {
+            fs::remove_dir_all(&entry.dir)?;
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{write_manifest, ExtensionInfo};
+
+    fn make_backup(root: &Path, name: &str, created_at: u64) -> PathBuf {
+        let dir = root.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        write_manifest(
+            &dir,
+            &BackupManifest {
+                created_at,
+                extensions: vec![ExtensionInfo {
+                    name: "foo".to_string(),
+                    version: "1.0.0".to_string(),
+                }],
+                uncompressed_bytes: 1024,
+                encrypted: false,
+                recipient: None,
+                effective_patterns: None,
+            },
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn lists_backups_oldest_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        make_backup(tmp.path(), "b", 200);
+        make_backup(tmp.path(), "a", 100);
+
+        let entries = list_backups(tmp.path()).unwrap();
+        let created_at: Vec<u64> = entries.iter().map(|e| e.manifest.created_at).collect();
+        assert_eq!(created_at, vec![100, 200]);
+    }
+
+    #[test]
+    fn prune_deletes_outside_window_and_beyond_count() {
+        let tmp = tempfile::tempdir().unwrap();
+        make_backup(tmp.path(), "too-old", 50);
+        make_backup(tmp.path(), "in-window-1", 150);
+        make_backup(tmp.path(), "in-window-2", 160);
+        make_backup(tmp.path(), "too-new", 500);
+
+        let window = RetentionWindow {
+            since: Some(100),
+            until: Some(400),
+            keep_last: Some(1),
+        };
+        let report = prune_backups(tmp.path(), &window, true).unwrap();
+
+        let mut removed_names: Vec<String> = report
+            .removed
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        removed_names.sort();
+
+        // "too-old"/"too-new" fall outside [since, until]; "in-window-1" is
+        // inside the window but isn't among the 1 most recent.
+        assert_eq!(removed_names, vec!["in-window-1", "too-new", "too-old"]);
+        assert!(tmp.path().join("in-window-2").is_dir());
+    }
+
+    #[test]
+    fn dry_run_reports_without_deleting() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = make_backup(tmp.path(), "only", 100);
+
+        let window = RetentionWindow {
+            since: Some(1000),
+            until: None,
+            keep_last: None,
+        };
+        let report = prune_backups(tmp.path(), &window, true).unwrap();
+
+        assert_eq!(report.removed, vec![dir.clone()]);
+        assert!(dir.is_dir());
+    }
+}
@@ -0,0 +1,206 @@
+use std::path::Path;
+
+use sindri_extensions::ExtensionDistributor;
+
+use crate::{
+    read_manifest, read_manifest_with_identity, reinstall_extensions, run_post_restore_hooks,
+    validate_restore_preconditions, BackupError, BackupExtensionSource, BackupManifest,
+    PostRestoreReport, ReinstallReport, RestoreOptions, RestoreProgress, RestoreStage,
+};
+
+/// Outcome of [`RestoreManager::restore`]. Each field is populated only if
+/// the corresponding stage actually ran.
+#[derive(Debug, Default)]
+pub struct RestoreOutcome {
+    pub manifest: Option<BackupManifest>,
+    pub extensions: Option<ReinstallReport>,
+    pub hooks: Option<PostRestoreReport>,
+}
+
+/// Post-restore hooks to run as part of [`RestoreManager::restore`], and
+/// where to run them.
+pub struct RestoreHooks<'a> {
+    pub dir: &'a Path,
+    pub options: &'a RestoreOptions,
+    pub dry_run: bool,
+}
+
+/// Extension reinstall behavior for [`RestoreManager::restore`]. Omit this
+/// (pass `None`) to skip the reinstall stage entirely.
+pub struct ExtensionReinstall<'a> {
+    pub extensions_dir: &'a Path,
+    /// How many extensions to reinstall at once.
+    pub parallelism: usize,
+    /// Reinstall an extension even if it's already at its manifest's
+    /// recorded version, instead of skipping it.
+    pub force: bool,
+}
+
+/// Coordinates a restore from the backup at `backup_dir`: reading its
+/// manifest, reinstalling extensions, and running post-restore hooks,
+/// reporting progress through a [`RestoreProgress`] sink as it goes.
+pub struct RestoreManager<'a> {
+    backup_dir: &'a Path,
+}
+
+impl<'a> RestoreManager<'a> {
+    pub fn new(backup_dir: &'a Path) -> Self {
+        Self { backup_dir }
+    }
+
+    /// Runs the restore, reporting to `progress` as each stage starts:
+    ///
+    /// 1. Read (and, if encrypted, decrypt with `identity`) the manifest.
+    /// 2. Validate restore preconditions, if `extensions` is given.
+    /// 3. Reinstall extensions per `extensions`, if given, up to its
+    ///    `parallelism` at a time. An extension already installed at its
+    ///    manifest-recorded version is skipped rather than reinstalled,
+    ///    unless `extensions.force` is set — so re-running a restore after
+    ///    a partial failure only redoes what didn't already succeed.
+    /// 4. Run `hooks`, if given and non-empty.
+    /// 5. Complete.
+    pub fn restore(
+        &self,
+        extensions: Option<ExtensionReinstall<'_>>,
+        identity: Option<&str>,
+        hooks: Option<RestoreHooks<'_>>,
+        progress: &mut dyn RestoreProgress,
+    ) -> Result<RestoreOutcome, BackupError> {
+        progress.stage(RestoreStage::ReadManifest);
+        let manifest = match (read_manifest(self.backup_dir), identity) {
+            (Ok(manifest), _) => manifest,
+            (Err(BackupError::ManifestEncrypted(_)), Some(identity)) => {
+                read_manifest_with_identity(self.backup_dir, identity)?
+            }
+            (Err(err), _) => return Err(err),
+        };
+
+        let mut outcome = RestoreOutcome::default();
+
+        if let Some(extensions) = extensions {
+            progress.stage(RestoreStage::ValidatePreconditions);
+            validate_restore_preconditions(&manifest, extensions.extensions_dir)?;
+
+            progress.stage(RestoreStage::ReinstallExtensions);
+            let distributor = ExtensionDistributor::new(extensions.extensions_dir);
+            let source = BackupExtensionSource::new(self.backup_dir);
+            let report = reinstall_extensions(
+                &manifest.extensions,
+                &distributor,
+                &source,
+                extensions.parallelism,
+                extensions.force,
+            );
+
+            for extension in report.installed.iter().chain(&report.skipped) {
+                progress.extension_restored(extension, true);
+            }
+            for extension in &report.unmatched {
+                progress.extension_restored(extension, false);
+            }
+
+            outcome.extensions = Some(report);
+        }
+
+        if let Some(hooks) = hooks.filter(|hooks| !hooks.options.hooks.is_empty()) {
+            progress.stage(RestoreStage::RunHooks);
+            outcome.hooks = Some(run_post_restore_hooks(hooks.dir, hooks.options, hooks.dry_run));
+        }
+
+        progress.stage(RestoreStage::Complete);
+        outcome.manifest = Some(manifest);
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{write_manifest, ExtensionInfo};
+
+    #[derive(Default)]
+    struct RecordingProgress {
+        stages: Vec<RestoreStage>,
+        extensions: Vec<(String, bool)>,
+    }
+
+    impl RestoreProgress for RecordingProgress {
+        fn stage(&mut self, stage: RestoreStage) {
+            self.stages.push(stage);
+        }
+
+        fn extension_restored(&mut self, extension: &ExtensionInfo, installed: bool) {
+            self.extensions.push((extension.name.clone(), installed));
+        }
+    }
+
+    fn manifest_with(extensions: Vec<ExtensionInfo>) -> BackupManifest {
+        BackupManifest {
+            created_at: 0,
+            extensions,
+            uncompressed_bytes: 0,
+            encrypted: false,
+            recipient: None,
+            effective_patterns: None,
+        }
+    }
+
+    #[test]
+    fn reports_read_manifest_and_complete_when_nothing_else_is_requested() {
+        let backup = tempfile::tempdir().unwrap();
+        write_manifest(backup.path(), &manifest_with(Vec::new())).unwrap();
+
+        let manager = RestoreManager::new(backup.path());
+        let mut progress = RecordingProgress::default();
+        let outcome = manager
+            .restore(None, None, None, &mut progress)
+            .unwrap();
+
+        assert_eq!(progress.stages, vec![RestoreStage::ReadManifest, RestoreStage::Complete]);
+        assert!(outcome.manifest.is_some());
+        assert!(outcome.extensions.is_none());
+        assert!(outcome.hooks.is_none());
+    }
+
+    #[test]
+    fn reinstalling_extensions_reports_each_outcome_to_progress() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+
+        let foo_dir = backup.path().join("extensions").join("foo").join("1.0.0");
+        std::fs::create_dir_all(&foo_dir).unwrap();
+        std::fs::write(foo_dir.join("bin"), b"contents").unwrap();
+
+        let manifest = manifest_with(vec![
+            ExtensionInfo { name: "foo".to_string(), version: "1.0.0".to_string() },
+            ExtensionInfo { name: "bar".to_string(), version: "9.9.9".to_string() },
+        ]);
+        write_manifest(backup.path(), &manifest).unwrap();
+
+        let manager = RestoreManager::new(backup.path());
+        let mut progress = RecordingProgress::default();
+        let outcome = manager
+            .restore(
+                Some(ExtensionReinstall { extensions_dir: extensions_root.path(), parallelism: 1, force: false }),
+                None,
+                None,
+                &mut progress,
+            )
+            .unwrap();
+
+        assert_eq!(
+            progress.stages,
+            vec![
+                RestoreStage::ReadManifest,
+                RestoreStage::ValidatePreconditions,
+                RestoreStage::ReinstallExtensions,
+                RestoreStage::Complete,
+            ]
+        );
+        assert_eq!(
+            progress.extensions,
+            vec![("foo".to_string(), true), ("bar".to_string(), false)]
+        );
+        assert_eq!(outcome.extensions.unwrap().installed.len(), 1);
+    }
+}
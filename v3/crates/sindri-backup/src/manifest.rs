@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{encryption, BackupError, EffectivePatterns};
+
+pub const MANIFEST_FILE: &str = "backup-manifest.json";
+
+/// Suffix appended to [`MANIFEST_FILE`] when it's encrypted with
+/// [`write_manifest_encrypted`], following age's own `.age` convention.
+pub const ENCRYPTED_MANIFEST_SUFFIX: &str = ".age";
+
+/// A single extension's name and active version, as recorded in the
+/// install ledger at backup time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtensionInfo {
+    pub name: String,
+    pub version: String,
+}
+
+/// Describes the contents of a backup archive: when it was taken, the
+/// complete installed-extension snapshot (so a restore can optionally
+/// reinstall the same set), and enough size information to preflight a
+/// restore before extracting anything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Unix timestamp, in seconds, the backup was taken at.
+    pub created_at: u64,
+    /// Complete installed-extension snapshot at backup time.
+    #[serde(default)]
+    pub extensions: Vec<ExtensionInfo>,
+    /// Total uncompressed size of the archive contents, in bytes.
+    pub uncompressed_bytes: u64,
+    /// Whether this backup was written with [`write_manifest_encrypted`].
+    /// `false` for every backup taken before encryption support existed,
+    /// so old backups keep restoring without an identity.
+    #[serde(default)]
+    pub encrypted: bool,
+    /// The age recipient (public key) the backup was encrypted to, when
+    /// `encrypted` is `true`.
+    #[serde(default)]
+    pub recipient: Option<String>,
+    /// The include/exclude pattern set the backup actually ran with, as
+    /// resolved from its [`crate::BackupProfile`] via
+    /// [`crate::BackupProfile::effective_patterns`]. `None` for backups
+    /// taken before profile-aware filtering existed.
+    #[serde(default)]
+    pub effective_patterns: Option<EffectivePatterns>,
+}
+
+/// Writes `manifest` as `backup-manifest.json` inside `dir`.
+pub fn write_manifest(dir: &Path, manifest: &BackupManifest) -> Result<(), BackupError> {
+    let path = dir.join(MANIFEST_FILE);
+    fs::write(path, serde_json::to_vec_pretty(manifest)?)?;
+    Ok(())
+}
+
+/// Encrypts `manifest` to `recipient` and writes it as
+/// `backup-manifest.json.age` inside `dir`, instead of the plaintext
+/// `backup-manifest.json` [`write_manifest`] would produce. `manifest`'s
+/// own `encrypted`/`recipient` fields are set to reflect that before
+/// writing, so once decrypted the manifest still records how it was
+/// sealed.
+pub fn write_manifest_encrypted(
+    dir: &Path,
+    manifest: &BackupManifest,
+    recipient: &str,
+) -> Result<(), BackupError> {
+    let manifest = BackupManifest {
+        encrypted: true,
+        recipient: Some(recipient.to_string()),
+        ..manifest.clone()
+    };
+    let plaintext = serde_json::to_vec_pretty(&manifest)?;
+    let ciphertext = encryption::encrypt(&plaintext, recipient)?;
+
+    let path = dir.join(format!("{MANIFEST_FILE}{ENCRYPTED_MANIFEST_SUFFIX}"));
+    fs::write(path, ciphertext)?;
+    Ok(())
+}
+
+/// Reads `backup-manifest.json` out of `dir`. If the manifest was sealed
+/// with [`write_manifest_encrypted`] instead, this returns
+/// [`BackupError::ManifestEncrypted`] rather than failing to parse
+/// ciphertext as JSON; callers that expect encrypted backups should catch
+/// that and retry with [`read_manifest_with_identity`].
+pub fn read_manifest(dir: &Path) -> Result<BackupManifest, BackupError> {
+    let path = dir.join(MANIFEST_FILE);
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => {
+            let encrypted_path = dir.join(format!("{MANIFEST_FILE}{ENCRYPTED_MANIFEST_SUFFIX}"));
+            if encrypted_path.is_file() {
+                return Err(BackupError::ManifestEncrypted(encrypted_path));
+            }
+            return Err(BackupError::ManifestMissing(path));
+        }
+        Err(source) => return Err(BackupError::Io(source)),
+    };
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Decrypts and reads a manifest written by [`write_manifest_encrypted`],
+/// using `identity` (an `AGE-SECRET-KEY-1...` private key).
+pub fn read_manifest_with_identity(dir: &Path, identity: &str) -> Result<BackupManifest, BackupError> {
+    let path = dir.join(format!("{MANIFEST_FILE}{ENCRYPTED_MANIFEST_SUFFIX}"));
+    let ciphertext = fs::read(&path).map_err(|source| {
+        if source.kind() == std::io::ErrorKind::NotFound {
+            BackupError::ManifestMissing(path.clone())
+        } else {
+            BackupError::Io(source)
+        }
+    })?;
+    let plaintext = encryption::decrypt(&ciphertext, identity)?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_disk() {
+        let tmp = tempfile::tempdir().unwrap();
+        let manifest = BackupManifest {
+            created_at: 1_700_000_000,
+            extensions: vec![ExtensionInfo {
+                name: "foo".to_string(),
+                version: "1.0.0".to_string(),
+            }],
+            uncompressed_bytes: 4096,
+            encrypted: false,
+            recipient: None,
+            effective_patterns: None,
+        };
+
+        write_manifest(tmp.path(), &manifest).unwrap();
+        let read_back = read_manifest(tmp.path()).unwrap();
+
+        assert_eq!(read_back.created_at, manifest.created_at);
+        assert_eq!(read_back.extensions, manifest.extensions);
+        assert_eq!(read_back.uncompressed_bytes, manifest.uncompressed_bytes);
+    }
+
+    #[test]
+    fn missing_manifest_is_reported_clearly() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            read_manifest(tmp.path()),
+            Err(BackupError::ManifestMissing(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_manifest_round_trips_with_the_matching_identity() {
+        let tmp = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let recipient = identity.to_public().to_string();
+        let manifest = BackupManifest {
+            created_at: 1_700_000_000,
+            extensions: vec![ExtensionInfo {
+                name: "foo".to_string(),
+                version: "1.0.0".to_string(),
+            }],
+            uncompressed_bytes: 4096,
+            encrypted: false,
+            recipient: None,
+            effective_patterns: None,
+        };
+
+        write_manifest_encrypted(tmp.path(), &manifest, &recipient).unwrap();
+
+        assert!(matches!(
+            read_manifest(tmp.path()),
+            Err(BackupError::ManifestEncrypted(_))
+        ));
+
+        use age::secrecy::ExposeSecret;
+        let read_back =
+            read_manifest_with_identity(tmp.path(), identity.to_string().expose_secret()).unwrap();
+        assert!(read_back.encrypted);
+        assert_eq!(read_back.recipient, Some(recipient));
+        assert_eq!(read_back.created_at, manifest.created_at);
+    }
+}
@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{BackupError, BackupManifest};
+
+/// Verifies a restore destination is writable and has enough free space for
+/// both the extracted backup and a pre-restore snapshot of whatever already
+/// lives there (taken so a failed restore can roll back). Checking capacity
+/// up front avoids the worst case where the restore runs out of room partway
+/// through and its own rollback can't fit either.
+pub fn validate_restore_preconditions(
+    manifest: &BackupManifest,
+    destination: &Path,
+) -> Result<(), BackupError> {
+    ensure_writable(destination)?;
+
+    let needed = manifest
+        .uncompressed_bytes
+        .saturating_add(dir_size(destination)?);
+
+    match available_bytes(destination) {
+        Ok(available) if available < needed => {
+            Err(BackupError::InsufficientSpace { needed, available })
+        }
+        Ok(_) => Ok(()),
+        Err(source) => {
+            tracing::warn!(%source, "could not determine free disk space, skipping capacity check");
+            Ok(())
+        }
+    }
+}
+
+fn ensure_writable(destination: &Path) -> Result<(), BackupError> {
+    fs::create_dir_all(destination)?;
+    let probe = destination.join(".sindri-restore-write-test");
+    fs::write(&probe, b"").map_err(|source| BackupError::NotWritable(destination.to_path_buf(), source))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Total size in bytes of everything already under `destination`, i.e. what
+/// a pre-restore snapshot would need to hold. A missing destination has
+/// nothing to snapshot.
+fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(source) => return Err(source),
+    };
+
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+
+    let mut total = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        total += dir_size(&entry.path())?;
+    }
+    Ok(total)
+}
+
+#[cfg(unix)]
+fn available_bytes(path: &Path) -> std::io::Result<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(not(unix))]
+fn available_bytes(_path: &Path) -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "free disk space checks are only implemented on unix",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtensionInfo;
+
+    fn manifest(uncompressed_bytes: u64) -> BackupManifest {
+        BackupManifest {
+            created_at: 0,
+            extensions: vec![ExtensionInfo {
+                name: "foo".to_string(),
+                version: "1.0.0".to_string(),
+            }],
+            uncompressed_bytes,
+            encrypted: false,
+            recipient: None,
+            effective_patterns: None,
+        }
+    }
+
+    #[test]
+    fn passes_when_destination_is_writable_and_space_is_sufficient() {
+        let tmp = tempfile::tempdir().unwrap();
+        validate_restore_preconditions(&manifest(1024), tmp.path()).unwrap();
+    }
+
+    #[test]
+    fn fails_fast_when_not_enough_free_space() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = validate_restore_preconditions(&manifest(u64::MAX - 1), tmp.path()).unwrap_err();
+        assert!(matches!(err, BackupError::InsufficientSpace { .. }));
+    }
+
+    #[test]
+    fn dir_size_counts_existing_contents() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(tmp.path().join("existing"), vec![0u8; 100]).unwrap();
+        assert_eq!(dir_size(tmp.path()).unwrap(), 100);
+    }
+
+    #[test]
+    fn dir_size_of_missing_path_is_zero() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(dir_size(&tmp.path().join("nope")).unwrap(), 0);
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+/// Path glob patterns every profile excludes by default (build caches,
+/// package manager stores, ...), unless a [`BackupProfile::Custom`] opts
+/// out via `replace_always_exclude`.
+pub const ALWAYS_EXCLUDE: &[&str] = &[
+    "**/node_modules/**",
+    "**/target/**",
+    "**/.cache/**",
+    "**/__pycache__/**",
+];
+
+/// Path glob patterns that are never included in a backup's effective
+/// pattern set, regardless of profile or `Custom` overrides — a backstop
+/// against accidentally capturing credentials even when a user asks for
+/// everything.
+pub const NEVER_RESTORE: &[&str] = &["**/.ssh/id_*", "**/.aws/credentials", "**/.env"];
+
+/// Which files a backup captures. The three fixed profiles cover common
+/// cases; [`BackupProfile::Custom`] gives power users an explicit
+/// include/exclude spec when those don't fit, e.g. standard plus one
+/// normally-excluded cache but minus SSH keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackupProfile {
+    /// Dotfiles and user configuration only.
+    UserData,
+    /// User data plus project directories; the default.
+    Standard,
+    /// Everything under the home directory, [`ALWAYS_EXCLUDE`] aside.
+    Full,
+    /// An explicit include/exclude spec, for needs the fixed profiles
+    /// don't cover.
+    Custom {
+        include: Vec<String>,
+        exclude: Vec<String>,
+        /// If `true`, `exclude` is the complete exclude set and
+        /// [`ALWAYS_EXCLUDE`] is not also applied. Defaults to `false`,
+        /// so a `Custom` profile composes with the built-in excludes
+        /// rather than silently losing them.
+        #[serde(default)]
+        replace_always_exclude: bool,
+    },
+}
+
+impl BackupProfile {
+    fn base_include(&self) -> Vec<String> {
+        match self {
+            BackupProfile::UserData => vec!["~/.config/**".to_string(), "~/.*rc".to_string()],
+            BackupProfile::Standard => {
+                let mut patterns = Self::UserData.base_include();
+                patterns.push("~/projects/**".to_string());
+                patterns
+            }
+            BackupProfile::Full => vec!["~/**".to_string()],
+            BackupProfile::Custom { include, .. } => include.clone(),
+        }
+    }
+
+    fn base_exclude(&self) -> (Vec<String>, bool) {
+        match self {
+            BackupProfile::Custom { exclude, replace_always_exclude, .. } => {
+                (exclude.clone(), *replace_always_exclude)
+            }
+            _ => (Vec::new(), false),
+        }
+    }
+
+    /// Resolves the effective include/exclude pattern set for this
+    /// profile: its own patterns, composed with [`ALWAYS_EXCLUDE`] unless
+    /// a `Custom` profile opts out, with [`NEVER_RESTORE`] always applied
+    /// last so it can't be overridden.
+    pub fn effective_patterns(&self) -> EffectivePatterns {
+        let include = self.base_include();
+        let (mut exclude, replace_always_exclude) = self.base_exclude();
+
+        if !replace_always_exclude {
+            for pattern in ALWAYS_EXCLUDE {
+                if !exclude.iter().any(|existing| existing == pattern) {
+                    exclude.push(pattern.to_string());
+                }
+            }
+        }
+
+        for pattern in NEVER_RESTORE {
+            if !exclude.iter().any(|existing| existing == pattern) {
+                exclude.push(pattern.to_string());
+            }
+        }
+
+        EffectivePatterns { include, exclude }
+    }
+}
+
+/// The fully resolved include/exclude pattern set a backup actually runs
+/// with, as recorded on [`crate::BackupManifest`] so a restore (or an
+/// audit) can see exactly what was captured without re-deriving it from
+/// the profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectivePatterns {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_profile_excludes_the_built_ins_and_never_restore_markers() {
+        let patterns = BackupProfile::Standard.effective_patterns();
+        assert!(patterns.exclude.contains(&"**/node_modules/**".to_string()));
+        assert!(patterns.exclude.contains(&"**/.ssh/id_*".to_string()));
+    }
+
+    #[test]
+    fn custom_profile_composes_with_always_exclude_by_default() {
+        let profile = BackupProfile::Custom {
+            include: vec!["~/.cache/big-build/**".to_string()],
+            exclude: vec!["~/.ssh/**".to_string()],
+            replace_always_exclude: false,
+        };
+        let patterns = profile.effective_patterns();
+
+        assert!(patterns.include.contains(&"~/.cache/big-build/**".to_string()));
+        assert!(patterns.exclude.contains(&"~/.ssh/**".to_string()));
+        assert!(patterns.exclude.contains(&"**/node_modules/**".to_string()));
+    }
+
+    #[test]
+    fn custom_profile_can_replace_always_exclude() {
+        let profile = BackupProfile::Custom {
+            include: vec!["~/**".to_string()],
+            exclude: vec!["~/.ssh/**".to_string()],
+            replace_always_exclude: true,
+        };
+        let patterns = profile.effective_patterns();
+
+        assert!(!patterns.exclude.contains(&"**/node_modules/**".to_string()));
+        assert!(patterns.exclude.contains(&"~/.ssh/**".to_string()));
+    }
+
+    #[test]
+    fn never_restore_markers_cannot_be_overridden() {
+        let profile = BackupProfile::Custom {
+            include: vec!["~/**".to_string()],
+            exclude: Vec::new(),
+            replace_always_exclude: true,
+        };
+        let patterns = profile.effective_patterns();
+
+        assert!(patterns.exclude.contains(&"**/.ssh/id_*".to_string()));
+        assert!(patterns.exclude.contains(&"**/.aws/credentials".to_string()));
+    }
+}
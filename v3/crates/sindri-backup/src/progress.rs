@@ -0,0 +1,53 @@
+use crate::ExtensionInfo;
+
+/// A stage [`crate::RestoreManager::restore`] passes through, in order.
+/// Reported to a [`RestoreProgress`] as each stage begins, so a caller can
+/// render real progress instead of parsing log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreStage {
+    ReadManifest,
+    ValidatePreconditions,
+    ReinstallExtensions,
+    RunHooks,
+    Complete,
+}
+
+/// Receives progress events from [`crate::RestoreManager::restore`]. Every
+/// method has a no-op default, so a caller only needs to override what it
+/// wants to render.
+pub trait RestoreProgress {
+    fn stage(&mut self, stage: RestoreStage) {
+        let _ = stage;
+    }
+
+    /// Called once per extension the manifest recorded, as it's either
+    /// reinstalled or reported unmatched — the closest thing to a
+    /// per-file event this restore pipeline has today.
+    fn extension_restored(&mut self, extension: &ExtensionInfo, installed: bool) {
+        let _ = (extension, installed);
+    }
+}
+
+/// The [`RestoreProgress`] used when a caller doesn't supply one, backing
+/// the same `tracing` output `sindri restore` produced before this trait
+/// existed.
+#[derive(Debug, Default)]
+pub struct TracingRestoreProgress;
+
+impl RestoreProgress for TracingRestoreProgress {
+    fn stage(&mut self, stage: RestoreStage) {
+        tracing::info!(?stage, "restore stage");
+    }
+
+    fn extension_restored(&mut self, extension: &ExtensionInfo, installed: bool) {
+        if installed {
+            tracing::info!(extension = %extension.name, version = %extension.version, "reinstalled");
+        } else {
+            tracing::warn!(
+                extension = %extension.name,
+                version = %extension.version,
+                "could not be matched to an available version, skipped"
+            );
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use sindri_providers::Provider;
+
+use crate::BackupError;
+
+/// Archives `remote_dir` on `target` (a container name or pod name) by
+/// running `tar` there via [`Provider::exec`], then writes the resulting
+/// `.tar.gz` to `out_file` — the `sindri backup --to-provider` path,
+/// avoiding a manual `scp` of a running deployment's workspace.
+///
+/// The whole archive is buffered in memory before it's written out; see
+/// [`Provider::exec`] for why there's no chunked streaming or resumability
+/// yet.
+pub async fn backup_to_provider(
+    provider: &dyn Provider,
+    target: &str,
+    remote_dir: &str,
+    out_file: &Path,
+) -> Result<(), BackupError> {
+    let archive = provider.exec(target, &["tar", "-czf", "-", "-C", remote_dir, "."], &[]).await?;
+    std::fs::write(out_file, archive)?;
+    Ok(())
+}
+
+/// Pushes the archive at `archive_file` to `target` and extracts it into
+/// `remote_dir` there via [`Provider::exec`] — the `sindri restore
+/// --from-provider` path, avoiding a manual `scp` before restoring into
+/// another deployment.
+pub async fn restore_from_provider(
+    provider: &dyn Provider,
+    target: &str,
+    archive_file: &Path,
+    remote_dir: &str,
+) -> Result<(), BackupError> {
+    let archive = std::fs::read(archive_file)?;
+    provider.exec(target, &["tar", "-xzf", "-", "-C", remote_dir], &archive).await?;
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sindri_extensions::ExtensionDistributor;
+
+use crate::ExtensionInfo;
+
+/// Resolves where the files for a given extension name/version can be
+/// copied from during a restore. Returns `None` when that exact version
+/// isn't available, so callers can report it as unmatched rather than
+/// failing the whole restore.
+pub trait ExtensionSource {
+    fn locate(&self, name: &str, version: &str) -> Option<PathBuf>;
+}
+
+/// An [`ExtensionSource`] backed by the `extensions/<name>/<version>/`
+/// snapshot that lives alongside the manifest inside a backup directory.
+pub struct BackupExtensionSource {
+    backup_dir: PathBuf,
+}
+
+impl BackupExtensionSource {
+    pub fn new(backup_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            backup_dir: backup_dir.into(),
+        }
+    }
+}
+
+impl ExtensionSource for BackupExtensionSource {
+    fn locate(&self, name: &str, version: &str) -> Option<PathBuf> {
+        let dir = self.backup_dir.join("extensions").join(name).join(version);
+        dir.is_dir().then_some(dir)
+    }
+}
+
+/// Outcome of [`reinstall_extensions`].
+#[derive(Debug, Default, Clone)]
+pub struct ReinstallReport {
+    pub installed: Vec<ExtensionInfo>,
+    /// Already at the manifest's recorded version, so left untouched. Only
+    /// populated when `force` is `false` — see [`reinstall_extensions`].
+    pub skipped: Vec<ExtensionInfo>,
+    /// Extensions the manifest recorded but that couldn't be matched to an
+    /// available version — either the source had nothing for them, or the
+    /// install into `distributor` itself failed.
+    pub unmatched: Vec<ExtensionInfo>,
+}
+
+enum ReinstallOutcome {
+    Installed,
+    Skipped,
+    Failed,
+}
+
+/// Reinstalls every extension recorded in a backup manifest via
+/// `distributor`, sourcing each version's files from `source`. An
+/// extension version `source` can't locate, or one whose install fails,
+/// is reported in `unmatched` rather than aborting the rest of the
+/// restore — so a partial match still restores everything it can, and a
+/// retry after a partial failure only reattempts what didn't already
+/// succeed: an extension already installed at the manifest's recorded
+/// version is left alone and reported as `skipped` rather than
+/// reinstalled, unless `force` is `true`.
+///
+/// Extensions are independent of each other (nothing here reinstalls in
+/// dependency order — there's no such ordering recorded in a backup
+/// manifest), so up to `parallel` of them install at once; `1` reinstalls
+/// one at a time, in manifest order, matching this function's original
+/// behavior. Each extension's outcome is attributed correctly regardless
+/// of `parallel`, since every thread reports back only its own extension's
+/// result rather than sharing mutable state.
+pub fn reinstall_extensions(
+    extensions: &[ExtensionInfo],
+    distributor: &ExtensionDistributor,
+    source: &(impl ExtensionSource + Sync),
+    parallel: usize,
+    force: bool,
+) -> ReinstallReport {
+    let mut report = ReinstallReport::default();
+    let parallel = parallel.max(1);
+
+    for chunk in extensions.chunks(parallel) {
+        let outcomes: Vec<ReinstallOutcome> = std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|extension| scope.spawn(|| reinstall_one(extension, distributor, source, force)))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().expect("reinstall thread panicked")).collect()
+        });
+
+        for (extension, outcome) in chunk.iter().zip(outcomes) {
+            match outcome {
+                ReinstallOutcome::Installed => report.installed.push(extension.clone()),
+                ReinstallOutcome::Skipped => report.skipped.push(extension.clone()),
+                ReinstallOutcome::Failed => report.unmatched.push(extension.clone()),
+            }
+        }
+    }
+
+    report
+}
+
+/// Reinstalls one extension. Skips it (unless `force`) when it's already
+/// installed at the recorded version — the resumability
+/// [`reinstall_extensions`] promises. Failures are logged here rather than
+/// propagated, since a failed extension should still let the rest of a
+/// [`reinstall_extensions`] batch proceed.
+fn reinstall_one(
+    extension: &ExtensionInfo,
+    distributor: &ExtensionDistributor,
+    source: &impl ExtensionSource,
+    force: bool,
+) -> ReinstallOutcome {
+    if !force && distributor.current_version(&extension.name).as_deref() == Some(extension.version.as_str()) {
+        return ReinstallOutcome::Skipped;
+    }
+
+    let Some(src_dir) = source.locate(&extension.name, &extension.version) else {
+        tracing::warn!(
+            extension = %extension.name,
+            version = %extension.version,
+            "no matching version available to reinstall"
+        );
+        return ReinstallOutcome::Failed;
+    };
+
+    let install_result =
+        distributor.install(&extension.name, &extension.version, |dest| copy_dir_recursive(&src_dir, dest));
+
+    match install_result {
+        Ok(()) => ReinstallOutcome::Installed,
+        Err(err) => {
+            tracing::warn!(
+                extension = %extension.name,
+                version = %extension.version,
+                %err,
+                "reinstall failed"
+            );
+            ReinstallOutcome::Failed
+        }
+    }
+}
+
+fn copy_dir_recursive(src: &Path, dest: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let dest_path = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            fs::create_dir_all(&dest_path)?;
+            copy_dir_recursive(&entry.path(), &dest_path)?;
+        } else {
+            fs::copy(entry.path(), &dest_path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinstalls_available_versions_and_reports_the_rest() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+
+        let foo_dir = backup.path().join("extensions").join("foo").join("1.0.0");
+        fs::create_dir_all(&foo_dir).unwrap();
+        fs::write(foo_dir.join("bin"), b"foo contents").unwrap();
+
+        let distributor = ExtensionDistributor::new(extensions_root.path());
+        let source = BackupExtensionSource::new(backup.path());
+
+        let manifest_extensions = vec![
+            ExtensionInfo {
+                name: "foo".to_string(),
+                version: "1.0.0".to_string(),
+            },
+            ExtensionInfo {
+                name: "bar".to_string(),
+                version: "9.9.9".to_string(),
+            },
+        ];
+
+        let report = reinstall_extensions(&manifest_extensions, &distributor, &source, 1, false);
+
+        assert_eq!(report.installed, vec![manifest_extensions[0].clone()]);
+        assert_eq!(report.unmatched, vec![manifest_extensions[1].clone()]);
+        assert_eq!(distributor.current_version("foo"), Some("1.0.0".to_string()));
+    }
+
+    #[test]
+    fn an_extension_already_at_the_recorded_version_is_skipped_not_reinstalled() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+
+        let foo_dir = backup.path().join("extensions").join("foo").join("1.0.0");
+        fs::create_dir_all(&foo_dir).unwrap();
+        fs::write(foo_dir.join("bin"), b"foo contents").unwrap();
+
+        let distributor = ExtensionDistributor::new(extensions_root.path());
+        let source = BackupExtensionSource::new(backup.path());
+        distributor.install("foo", "1.0.0", |dest| copy_dir_recursive(&foo_dir, dest)).unwrap();
+
+        let manifest_extensions = vec![ExtensionInfo { name: "foo".to_string(), version: "1.0.0".to_string() }];
+
+        let report = reinstall_extensions(&manifest_extensions, &distributor, &source, 1, false);
+
+        assert_eq!(report.skipped, manifest_extensions);
+        assert!(report.installed.is_empty());
+    }
+
+    #[test]
+    fn force_reinstalls_an_extension_already_at_the_recorded_version() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+
+        let foo_dir = backup.path().join("extensions").join("foo").join("1.0.0");
+        fs::create_dir_all(&foo_dir).unwrap();
+        fs::write(foo_dir.join("bin"), b"foo contents").unwrap();
+
+        let distributor = ExtensionDistributor::new(extensions_root.path());
+        let source = BackupExtensionSource::new(backup.path());
+        distributor.install("foo", "1.0.0", |dest| copy_dir_recursive(&foo_dir, dest)).unwrap();
+
+        let manifest_extensions = vec![ExtensionInfo { name: "foo".to_string(), version: "1.0.0".to_string() }];
+
+        let report = reinstall_extensions(&manifest_extensions, &distributor, &source, 1, true);
+
+        assert_eq!(report.installed, manifest_extensions);
+        assert!(report.skipped.is_empty());
+    }
+
+    #[test]
+    fn reinstalling_in_parallel_still_attributes_each_outcome_correctly() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+
+        let mut manifest_extensions = Vec::new();
+        for i in 0..6 {
+            let name = format!("ext{i}");
+            let dir = backup.path().join("extensions").join(&name).join("1.0.0");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("bin"), format!("{name} contents")).unwrap();
+            manifest_extensions.push(ExtensionInfo { name, version: "1.0.0".to_string() });
+        }
+        manifest_extensions.push(ExtensionInfo { name: "missing".to_string(), version: "1.0.0".to_string() });
+
+        let distributor = ExtensionDistributor::new(extensions_root.path());
+        let source = BackupExtensionSource::new(backup.path());
+
+        let report = reinstall_extensions(&manifest_extensions, &distributor, &source, 4, false);
+
+        assert_eq!(report.installed, manifest_extensions[..6]);
+        assert_eq!(report.unmatched, vec![manifest_extensions[6].clone()]);
+        for extension in &manifest_extensions[..6] {
+            assert_eq!(distributor.current_version(&extension.name), Some("1.0.0".to_string()));
+        }
+    }
+
+    #[test]
+    fn a_parallel_of_zero_still_reinstalls_everything_sequentially() {
+        let backup = tempfile::tempdir().unwrap();
+        let extensions_root = tempfile::tempdir().unwrap();
+        let foo_dir = backup.path().join("extensions").join("foo").join("1.0.0");
+        fs::create_dir_all(&foo_dir).unwrap();
+        fs::write(foo_dir.join("bin"), b"foo contents").unwrap();
+
+        let distributor = ExtensionDistributor::new(extensions_root.path());
+        let source = BackupExtensionSource::new(backup.path());
+        let manifest_extensions =
+            vec![ExtensionInfo { name: "foo".to_string(), version: "1.0.0".to_string() }];
+
+        let report = reinstall_extensions(&manifest_extensions, &distributor, &source, 0, false);
+        assert_eq!(report.installed, manifest_extensions);
+    }
+}
@@ -0,0 +1,26 @@
+use serde::Serialize;
+use sindri_core::TableRow;
+
+/// A row in `sindri backup list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupRow {
+    pub dir: String,
+    pub created_at: u64,
+    pub extensions: usize,
+    pub uncompressed_bytes: u64,
+}
+
+impl TableRow for BackupRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["DIR", "CREATED_AT", "EXTENSIONS", "UNCOMPRESSED_BYTES"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![
+            self.dir.clone(),
+            self.created_at.to_string(),
+            self.extensions.to_string(),
+            self.uncompressed_bytes.to_string(),
+        ]
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+/// How often [`ConfigWatcher`] re-checks the file's modification time.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Polls a config file's modification time and notifies a long-running
+/// command when it changes, so e.g. a `deploy --wait` loop can re-read it
+/// without restarting. There's no broader hierarchical config system yet
+/// for this to merge layers for — it only watches the one file it's given
+/// and leaves deciding what to do about a change to the caller.
+pub struct ConfigWatcher {
+    path: PathBuf,
+    poll_interval: Duration,
+}
+
+impl ConfigWatcher {
+    /// Watches `path` at the default poll interval.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), poll_interval: DEFAULT_POLL_INTERVAL }
+    }
+
+    /// Overrides the poll interval, mainly so tests aren't stuck waiting a
+    /// full second.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Spawns the poller and returns a receiver that gets a message each
+    /// time the file's modification time advances. Stops polling once the
+    /// receiver is dropped.
+    pub fn watch(self) -> mpsc::UnboundedReceiver<()> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut last_modified = modified_at(&self.path).await;
+            loop {
+                tokio::time::sleep(self.poll_interval).await;
+                let current = modified_at(&self.path).await;
+                if current.is_some() && current != last_modified {
+                    last_modified = current;
+                    if tx.send(()).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        rx
+    }
+}
+
+async fn modified_at(path: &std::path::Path) -> Option<std::time::SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn notifies_when_the_watched_file_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.yaml");
+        tokio::fs::write(&path, "a: 1").await.unwrap();
+
+        let mut changes = ConfigWatcher::new(&path)
+            .with_poll_interval(Duration::from_millis(10))
+            .watch();
+
+        // Give the watcher a moment to record the file's initial mtime
+        // before we change it, otherwise the write below might land
+        // before polling starts and get missed as the baseline instead
+        // of a change.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        tokio::fs::write(&path, "a: 2").await.unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), changes.recv())
+            .await
+            .expect("should have been notified of the change")
+            .expect("channel should still be open");
+    }
+
+    #[tokio::test]
+    async fn does_not_notify_without_a_change() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.yaml");
+        tokio::fs::write(&path, "a: 1").await.unwrap();
+
+        let mut changes = ConfigWatcher::new(&path)
+            .with_poll_interval(Duration::from_millis(10))
+            .watch();
+
+        let result = tokio::time::timeout(Duration::from_millis(100), changes.recv()).await;
+        assert!(result.is_err(), "should not have been notified without a change");
+    }
+}
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Result of a successful `sindri deploy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployResult {
+    pub instance_id: String,
+    pub provider: String,
+    pub ssh_command: Option<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+impl DeployResult {
+    /// Multi-line human-readable rendering used when `--json` isn't passed.
+    /// Non-fatal warnings are rendered as a single clearly-delineated
+    /// section at the end rather than interleaved with the rest, so they
+    /// don't scroll past unnoticed in the log stream.
+    pub fn render_human(&self) -> String {
+        let mut out = format!(
+            "Deployed {} on {}\n",
+            self.instance_id, self.provider
+        );
+        if let Some(ssh) = &self.ssh_command {
+            out += &format!("Connect with: {ssh}\n");
+        }
+        if !self.warnings.is_empty() {
+            out += &format!(
+                "\n\u{26a0} {} warning{}: {}\n",
+                self.warnings.len(),
+                if self.warnings.len() == 1 { "" } else { "s" },
+                self.warnings.join("; ")
+            );
+        }
+        out.trim_end().to_string()
+    }
+}
+
+/// Status of a deployed instance, as reported by `sindri status`/`connect`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentStatus {
+    pub instance_id: String,
+    pub provider: String,
+    pub state: String,
+    /// How long the deployment has been running, where the backend exposes
+    /// it (Docker `inspect`, a pod's `status.startTime`, a Fly machine's
+    /// `created_at`). `None` when the provider can't supply it.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "opt_duration_secs")]
+    pub uptime: Option<Duration>,
+    /// Restart count reported by the backend (e.g. a container's restart
+    /// counter or a pod's `containerStatuses[].restartCount`). `None` when
+    /// the provider can't supply it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_count: Option<u32>,
+    /// Instance/replica count, as last set via `Provider::scale` or the
+    /// provider's default. `None` for providers that don't expose a
+    /// replica concept (e.g. single-instance Docker/E2B deployments).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<u32>,
+    /// Whether the running container's image came from a local
+    /// `"built"` (`sindri deploy --build-context`) or a registry
+    /// `"pulled"` image, per `DockerProvider::image_source`'s build-tag
+    /// check. `None` for a non-Docker provider or when it can't be
+    /// determined (e.g. the container can't be found).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_source: Option<String>,
+}
+
+impl DeploymentStatus {
+    pub fn render_human(&self) -> String {
+        let mut out = format!(
+            "{} ({}) — {}",
+            self.instance_id, self.provider, self.state
+        );
+        if let Some(uptime) = self.uptime {
+            out += &format!(", up {}s", uptime.as_secs());
+        }
+        if let Some(restart_count) = self.restart_count {
+            out += &format!(", {restart_count} restart(s)");
+        }
+        if let Some(replicas) = self.replicas {
+            out += &format!(", {replicas} replica(s)");
+        }
+        if let Some(image_source) = &self.image_source {
+            out += &format!(", image {image_source}");
+        }
+        out
+    }
+}
+
+/// (De)serializes `Option<Duration>` as whole seconds rather than serde's
+/// default `{secs, nanos}` struct, so the JSON/YAML form stays a plain
+/// number for dashboards to consume.
+mod opt_duration_secs {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_some(&duration.as_secs()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        let secs = Option::<u64>::deserialize(deserializer)?;
+        Ok(secs.map(Duration::from_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_without_a_warnings_section_when_there_are_none() {
+        let result = DeployResult {
+            instance_id: "docker_abc".to_string(),
+            provider: "docker".to_string(),
+            ssh_command: None,
+            warnings: Vec::new(),
+        };
+        assert!(!result.render_human().contains("warning"));
+    }
+
+    #[test]
+    fn renders_warnings_as_one_delineated_section() {
+        let result = DeployResult {
+            instance_id: "docker_abc".to_string(),
+            provider: "docker".to_string(),
+            ssh_command: None,
+            warnings: vec![
+                "GPU requested but provider will schedule best-effort".to_string(),
+                "home volume smaller than recommended".to_string(),
+            ],
+        };
+        let rendered = result.render_human();
+        assert!(rendered.contains(
+            "2 warnings: GPU requested but provider will schedule best-effort; \
+             home volume smaller than recommended"
+        ));
+    }
+}
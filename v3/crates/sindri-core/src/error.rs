@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+/// Top-level error type shared by Sindri crates that don't need a more
+/// specific error enum of their own.
+#[derive(Debug, Error)]
+pub enum SindriError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("serialization error: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("config error: {0}")]
+    Config(String),
+
+    #[error("{0}")]
+    Other(String),
+}
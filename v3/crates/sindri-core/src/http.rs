@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+/// Env var naming a PEM bundle of extra trusted CA certificates, for
+/// networks that terminate TLS with a corporate root (e.g. an inspecting
+/// proxy in front of an internal registry or Vault). Set globally by
+/// `sindri --ca-bundle`.
+pub const CA_BUNDLE_ENV: &str = "SINDRI_CA_BUNDLE";
+
+/// Builds a [`reqwest::Client`] configured consistently for every HTTP
+/// client in the CLI, so proxy/CA behavior doesn't vary by which module
+/// happens to be making the request.
+///
+/// Proxy settings come from `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY`, which
+/// `reqwest` already honors by default. When [`CA_BUNDLE_ENV`] is set,
+/// every certificate in that PEM bundle is added *in addition to* the
+/// system roots reqwest already trusts — this only ever widens trust, it
+/// never disables certificate verification.
+///
+/// Falls back to a plain [`reqwest::Client::new`] if the configured
+/// bundle can't be read or parsed, logging a warning rather than failing
+/// a caller that doesn't expect client construction to be fallible.
+pub fn build_http_client() -> reqwest::Client {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(path) = std::env::var_os(CA_BUNDLE_ENV).map(PathBuf::from) {
+        match load_ca_bundle(&path) {
+            Ok(certs) => {
+                for cert in certs {
+                    builder = builder.add_root_certificate(cert);
+                }
+            }
+            Err(err) => {
+                tracing::warn!(path = %path.display(), %err, "ignoring {CA_BUNDLE_ENV}");
+            }
+        }
+    }
+
+    builder.build().unwrap_or_else(|err| {
+        tracing::warn!(%err, "failed to build configured http client, falling back to defaults");
+        reqwest::Client::new()
+    })
+}
+
+fn load_ca_bundle(path: &Path) -> Result<Vec<reqwest::Certificate>, String> {
+    let pem = std::fs::read(path).map_err(|err| err.to_string())?;
+    reqwest::Certificate::from_pem_bundle(&pem).map_err(|err| err.to_string())
+}
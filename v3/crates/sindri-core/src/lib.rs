@@ -0,0 +1,33 @@
+//! Shared types, configuration, and utilities used across the Sindri workspace.
+
+mod config_watch;
+mod deploy;
+mod error;
+mod http;
+mod lint;
+mod output;
+mod paths;
+mod session;
+mod shutdown;
+mod targets;
+mod template;
+mod webhook;
+
+pub use config_watch::ConfigWatcher;
+pub use deploy::{DeployResult, DeploymentStatus};
+pub use error::SindriError;
+pub use http::{build_http_client, CA_BUNDLE_ENV};
+pub use lint::{LintFinding, LintSeverity};
+pub use output::{emit, render_rows, render_rows_selected, OutputFormat, TableRow};
+pub use paths::PathResolver;
+pub use session::SessionRecorder;
+pub use shutdown::ShutdownSignal;
+pub use targets::{
+    json_schema as multi_target_config_json_schema, CommonConfig, MultiTargetConfig,
+    RetryPolicyConfig, TargetConfig,
+};
+pub use template::{TemplateContext, TemplateContextBuilder, RESERVED_KEYS};
+pub use webhook::{send_webhook_event, DeployEvent, DeployOutcome};
+
+/// Convenience result alias used throughout the workspace.
+pub type Result<T> = std::result::Result<T, SindriError>;
@@ -0,0 +1,24 @@
+//! Shared vocabulary for semantic (not just structural/schema) lint
+//! findings, used by every config type in the workspace that has its own
+//! `lint`: `sindri_extensions::Profile` and [`crate::MultiTargetConfig`]
+//! (via `sindri_providers`'s lint over it).
+
+/// Severity of a [`LintFinding`]. An [`Error`](LintSeverity::Error) should
+/// block an install/deploy; a [`Warning`](LintSeverity::Warning) is worth
+/// surfacing but not blocking on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintSeverity {
+    Warning,
+    Error,
+}
+
+/// A single semantic lint finding against an already-loaded,
+/// already-schema-valid config, naming the field it's about (`path`) so a
+/// caller can point a user at exactly what to fix, the way a schema
+/// validator would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFinding {
+    pub severity: LintSeverity,
+    pub path: String,
+    pub message: String,
+}
@@ -0,0 +1,253 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::Result;
+
+/// Output format shared by list-style commands (`extension list`, `status`,
+/// `info`, `versions`, ...). `Table` is the default for interactive use;
+/// `Json`/`Yaml` serialize the same row structs for scripting; `Csv` emits
+/// RFC 4180 CSV with the table's headers as the header row, for spreadsheet
+/// import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Default)]
+#[clap(rename_all = "lower")]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+    Csv,
+}
+
+/// A row that knows how to render itself as a table column set, in addition
+/// to the `Serialize` impl used for `Json`/`Yaml`.
+pub trait TableRow {
+    fn headers() -> Vec<&'static str>;
+    fn columns(&self) -> Vec<String>;
+}
+
+/// Renders `rows` in the requested `format`. `Yaml`/`Json` serialize the
+/// rows as-is (struct field order is preserved, so output is stable-ordered
+/// for clean diffs); `Table` pads columns to the widest value per column.
+pub fn render_rows<T: Serialize + TableRow>(rows: &[T], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(rows)
+            .map_err(|e| crate::SindriError::Other(e.to_string()))?),
+        OutputFormat::Table => Ok(render_table(&T::headers(), &rows.iter().map(T::columns).collect::<Vec<_>>())),
+        OutputFormat::Csv => Ok(render_csv(&T::headers(), &rows.iter().map(T::columns).collect::<Vec<_>>())),
+    }
+}
+
+/// Like [`render_rows`], but for `Table`/`Csv` output, only renders the
+/// headers named in `columns` (case-insensitive), in the order given,
+/// instead of every column `T` has. `Json`/`Yaml` output is unaffected —
+/// `columns` only thins out the table/CSV, it doesn't change what gets
+/// serialized. Errors if `columns` names anything that isn't one of
+/// `T::headers()`.
+pub fn render_rows_selected<T: Serialize + TableRow>(
+    rows: &[T],
+    format: OutputFormat,
+    columns: Option<&[String]>,
+) -> Result<String> {
+    let Some(columns) = columns.filter(|c| !c.is_empty()) else {
+        return render_rows(rows, format);
+    };
+
+    let headers = T::headers();
+    let indices: Vec<usize> = columns
+        .iter()
+        .map(|requested| {
+            headers.iter().position(|header| header.eq_ignore_ascii_case(requested)).ok_or_else(|| {
+                crate::SindriError::Other(format!(
+                    "unknown column {requested:?}; valid columns: {}",
+                    headers.join(", ")
+                ))
+            })
+        })
+        .collect::<Result<_>>()?;
+    let selected_headers: Vec<&'static str> = indices.iter().map(|&i| headers[i]).collect();
+
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(rows)
+            .map_err(|e| crate::SindriError::Other(e.to_string()))?),
+        OutputFormat::Table | OutputFormat::Csv => {
+            let body: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| {
+                    let full = row.columns();
+                    indices.iter().map(|&i| full[i].clone()).collect()
+                })
+                .collect();
+            Ok(match format {
+                OutputFormat::Csv => render_csv(&selected_headers, &body),
+                _ => render_table(&selected_headers, &body),
+            })
+        }
+    }
+}
+
+fn render_table(headers: &[&'static str], body: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in body {
+        for (i, cell) in row.iter().enumerate() {
+            if let Some(w) = widths.get_mut(i) {
+                *w = (*w).max(cell.len());
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out += &pad_row(&headers.iter().map(|h| h.to_string()).collect::<Vec<_>>(), &widths);
+    out.push('\n');
+    for row in body {
+        out += &pad_row(row, &widths);
+        out.push('\n');
+    }
+    out.trim_end().to_string()
+}
+
+/// Renders `headers`/`body` as RFC 4180 CSV: CRLF line endings, a field is
+/// quoted (with internal quotes doubled) only if it contains a comma,
+/// quote, or newline.
+fn render_csv(headers: &[&'static str], body: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    out += &csv_row(&headers.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    for row in body {
+        out += &csv_row(row);
+    }
+    out
+}
+
+fn csv_row(cells: &[String]) -> String {
+    let mut row: String = cells.iter().map(|cell| csv_field(cell)).collect::<Vec<_>>().join(",");
+    row.push_str("\r\n");
+    row
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn pad_row(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| format!("{:width$}", cell, width = widths.get(i).copied().unwrap_or(0)))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string()
+}
+
+/// Writes `value` to stdout as a single JSON object, or `human` if `json`
+/// is false. This is the only thing command implementations should print to
+/// stdout in `--json` mode — logs must go to stderr (see `tracing`'s writer
+/// setup in `main.rs`) so orchestration tooling can parse stdout reliably.
+pub fn emit<T: Serialize>(value: &T, json: bool, human: impl FnOnce(&T) -> String) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    if json {
+        let rendered = serde_json::to_string(value)?;
+        writeln!(stdout, "{rendered}")?;
+    } else {
+        writeln!(stdout, "{}", human(value))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        name: String,
+        version: String,
+        status: String,
+    }
+
+    impl TableRow for Row {
+        fn headers() -> Vec<&'static str> {
+            vec!["NAME", "VERSION", "STATUS"]
+        }
+
+        fn columns(&self) -> Vec<String> {
+            vec![self.name.clone(), self.version.clone(), self.status.clone()]
+        }
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![Row {
+            name: "foo".to_string(),
+            version: "1.0.0".to_string(),
+            status: "installed".to_string(),
+        }]
+    }
+
+    #[test]
+    fn no_columns_renders_every_header() {
+        let out = render_rows_selected(&rows(), OutputFormat::Table, None).unwrap();
+        assert_eq!(out.lines().next().unwrap(), "NAME  VERSION  STATUS");
+    }
+
+    #[test]
+    fn selected_columns_render_in_the_requested_order_case_insensitively() {
+        let columns = vec!["STATUS".to_string(), "name".to_string()];
+        let out = render_rows_selected(&rows(), OutputFormat::Table, Some(&columns)).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "STATUS     NAME");
+        assert_eq!(lines[1], "installed  foo");
+    }
+
+    #[test]
+    fn an_unknown_column_errors_with_the_valid_list() {
+        let columns = vec!["bogus".to_string()];
+        let err = render_rows_selected(&rows(), OutputFormat::Table, Some(&columns)).unwrap_err();
+        assert!(err.to_string().contains("NAME, VERSION, STATUS"));
+    }
+
+    #[test]
+    fn json_output_is_unaffected_by_a_column_selection() {
+        let columns = vec!["name".to_string()];
+        let selected = render_rows_selected(&rows(), OutputFormat::Json, Some(&columns)).unwrap();
+        let full = render_rows(&rows(), OutputFormat::Json).unwrap();
+        assert_eq!(selected, full);
+    }
+
+    #[test]
+    fn csv_output_has_a_header_row_and_uses_crlf_line_endings() {
+        let out = render_rows(&rows(), OutputFormat::Csv).unwrap();
+        assert_eq!(out, "NAME,VERSION,STATUS\r\nfoo,1.0.0,installed\r\n");
+    }
+
+    #[test]
+    fn csv_output_respects_a_column_selection() {
+        let columns = vec!["status".to_string(), "name".to_string()];
+        let out = render_rows_selected(&rows(), OutputFormat::Csv, Some(&columns)).unwrap();
+        assert_eq!(out, "STATUS,NAME\r\ninstalled,foo\r\n");
+    }
+
+    #[test]
+    fn csv_fields_with_commas_quotes_or_newlines_are_quoted_and_escaped() {
+        let rows = vec![Row {
+            name: "foo, bar".to_string(),
+            version: "says \"v1\"".to_string(),
+            status: "line one\nline two".to_string(),
+        }];
+        let out = render_rows(&rows, OutputFormat::Csv).unwrap();
+        assert_eq!(
+            out,
+            "NAME,VERSION,STATUS\r\n\"foo, bar\",\"says \"\"v1\"\"\",\"line one\nline two\"\r\n"
+        );
+    }
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        let out = render_rows(&rows(), OutputFormat::Csv).unwrap();
+        assert!(!out.contains('"'));
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Result, SindriError};
+
+/// Resolves Sindri's on-disk layout (cache, extensions, ledger, state) from
+/// a single config directory root, honoring (in priority order) an explicit
+/// override, `SINDRI_CONFIG_DIR`, and the platform's XDG config directory.
+#[derive(Debug, Clone)]
+pub struct PathResolver {
+    config_dir: PathBuf,
+}
+
+impl PathResolver {
+    /// Resolves the config directory from `override_dir`, then
+    /// `SINDRI_CONFIG_DIR`, then the platform default (`~/.config/sindri`
+    /// on Linux/macOS, `%APPDATA%\sindri` on Windows).
+    pub fn resolve(override_dir: Option<PathBuf>) -> Result<Self> {
+        let config_dir = override_dir
+            .or_else(|| std::env::var_os("SINDRI_CONFIG_DIR").map(PathBuf::from))
+            .or_else(default_config_dir)
+            .ok_or_else(|| {
+                SindriError::Config("could not determine a config directory".to_string())
+            })?;
+        Ok(Self { config_dir })
+    }
+
+    pub fn config_dir(&self) -> &Path {
+        &self.config_dir
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.config_dir.join("cache")
+    }
+
+    pub fn extensions_dir(&self) -> PathBuf {
+        self.config_dir.join("extensions")
+    }
+
+    pub fn ledger_dir(&self) -> PathBuf {
+        self.config_dir.join("ledger")
+    }
+
+    pub fn logs_dir(&self) -> PathBuf {
+        self.config_dir.join("logs")
+    }
+
+    /// Where the cached extension registry catalog is stored, so searching
+    /// and filtering it works offline once fetched.
+    pub fn registry_file(&self) -> PathBuf {
+        self.cache_dir().join("registry.json")
+    }
+
+    /// Where the fetched extension/CLI compatibility matrix is stored.
+    pub fn compat_matrix_file(&self) -> PathBuf {
+        self.cache_dir().join("compat-matrix.json")
+    }
+
+    /// Where the fetched extension/platform availability matrix is stored.
+    pub fn platform_matrix_file(&self) -> PathBuf {
+        self.cache_dir().join("platform-matrix.json")
+    }
+
+    pub fn state_dir(&self) -> PathBuf {
+        self.config_dir.join("state")
+    }
+
+    /// Where `connect --record` writes session transcripts.
+    pub fn sessions_dir(&self) -> PathBuf {
+        self.config_dir.join("sessions")
+    }
+
+    /// Creates the config directory if needed and verifies it's writable,
+    /// failing with a clear message otherwise (e.g. a read-only `$HOME` in
+    /// CI).
+    pub fn ensure_writable(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.config_dir)?;
+        let probe = self.config_dir.join(".sindri-write-test");
+        std::fs::write(&probe, b"").map_err(|source| {
+            SindriError::Config(format!(
+                "config directory {} is not writable: {source}",
+                self.config_dir.display()
+            ))
+        })?;
+        let _ = std::fs::remove_file(&probe);
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn default_config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("sindri"))
+}
+
+#[cfg(windows)]
+fn default_config_dir() -> Option<PathBuf> {
+    std::env::var_os("APPDATA")
+        .map(PathBuf::from)
+        .map(|dir| dir.join("sindri"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_override_wins_over_env_and_default() {
+        let resolver = PathResolver::resolve(Some(PathBuf::from("/tmp/explicit"))).unwrap();
+        assert_eq!(resolver.config_dir(), Path::new("/tmp/explicit"));
+        assert_eq!(resolver.extensions_dir(), Path::new("/tmp/explicit/extensions"));
+    }
+
+    #[test]
+    fn ensure_writable_creates_and_checks_directory() {
+        let tmp = std::env::temp_dir().join(format!(
+            "sindri-path-resolver-test-{}",
+            std::process::id()
+        ));
+        let resolver = PathResolver::resolve(Some(tmp.clone())).unwrap();
+        resolver.ensure_writable().unwrap();
+        assert!(tmp.is_dir());
+        std::fs::remove_dir_all(&tmp).unwrap();
+    }
+}
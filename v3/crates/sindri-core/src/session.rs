@@ -0,0 +1,61 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::Result;
+
+/// Records a `connect` session's transcript to a timestamped file under
+/// [`crate::PathResolver::sessions_dir`], like `script(1)`. Nothing
+/// written through [`Self::record`] is redacted — callers must warn the
+/// user up front that a transcript may capture secrets typed or echoed
+/// during the session.
+pub struct SessionRecorder {
+    file: File,
+    path: PathBuf,
+}
+
+impl SessionRecorder {
+    /// Starts a new recording under `sessions_dir`, named
+    /// `<label>-<unix-timestamp>.log`. Creates `sessions_dir` if missing.
+    pub fn start(sessions_dir: &Path, label: &str) -> Result<Self> {
+        std::fs::create_dir_all(sessions_dir)?;
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let path = sessions_dir.join(format!("{label}-{timestamp}.log"));
+        let file = File::create(&path)?;
+        Ok(Self { file, path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends one line to the transcript.
+    pub fn record(&mut self, line: &str) -> Result<()> {
+        writeln!(self.file, "{line}")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_recorded_lines_to_a_file_under_the_sessions_dir() {
+        let dir = std::env::temp_dir().join(format!(
+            "sindri-session-recorder-test-{}",
+            std::process::id()
+        ));
+        let mut recorder = SessionRecorder::start(&dir, "docker_abc").unwrap();
+        recorder.record("connected").unwrap();
+        recorder.record("disconnected").unwrap();
+
+        let contents = std::fs::read_to_string(recorder.path()).unwrap();
+        assert_eq!(contents, "connected\ndisconnected\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
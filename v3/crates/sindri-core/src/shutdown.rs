@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+/// Listens for SIGINT/SIGTERM and resolves [`Self::cancelled`] exactly once,
+/// so in-flight work (a `deploy`, a Packer build) can race it in a
+/// `tokio::select!` and run its own cleanup instead of being killed
+/// outright. A second signal after the first assumes cleanup is stuck and
+/// force-exits immediately, so pressing Ctrl-C twice never hangs.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+    triggered: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl ShutdownSignal {
+    /// Spawns the signal listener and returns a handle to it.
+    pub fn install() -> Self {
+        let signal = Self {
+            triggered: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        };
+        let background = signal.clone();
+        tokio::spawn(async move {
+            loop {
+                Self::wait_for_signal().await;
+                if background.triggered.swap(true, Ordering::SeqCst) {
+                    std::process::exit(130);
+                }
+                background.notify.notify_waiters();
+            }
+        });
+        signal
+    }
+
+    async fn wait_for_signal() {
+        #[cfg(unix)]
+        {
+            let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                .expect("failed to install SIGTERM handler");
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+
+    /// Whether a shutdown signal has already arrived.
+    pub fn is_triggered(&self) -> bool {
+        self.triggered.load(Ordering::SeqCst)
+    }
+
+    /// Triggers cancellation programmatically — e.g. a global `--timeout`
+    /// expiring — so in-flight work reacts exactly as it would to
+    /// SIGINT/SIGTERM. Unlike the signal listener, a repeated call doesn't
+    /// force-exit; it's a no-op once already triggered.
+    pub fn trigger(&self) {
+        if !self.triggered.swap(true, Ordering::SeqCst) {
+            self.notify.notify_waiters();
+        }
+    }
+
+    /// Resolves once the first shutdown signal arrives. Returns immediately
+    /// if one already has, so callers can't miss a signal that fired before
+    /// they started waiting.
+    pub async fn cancelled(&self) {
+        if self.is_triggered() {
+            return;
+        }
+        self.notify.notified().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cancelled_returns_immediately_once_already_triggered() {
+        let signal = ShutdownSignal {
+            triggered: Arc::new(AtomicBool::new(true)),
+            notify: Arc::new(Notify::new()),
+        };
+        tokio::time::timeout(std::time::Duration::from_millis(50), signal.cancelled())
+            .await
+            .expect("cancelled() should not block when already triggered");
+    }
+
+    #[tokio::test]
+    async fn cancelled_waits_until_notified() {
+        let signal = ShutdownSignal {
+            triggered: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        };
+        let waiting = signal.clone();
+        let task = tokio::spawn(async move { waiting.cancelled().await });
+
+        // Give the spawned task a chance to start waiting before notifying,
+        // otherwise the notification has nothing registered to wake.
+        tokio::task::yield_now().await;
+        signal.notify.notify_waiters();
+
+        tokio::time::timeout(std::time::Duration::from_millis(50), task)
+            .await
+            .expect("cancelled() should resolve once notified")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn trigger_wakes_up_waiters_without_force_exiting() {
+        let signal = ShutdownSignal { triggered: Arc::new(AtomicBool::new(false)), notify: Arc::new(Notify::new()) };
+        let waiting = signal.clone();
+        let task = tokio::spawn(async move { waiting.cancelled().await });
+
+        tokio::task::yield_now().await;
+        signal.trigger();
+
+        tokio::time::timeout(std::time::Duration::from_millis(50), task)
+            .await
+            .expect("cancelled() should resolve once triggered")
+            .unwrap();
+        assert!(signal.is_triggered());
+    }
+}
@@ -0,0 +1,424 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{SindriError, TemplateContext};
+
+/// Resource and extension settings shared by every [`TargetConfig`],
+/// unless a target overrides a given field itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommonConfig {
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(default)]
+    pub memory: Option<String>,
+    #[serde(default)]
+    pub cpus: Option<u32>,
+    #[serde(default)]
+    pub retry: Option<RetryPolicyConfig>,
+    #[serde(default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// One named deployment target within a [`MultiTargetConfig`] — which
+/// provider it deploys to, plus whichever fields it overrides from
+/// `common` for itself only.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetConfig {
+    pub provider: String,
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(default)]
+    pub memory: Option<String>,
+    #[serde(default)]
+    pub cpus: Option<u32>,
+    #[serde(default)]
+    pub retry: Option<RetryPolicyConfig>,
+    #[serde(default)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// A provider command retry policy override, read from `sindri.yaml`'s
+/// `common.retry` or a target's own `retry`. Overrides the defaults in
+/// `sindri_providers::RetryPolicy` for that provider's command execution —
+/// the only retry engine this workspace has today. There's no separate
+/// retry engine for registry fetches or secret resolution yet, so this
+/// is the only operation class a config can tune.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicyConfig {
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+}
+
+impl RetryPolicyConfig {
+    /// Rejects a policy that would never run (`max_attempts` of zero) or a
+    /// backoff base over a minute, which is almost certainly a typo (a
+    /// `base_delay_ms` that large turns into a multi-hour wait once
+    /// multiplied by attempt number).
+    pub fn validate(&self) -> Result<(), SindriError> {
+        if self.max_attempts == 0 {
+            return Err(SindriError::Config("retry.max_attempts must be at least 1".to_string()));
+        }
+        if self.base_delay_ms > 60_000 {
+            return Err(SindriError::Config(format!(
+                "retry.base_delay_ms of {} is over a minute; that's almost certainly a typo",
+                self.base_delay_ms
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A `sindri.yaml` declaring multiple named deployment targets — e.g.
+/// `local` on Docker, `cloud` on Fly — that share `common` resource and
+/// extension settings, each overriding only the fields it needs to
+/// differ on. Lets a project keep one config file instead of
+/// near-duplicate ones per provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MultiTargetConfig {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub common: CommonConfig,
+    pub targets: BTreeMap<String, TargetConfig>,
+}
+
+/// The JSON Schema for a [`MultiTargetConfig`] `sindri.yaml`, for editor
+/// integration (point a YAML LSP at it). The `retry` bounds mirror
+/// [`RetryPolicyConfig::validate`] exactly, so an editor flags a bad
+/// `max_attempts`/`base_delay_ms` before [`MultiTargetConfig::resolve`]
+/// would reject it at load time.
+///
+/// Hand-written rather than derived, same as
+/// `sindri_extensions::profile_json_schema`; revisit if these types grow
+/// fields often enough that the two drift. Not versioned per config
+/// version yet — there's only ever been one shape of `sindri.yaml`.
+pub fn json_schema() -> Value {
+    let retry_schema = serde_json::json!({
+        "type": "object",
+        "additionalProperties": false,
+        "required": ["max_attempts", "base_delay_ms"],
+        "properties": {
+            "max_attempts": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "How many attempts before giving up. Must be at least 1."
+            },
+            "base_delay_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": 60_000,
+                "description": "Base backoff delay in milliseconds. Over a minute is rejected as almost certainly a typo."
+            }
+        }
+    });
+    let resource_properties = serde_json::json!({
+        "profile": {
+            "type": "string",
+            "description": "Extension profile name to apply."
+        },
+        "memory": {
+            "type": "string",
+            "description": "Memory limit, e.g. \"2gb\"."
+        },
+        "cpus": {
+            "type": "integer",
+            "minimum": 1,
+            "description": "CPU count."
+        },
+        "retry": retry_schema,
+        "extra": {
+            "type": "object",
+            "description": "Provider-specific fields passed through to the template as-is."
+        }
+    });
+
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "MultiTargetConfig",
+        "type": "object",
+        "required": ["targets"],
+        "additionalProperties": false,
+        "properties": {
+            "name": {
+                "type": "string",
+                "description": "Deployment name, threaded into the rendered template as `name`."
+            },
+            "common": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": resource_properties,
+                "description": "Resource and extension settings shared by every target, unless a target overrides a given field itself."
+            },
+            "targets": {
+                "type": "object",
+                "description": "Named deployment targets, keyed by target name (e.g. \"local\", \"cloud\").",
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "required": ["provider"],
+                    "properties": {
+                        "provider": {
+                            "type": "string",
+                            "description": "Cloud provider to deploy this target to."
+                        },
+                        "profile": resource_properties["profile"].clone(),
+                        "memory": resource_properties["memory"].clone(),
+                        "cpus": resource_properties["cpus"].clone(),
+                        "retry": resource_properties["retry"].clone(),
+                        "extra": resource_properties["extra"].clone()
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// The file format a `sindri.yaml` (a [`MultiTargetConfig`]) was read
+/// from, detected from its extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Yaml => "YAML",
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+        }
+    }
+
+    /// Detects the format from a file's extension, defaulting to YAML for
+    /// an unrecognized or missing extension (the format this repo has
+    /// always used, `sindri.yaml`).
+    fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::Json,
+            Some("toml") => Self::Toml,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+impl MultiTargetConfig {
+    /// Loads a `sindri.yaml` from a YAML, JSON, or TOML file, detected
+    /// from the file's extension. Whichever format it's in, the result is
+    /// the same [`MultiTargetConfig`] and subject to the same
+    /// [`resolve`](Self::resolve).
+    pub fn load(path: &Path) -> Result<Self, SindriError> {
+        let raw = std::fs::read_to_string(path)?;
+        let format = ConfigFormat::from_extension(path);
+        Self::parse(&raw, format)
+            .map_err(|reason| SindriError::Config(format!("{}: {reason}", path.display())))
+    }
+
+    fn parse(raw: &str, format: ConfigFormat) -> Result<Self, String> {
+        let result = match format {
+            ConfigFormat::Yaml => serde_yaml::from_str(raw).map_err(|e| e.to_string()),
+            ConfigFormat::Json => serde_json::from_str(raw).map_err(|e| e.to_string()),
+            ConfigFormat::Toml => toml::from_str(raw).map_err(|e| e.to_string()),
+        };
+        result.map_err(|reason| format!("{} parse error: {reason}", format.name()))
+    }
+
+    /// Merges `common` with the named target's overrides into a
+    /// [`TemplateContext`], along with which provider to deploy it to and
+    /// the retry policy override to apply to that provider's commands, if
+    /// either `common` or the target declared one. Errors helpfully,
+    /// listing the targets that do exist, if `target` isn't declared, or
+    /// if the resolved `retry` override fails [`RetryPolicyConfig::validate`].
+    pub fn resolve(
+        &self,
+        target: &str,
+    ) -> Result<(String, TemplateContext, Option<RetryPolicyConfig>), SindriError> {
+        let selected = self.targets.get(target).ok_or_else(|| {
+            let mut known: Vec<&str> = self.targets.keys().map(String::as_str).collect();
+            known.sort();
+            SindriError::Config(format!(
+                "no target {target:?} in this config; known targets: {}",
+                if known.is_empty() { "(none declared)".to_string() } else { known.join(", ") }
+            ))
+        })?;
+
+        let mut builder = TemplateContext::builder().name(self.name.clone());
+        if let Some(profile) = selected.profile.clone().or_else(|| self.common.profile.clone()) {
+            builder = builder.profile(profile);
+        }
+        if let Some(memory) = selected.memory.clone().or_else(|| self.common.memory.clone()) {
+            builder = builder.memory(memory);
+        }
+        if let Some(cpus) = selected.cpus.or(self.common.cpus) {
+            builder = builder.cpus(cpus);
+        }
+        let mut extra = self.common.extra.clone();
+        extra.extend(selected.extra.clone());
+        builder = builder.extras(extra);
+
+        let retry = selected.retry.clone().or_else(|| self.common.retry.clone());
+        if let Some(retry) = &retry {
+            retry.validate()?;
+        }
+
+        Ok((selected.provider.clone(), builder.build()?, retry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> MultiTargetConfig {
+        let mut targets = BTreeMap::new();
+        targets.insert(
+            "local".to_string(),
+            TargetConfig { provider: "docker".to_string(), ..Default::default() },
+        );
+        targets.insert(
+            "cloud".to_string(),
+            TargetConfig {
+                provider: "fly".to_string(),
+                memory: Some("4gb".to_string()),
+                ..Default::default()
+            },
+        );
+        MultiTargetConfig {
+            name: "my-app".to_string(),
+            common: CommonConfig { memory: Some("2gb".to_string()), ..Default::default() },
+            targets,
+        }
+    }
+
+    #[test]
+    fn a_target_without_overrides_inherits_common_fields() {
+        let (provider, context, retry) = sample().resolve("local").unwrap();
+        assert_eq!(provider, "docker");
+        assert_eq!(context.memory, Some("2gb".to_string()));
+        assert!(retry.is_none());
+    }
+
+    #[test]
+    fn a_target_override_wins_over_common() {
+        let (provider, context, _) = sample().resolve("cloud").unwrap();
+        assert_eq!(provider, "fly");
+        assert_eq!(context.memory, Some("4gb".to_string()));
+    }
+
+    #[test]
+    fn an_unknown_target_lists_the_ones_that_do_exist() {
+        let err = sample().resolve("staging").unwrap_err().to_string();
+        assert!(err.contains("cloud"));
+        assert!(err.contains("local"));
+    }
+
+    #[test]
+    fn a_target_inherits_retry_from_common() {
+        let mut config = sample();
+        config.common.retry = Some(RetryPolicyConfig { max_attempts: 5, base_delay_ms: 500 });
+        let (_, _, retry) = config.resolve("local").unwrap();
+        assert_eq!(retry.unwrap().max_attempts, 5);
+    }
+
+    #[test]
+    fn a_target_retry_override_wins_over_common() {
+        let mut config = sample();
+        config.common.retry = Some(RetryPolicyConfig { max_attempts: 5, base_delay_ms: 500 });
+        config.targets.get_mut("cloud").unwrap().retry =
+            Some(RetryPolicyConfig { max_attempts: 10, base_delay_ms: 1000 });
+        let (_, _, retry) = config.resolve("cloud").unwrap();
+        assert_eq!(retry.unwrap().max_attempts, 10);
+    }
+
+    #[test]
+    fn zero_max_attempts_fails_validation() {
+        let mut config = sample();
+        config.common.retry = Some(RetryPolicyConfig { max_attempts: 0, base_delay_ms: 200 });
+        let err = config.resolve("local").unwrap_err().to_string();
+        assert!(err.contains("max_attempts"));
+    }
+
+    #[test]
+    fn a_backoff_over_a_minute_fails_validation() {
+        let mut config = sample();
+        config.common.retry = Some(RetryPolicyConfig { max_attempts: 3, base_delay_ms: 60_001 });
+        let err = config.resolve("local").unwrap_err().to_string();
+        assert!(err.contains("base_delay_ms"));
+    }
+
+    #[test]
+    fn parses_from_yaml() {
+        let config: MultiTargetConfig = serde_yaml::from_str(
+            "name: my-app\ncommon:\n  memory: 2gb\ntargets:\n  local:\n    provider: docker\n  cloud:\n    provider: fly\n    memory: 4gb\n",
+        )
+        .unwrap();
+        assert_eq!(config.resolve("cloud").unwrap().0, "fly");
+    }
+
+    #[test]
+    fn loads_a_config_from_yaml() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("sindri.yaml");
+        std::fs::write(&path, "name: my-app\ntargets:\n  local:\n    provider: docker\n").unwrap();
+
+        let config = MultiTargetConfig::load(&path).unwrap();
+        assert_eq!(config.resolve("local").unwrap().0, "docker");
+    }
+
+    #[test]
+    fn loads_a_config_from_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("sindri.json");
+        std::fs::write(
+            &path,
+            r#"{"name": "my-app", "targets": {"local": {"provider": "docker"}}}"#,
+        )
+        .unwrap();
+
+        let config = MultiTargetConfig::load(&path).unwrap();
+        assert_eq!(config.resolve("local").unwrap().0, "docker");
+    }
+
+    #[test]
+    fn loads_a_config_from_toml() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("sindri.toml");
+        std::fs::write(&path, "name = \"my-app\"\n\n[targets.local]\nprovider = \"docker\"\n").unwrap();
+
+        let config = MultiTargetConfig::load(&path).unwrap();
+        assert_eq!(config.resolve("local").unwrap().0, "docker");
+    }
+
+    #[test]
+    fn names_the_format_in_a_load_parse_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("sindri.toml");
+        std::fs::write(&path, "not valid toml {{{").unwrap();
+
+        let err = MultiTargetConfig::load(&path).unwrap_err().to_string();
+        assert!(err.contains("TOML parse error"), "unexpected message: {err}");
+    }
+
+    #[test]
+    fn json_schema_declares_targets_as_required() {
+        let schema = json_schema();
+        assert_eq!(schema["required"], serde_json::json!(["targets"]));
+        assert!(schema["properties"]["common"].is_object());
+        assert!(schema["properties"]["targets"]["additionalProperties"]["required"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("provider")));
+    }
+
+    #[test]
+    fn json_schema_retry_bounds_match_validate() {
+        let schema = json_schema();
+        let retry = &schema["properties"]["common"]["properties"]["retry"];
+        assert_eq!(retry["properties"]["max_attempts"]["minimum"], serde_json::json!(1));
+        assert_eq!(retry["properties"]["base_delay_ms"]["maximum"], serde_json::json!(60_000));
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::SindriError;
+
+/// Keys [`TemplateContext`] reserves for its own built-in fields. A
+/// user-defined variable passed via [`TemplateContextBuilder::extra`] may
+/// not reuse one of these — it's always nested under `extra` instead, so
+/// it can never shadow a built-in a provider template relies on.
+pub const RESERVED_KEYS: [&str; 4] = ["name", "profile", "memory", "cpus"];
+
+/// The variables exposed to a provider template when it's rendered.
+/// Built-in templates reference [`Self::name`]/[`Self::profile`]/
+/// [`Self::memory`]/[`Self::cpus`] directly; a custom override template
+/// can reference anything passed via [`TemplateContextBuilder::extra`] as
+/// `{{ extra.cost_center }}`. Built-in templates simply don't reference
+/// `extra`, so unknown extras are ignored rather than causing an error.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateContext {
+    pub name: String,
+    pub profile: Option<String>,
+    pub memory: Option<String>,
+    pub cpus: Option<u32>,
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl TemplateContext {
+    pub fn builder() -> TemplateContextBuilder {
+        TemplateContextBuilder::default()
+    }
+
+    /// Renders this context into a [`tera::Context`], with the built-in
+    /// fields at the top level and every `extra` entry nested under an
+    /// `extra` key, so user-defined variables can never collide with a
+    /// reserved one.
+    pub fn to_tera_context(&self) -> tera::Context {
+        let mut context = tera::Context::new();
+        context.insert("name", &self.name);
+        context.insert("profile", &self.profile);
+        context.insert("memory", &self.memory);
+        context.insert("cpus", &self.cpus);
+        context.insert("extra", &self.extra);
+        context
+    }
+}
+
+/// Builds a [`TemplateContext`]. See [`TemplateContext::builder`].
+#[derive(Debug, Default)]
+pub struct TemplateContextBuilder {
+    name: String,
+    profile: Option<String>,
+    memory: Option<String>,
+    cpus: Option<u32>,
+    extra: BTreeMap<String, Value>,
+}
+
+impl TemplateContextBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    pub fn memory(mut self, memory: impl Into<String>) -> Self {
+        self.memory = Some(memory.into());
+        self
+    }
+
+    pub fn cpus(mut self, cpus: u32) -> Self {
+        self.cpus = Some(cpus);
+        self
+    }
+
+    /// Adds a single user-defined variable, merged into the Tera context
+    /// under `extra` rather than the top level. Collisions with
+    /// [`RESERVED_KEYS`] are caught by [`Self::build`], not here, so calls
+    /// can be chained freely.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+
+    /// Merges every entry of `extras` in one call. See [`Self::extra`].
+    pub fn extras(mut self, extras: impl IntoIterator<Item = (String, Value)>) -> Self {
+        self.extra.extend(extras);
+        self
+    }
+
+    /// Finalizes the context. Fails if an `extra` key collides with a
+    /// [`RESERVED_KEYS`] built-in, since that variable would otherwise be
+    /// silently shadowed once merged into the Tera context.
+    pub fn build(self) -> Result<TemplateContext, SindriError> {
+        if let Some(reserved) = self.extra.keys().find(|key| RESERVED_KEYS.contains(&key.as_str())) {
+            return Err(SindriError::Config(format!(
+                "{reserved:?} is a reserved template variable; set it via the builder's dedicated method instead"
+            )));
+        }
+
+        Ok(TemplateContext {
+            name: self.name,
+            profile: self.profile,
+            memory: self.memory,
+            cpus: self.cpus,
+            extra: self.extra,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_extras_under_the_extra_key() {
+        let context = TemplateContext::builder()
+            .name("dev-box")
+            .extra("cost_center", "eng-42")
+            .build()
+            .unwrap();
+        assert_eq!(context.extra.get("cost_center"), Some(&Value::from("eng-42")));
+    }
+
+    #[test]
+    fn rejects_an_extra_that_collides_with_a_reserved_key() {
+        let result = TemplateContext::builder().extra("profile", "prod").build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn built_in_fields_round_trip_into_the_tera_context() {
+        let context = TemplateContext::builder()
+            .name("dev-box")
+            .profile("gpu")
+            .cpus(4)
+            .build()
+            .unwrap();
+        let tera_context = context.to_tera_context();
+        assert_eq!(tera_context.get("name").and_then(Value::as_str), Some("dev-box"));
+        assert_eq!(tera_context.get("cpus").and_then(Value::as_u64), Some(4));
+    }
+}
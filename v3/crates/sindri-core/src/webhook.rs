@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// How long to wait for a webhook endpoint to respond before giving up on
+/// it. Deliberately short — a slow dashboard shouldn't make `deploy`/
+/// `destroy` feel slow.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// What stage of a deploy/destroy a [`DeployEvent`] reports.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployOutcome {
+    Start,
+    Success,
+    Failure,
+    Destroyed,
+}
+
+/// Payload POSTed to a configured webhook on deploy start/success/failure
+/// and on destroy, for feeding an external dashboard or chat notification.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployEvent {
+    pub provider: String,
+    pub instance_id: String,
+    pub outcome: DeployOutcome,
+    #[serde(with = "duration_secs")]
+    pub duration: Duration,
+}
+
+/// POSTs `event` as JSON to `url`, logging a warning and returning
+/// normally on any failure — a webhook endpoint being down or slow must
+/// never fail the deploy/destroy it's reporting on.
+pub async fn send_webhook_event(url: &str, event: &DeployEvent) {
+    let body = match serde_json::to_vec(event) {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::warn!(%err, "failed to encode webhook event, not sending");
+            return;
+        }
+    };
+
+    let client = crate::http::build_http_client();
+    let request = client.post(url).header("content-type", "application/json").body(body).send();
+
+    match tokio::time::timeout(WEBHOOK_TIMEOUT, request).await {
+        Ok(Ok(response)) if !response.status().is_success() => {
+            tracing::warn!(status = %response.status(), url, "webhook endpoint returned an error status");
+        }
+        Ok(Ok(_)) => {}
+        Ok(Err(err)) => tracing::warn!(%err, url, "failed to reach webhook endpoint"),
+        Err(_) => tracing::warn!(url, timeout = ?WEBHOOK_TIMEOUT, "webhook endpoint did not respond in time"),
+    }
+}
+
+mod duration_secs {
+    use std::time::Duration;
+
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_outcome_as_lowercase_snake_case() {
+        let event = DeployEvent {
+            provider: "docker".to_string(),
+            instance_id: "docker_abc".to_string(),
+            outcome: DeployOutcome::Success,
+            duration: Duration::from_secs(5),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["outcome"], "success");
+        assert_eq!(json["duration"], 5);
+    }
+}
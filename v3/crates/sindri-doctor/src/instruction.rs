@@ -0,0 +1,84 @@
+use crate::pkg::PackageManager;
+
+/// One way to install a missing tool: either a copy-pasteable command for a
+/// specific package manager, or a generic fallback (typically a download
+/// URL) used when no manager-specific instruction applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallInstruction {
+    PackageManager { manager: PackageManager, command: String },
+    Generic { command: String },
+}
+
+impl InstallInstruction {
+    pub fn package_manager(manager: PackageManager, command: impl Into<String>) -> Self {
+        Self::PackageManager { manager, command: command.into() }
+    }
+
+    pub fn generic(command: impl Into<String>) -> Self {
+        Self::Generic { command: command.into() }
+    }
+}
+
+/// Picks the command to show for a missing tool: the instruction matching
+/// `preferred`'s package manager if there is one, else the generic
+/// fallback, else `None` if neither is declared.
+pub fn select_install_command(
+    instructions: &[InstallInstruction],
+    preferred: Option<PackageManager>,
+) -> Option<&str> {
+    if let Some(manager) = preferred {
+        let matching = instructions.iter().find_map(|instruction| match instruction {
+            InstallInstruction::PackageManager { manager: m, command } if *m == manager => {
+                Some(command.as_str())
+            }
+            _ => None,
+        });
+        if matching.is_some() {
+            return matching;
+        }
+    }
+
+    instructions.iter().find_map(|instruction| match instruction {
+        InstallInstruction::Generic { command } => Some(command.as_str()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instructions() -> Vec<InstallInstruction> {
+        vec![
+            InstallInstruction::package_manager(PackageManager::Brew, "brew install git"),
+            InstallInstruction::package_manager(PackageManager::Apt, "sudo apt-get install git"),
+            InstallInstruction::generic("https://git-scm.com/downloads"),
+        ]
+    }
+
+    #[test]
+    fn picks_the_preferred_managers_command() {
+        let instructions = instructions();
+        let selected = select_install_command(&instructions, Some(PackageManager::Apt));
+        assert_eq!(selected, Some("sudo apt-get install git"));
+    }
+
+    #[test]
+    fn falls_back_to_the_generic_instruction_when_no_manager_matches() {
+        let instructions = instructions();
+        let selected = select_install_command(&instructions, Some(PackageManager::Dnf));
+        assert_eq!(selected, Some("https://git-scm.com/downloads"));
+    }
+
+    #[test]
+    fn falls_back_to_the_generic_instruction_with_no_preference_at_all() {
+        let instructions = instructions();
+        let selected = select_install_command(&instructions, None);
+        assert_eq!(selected, Some("https://git-scm.com/downloads"));
+    }
+
+    #[test]
+    fn no_instructions_at_all_means_nothing_to_suggest() {
+        assert_eq!(select_install_command(&[], None), None);
+    }
+}
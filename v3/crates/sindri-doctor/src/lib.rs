@@ -0,0 +1,15 @@
+//! Environment diagnostics: checking for missing or misconfigured tools.
+
+mod instruction;
+mod network;
+mod pkg;
+mod report;
+mod tool;
+mod watch;
+
+pub use instruction::{select_install_command, InstallInstruction};
+pub use network::{check_domains, DomainCheck, DEFAULT_NETWORK_TIMEOUT};
+pub use pkg::{detect_package_managers, preferred, PackageManager};
+pub use report::{Doctor, DiagnosticReport, ToolResult};
+pub use tool::{ToolDefinition, ToolExplanation, ToolStatus};
+pub use watch::{watch, MIN_WATCH_INTERVAL};
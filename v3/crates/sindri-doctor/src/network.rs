@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Default time-box for a single domain probe, so one blocked host (e.g.
+/// behind a corporate proxy) doesn't hang the rest of `doctor --network`.
+pub const DEFAULT_NETWORK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether a required domain was reachable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainCheck {
+    pub domain: String,
+    pub reachable: bool,
+    /// Why the probe failed, when `reachable` is false.
+    pub reason: Option<String>,
+}
+
+/// Probes every domain in `domains` concurrently with a `HEAD` request,
+/// time-boxing each one to `timeout`. Any response at all (including a
+/// 4xx/5xx) counts as reachable — this checks network path, not whether
+/// the endpoint likes the request.
+///
+/// Uses [`sindri_core::build_http_client`], so this honors the same
+/// proxy/CA configuration as every other HTTP client in the CLI and
+/// reports what the proxy actually allows through.
+pub async fn check_domains(domains: &[String], timeout: Duration) -> Vec<DomainCheck> {
+    let client = sindri_core::build_http_client();
+    futures::future::join_all(domains.iter().map(|domain| check_domain(&client, domain, timeout))).await
+}
+
+async fn check_domain(client: &reqwest::Client, domain: &str, timeout: Duration) -> DomainCheck {
+    let url = format!("https://{domain}");
+    match client.head(&url).timeout(timeout).send().await {
+        Ok(_) => DomainCheck { domain: domain.to_string(), reachable: true, reason: None },
+        Err(err) => DomainCheck {
+            domain: domain.to_string(),
+            reachable: false,
+            reason: Some(err.to_string()),
+        },
+    }
+}
@@ -0,0 +1,77 @@
+/// A package manager the doctor knows how to suggest an install command
+/// for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageManager {
+    Brew,
+    Port,
+    Apt,
+    Dnf,
+    Winget,
+}
+
+impl PackageManager {
+    fn binary(&self) -> &'static str {
+        match self {
+            Self::Brew => "brew",
+            Self::Port => "port",
+            Self::Apt => "apt",
+            Self::Dnf => "dnf",
+            Self::Winget => "winget",
+        }
+    }
+}
+
+impl std::fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.binary())
+    }
+}
+
+/// Preference order when more than one manager is detected (e.g. macOS
+/// with both Homebrew and MacPorts installed) — earlier wins.
+const PRIORITY: [PackageManager; 5] = [
+    PackageManager::Brew,
+    PackageManager::Port,
+    PackageManager::Apt,
+    PackageManager::Dnf,
+    PackageManager::Winget,
+];
+
+/// Checks which package managers are usable on this machine, in priority
+/// order.
+pub async fn detect_package_managers() -> Vec<PackageManager> {
+    let mut found = Vec::new();
+    for manager in PRIORITY {
+        let output = tokio::process::Command::new(manager.binary())
+            .arg("--version")
+            .output()
+            .await;
+        if matches!(output, Ok(o) if o.status.success()) {
+            found.push(manager);
+        }
+    }
+    found
+}
+
+/// The one manager to suggest commands for, when several are available.
+pub fn preferred(available: &[PackageManager]) -> Option<PackageManager> {
+    PRIORITY.into_iter().find(|manager| available.contains(manager))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_brew_over_port_when_both_are_available() {
+        assert_eq!(
+            preferred(&[PackageManager::Port, PackageManager::Brew]),
+            Some(PackageManager::Brew)
+        );
+    }
+
+    #[test]
+    fn no_managers_available_means_no_preference() {
+        assert_eq!(preferred(&[]), None);
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::tool::{ToolDefinition, ToolStatus};
+
+/// A single tool's status from one [`Doctor::run`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolResult {
+    pub name: String,
+    pub status: ToolStatus,
+    /// How long the check took to run. Checks run concurrently, so this is
+    /// the tool's own wall-clock time, not a share of the total.
+    #[serde(with = "duration_millis")]
+    pub elapsed: Duration,
+}
+
+/// The outcome of running every configured [`ToolDefinition`] once.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticReport {
+    pub results: Vec<ToolResult>,
+}
+
+impl DiagnosticReport {
+    pub fn is_healthy(&self) -> bool {
+        self.results.iter().all(|r| r.status == ToolStatus::Available)
+    }
+
+    /// Results ordered slowest-first, for `doctor --verbose`'s "slowest
+    /// checks" summary.
+    pub fn slowest_first(&self) -> Vec<&ToolResult> {
+        let mut results: Vec<&ToolResult> = self.results.iter().collect();
+        results.sort_by_key(|result| std::cmp::Reverse(result.elapsed));
+        results
+    }
+}
+
+/// Runs a fixed set of tool checks and reports their status.
+#[derive(Debug, Clone)]
+pub struct Doctor {
+    tools: Vec<ToolDefinition>,
+}
+
+impl Doctor {
+    pub fn new(tools: Vec<ToolDefinition>) -> Self {
+        Self { tools }
+    }
+
+    /// The configured tools, in the same order [`Self::run`] checks and
+    /// reports them in.
+    pub fn tools(&self) -> &[ToolDefinition] {
+        &self.tools
+    }
+
+    /// Runs every tool's check concurrently, so one slow cloud CLI doing a
+    /// network call doesn't hold up the rest. Results keep the tools'
+    /// configured order regardless of completion order.
+    pub async fn run(&self) -> DiagnosticReport {
+        let results = futures::future::join_all(self.tools.iter().map(|tool| async move {
+            let start = Instant::now();
+            let status = tool.check().await;
+            ToolResult {
+                name: tool.name.clone(),
+                status,
+                elapsed: start.elapsed(),
+            }
+        }))
+        .await;
+        DiagnosticReport { results }
+    }
+}
+
+/// (De)serializes [`Duration`] as whole milliseconds rather than serde's
+/// default `{secs, nanos}` struct, so timings stay a plain number in JSON.
+mod duration_millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(value.as_millis())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let millis = u64::deserialize(deserializer)?;
+        Ok(Duration::from_millis(millis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_every_configured_tool_in_order() {
+        let doctor = Doctor::new(vec![
+            ToolDefinition::new("true", "true"),
+            ToolDefinition::new("nope", "definitely-not-a-real-binary-xyz"),
+        ]);
+        let report = doctor.run().await;
+        assert_eq!(
+            report.results.iter().map(|r| (r.name.as_str(), r.status)).collect::<Vec<_>>(),
+            vec![("true", ToolStatus::Available), ("nope", ToolStatus::Missing)]
+        );
+        assert!(!report.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn a_report_with_every_tool_available_is_healthy() {
+        let doctor = Doctor::new(vec![ToolDefinition::new("true", "true")]);
+        assert!(doctor.run().await.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn slowest_first_orders_results_by_descending_elapsed() {
+        let report = DiagnosticReport {
+            results: vec![
+                ToolResult { name: "fast".to_string(), status: ToolStatus::Available, elapsed: Duration::from_millis(5) },
+                ToolResult { name: "slow".to_string(), status: ToolStatus::Available, elapsed: Duration::from_millis(500) },
+            ],
+        };
+        let names: Vec<&str> = report.slowest_first().iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["slow", "fast"]);
+    }
+}
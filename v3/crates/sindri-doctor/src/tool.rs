@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use crate::instruction::InstallInstruction;
+
+/// A tool the doctor checks for by running a version/help command and
+/// looking at whether it exits successfully.
+#[derive(Debug, Clone)]
+pub struct ToolDefinition {
+    pub name: String,
+    binary: String,
+    check_args: Vec<String>,
+    install: Vec<InstallInstruction>,
+}
+
+impl ToolDefinition {
+    /// Checks `binary --version`. Use [`Self::with_check_args`] if the
+    /// tool needs something else (e.g. `docker info` instead of
+    /// `docker --version`, which succeeds even without a running daemon).
+    pub fn new(name: impl Into<String>, binary: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            binary: binary.into(),
+            check_args: vec!["--version".to_string()],
+            install: Vec::new(),
+        }
+    }
+
+    pub fn with_check_args(mut self, args: Vec<String>) -> Self {
+        self.check_args = args;
+        self
+    }
+
+    pub fn with_install(mut self, install: Vec<InstallInstruction>) -> Self {
+        self.install = install;
+        self
+    }
+
+    pub fn install(&self) -> &[InstallInstruction] {
+        &self.install
+    }
+
+    pub async fn check(&self) -> ToolStatus {
+        let output = tokio::process::Command::new(&self.binary)
+            .args(&self.check_args)
+            .output()
+            .await;
+        match output {
+            Ok(output) if output.status.success() => ToolStatus::Available,
+            _ => ToolStatus::Missing,
+        }
+    }
+
+    /// Runs this tool's check the same way [`Self::check`] does, but keeps
+    /// the raw command and its output instead of collapsing it to a
+    /// [`ToolStatus`] — for `doctor --explain`, where seeing exactly what
+    /// was run and what it printed is the point.
+    pub async fn explain(&self) -> ToolExplanation {
+        let output = tokio::process::Command::new(&self.binary)
+            .args(&self.check_args)
+            .output()
+            .await;
+        match output {
+            Ok(output) => ToolExplanation {
+                binary: self.binary.clone(),
+                args: self.check_args.clone(),
+                status: if output.status.success() { ToolStatus::Available } else { ToolStatus::Missing },
+                exit_code: output.status.code(),
+                stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            },
+            Err(err) => ToolExplanation {
+                binary: self.binary.clone(),
+                args: self.check_args.clone(),
+                status: ToolStatus::Missing,
+                exit_code: None,
+                stdout: String::new(),
+                stderr: err.to_string(),
+            },
+        }
+    }
+}
+
+/// What [`ToolDefinition::explain`] found: the exact command it ran and
+/// everything that came back, for debugging a misdetected tool.
+#[derive(Debug, Clone)]
+pub struct ToolExplanation {
+    pub binary: String,
+    pub args: Vec<String>,
+    pub status: ToolStatus,
+    /// `None` when the binary couldn't even be spawned (e.g. not on `PATH`).
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Whether a [`ToolDefinition`]'s check succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToolStatus {
+    Available,
+    Missing,
+}
+
+impl std::fmt::Display for ToolStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Available => write!(f, "available"),
+            Self::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_present_binary_that_ignores_unknown_flags_is_available() {
+        // `true` exits 0 regardless of arguments on every platform we target.
+        let tool = ToolDefinition::new("true", "true");
+        assert_eq!(tool.check().await, ToolStatus::Available);
+    }
+
+    #[tokio::test]
+    async fn a_binary_that_does_not_exist_is_missing() {
+        let tool = ToolDefinition::new("nope", "definitely-not-a-real-binary-xyz");
+        assert_eq!(tool.check().await, ToolStatus::Missing);
+    }
+
+    #[tokio::test]
+    async fn a_failing_check_command_is_missing() {
+        let tool = ToolDefinition::new("false", "false");
+        assert_eq!(tool.check().await, ToolStatus::Missing);
+    }
+
+    #[tokio::test]
+    async fn explain_reports_the_command_exit_code_and_status() {
+        let tool = ToolDefinition::new("false", "false");
+        let explanation = tool.explain().await;
+        assert_eq!(explanation.binary, "false");
+        assert_eq!(explanation.status, ToolStatus::Missing);
+        assert_eq!(explanation.exit_code, Some(1));
+    }
+
+    #[tokio::test]
+    async fn explain_reports_no_exit_code_when_the_binary_cannot_be_spawned() {
+        let tool = ToolDefinition::new("nope", "definitely-not-a-real-binary-xyz");
+        let explanation = tool.explain().await;
+        assert_eq!(explanation.status, ToolStatus::Missing);
+        assert_eq!(explanation.exit_code, None);
+    }
+}
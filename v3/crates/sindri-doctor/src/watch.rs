@@ -0,0 +1,127 @@
+use std::io::Write;
+use std::time::Duration;
+
+use crate::report::{Doctor, DiagnosticReport};
+use crate::tool::ToolStatus;
+
+/// Below this, a `--watch` refresh would burn CPU/network for no visible
+/// benefit (tool checks are cheap, but still shell out). Callers at the CLI
+/// boundary should clamp user-supplied intervals to this before calling
+/// [`watch`].
+pub const MIN_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Re-runs `doctor` on `interval`, clearing the screen and rewriting the
+/// report to `out` each time with a marker on any tool whose status
+/// changed since the previous run. Stops as soon as `should_stop` returns
+/// `true`, checked right after each render.
+pub async fn watch(
+    doctor: &Doctor,
+    interval: Duration,
+    out: &mut impl Write,
+    mut should_stop: impl FnMut() -> bool,
+) -> std::io::Result<()> {
+    let mut previous: Option<DiagnosticReport> = None;
+
+    loop {
+        let report = doctor.run().await;
+        render(out, &report, previous.as_ref())?;
+        previous = Some(report);
+
+        if should_stop() {
+            return Ok(());
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+fn render(
+    out: &mut impl Write,
+    report: &DiagnosticReport,
+    previous: Option<&DiagnosticReport>,
+) -> std::io::Result<()> {
+    write!(out, "\x1B[2J\x1B[H")?;
+    for (name, status, changed) in diff(report, previous) {
+        if changed {
+            writeln!(out, "{name:<24} {status} (changed)")?;
+        } else {
+            writeln!(out, "{name:<24} {status}")?;
+        }
+    }
+    out.flush()
+}
+
+/// Pairs each result in `report` with whether its status differs from the
+/// same-named tool in `previous` (a new tool, with nothing to compare
+/// against, counts as unchanged).
+fn diff<'a>(
+    report: &'a DiagnosticReport,
+    previous: Option<&DiagnosticReport>,
+) -> Vec<(&'a str, ToolStatus, bool)> {
+    report
+        .results
+        .iter()
+        .map(|result| {
+            let changed = previous
+                .and_then(|prev| prev.results.iter().find(|r| r.name == result.name))
+                .is_some_and(|prev| prev.status != result.status);
+            (result.name.as_str(), result.status, changed)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::ToolResult;
+    use crate::tool::ToolDefinition;
+
+    fn report(status: ToolStatus) -> DiagnosticReport {
+        DiagnosticReport {
+            results: vec![ToolResult { name: "docker".to_string(), status, elapsed: Duration::ZERO }],
+        }
+    }
+
+    #[test]
+    fn a_tool_with_no_previous_run_is_not_flagged_as_changed() {
+        let current = report(ToolStatus::Available);
+        assert_eq!(diff(&current, None), vec![("docker", ToolStatus::Available, false)]);
+    }
+
+    #[test]
+    fn a_tool_whose_status_flipped_is_flagged_as_changed() {
+        let previous = report(ToolStatus::Missing);
+        let current = report(ToolStatus::Available);
+        assert_eq!(
+            diff(&current, Some(&previous)),
+            vec![("docker", ToolStatus::Available, true)]
+        );
+    }
+
+    #[test]
+    fn an_unchanged_tool_is_not_flagged() {
+        let previous = report(ToolStatus::Available);
+        let current = report(ToolStatus::Available);
+        assert_eq!(
+            diff(&current, Some(&previous)),
+            vec![("docker", ToolStatus::Available, false)]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_after_should_stop_returns_true() {
+        let doctor = Doctor::new(vec![ToolDefinition::new("true", "true")]);
+        let mut out = Vec::new();
+        let mut calls = 0;
+
+        watch(&doctor, Duration::from_millis(1), &mut out, || {
+            calls += 1;
+            calls >= 2
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(calls, 2);
+        let rendered = String::from_utf8(out).unwrap();
+        assert_eq!(rendered.matches("true").count(), 2);
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+use crate::{ExtensionError, ExtensionRegistry};
+
+/// Declares, per extension, which CLI releases each extension version
+/// supports. Loaded once from the fetched matrix file and checked with
+/// [`Self::validate`] before it's trusted to pick install versions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompatibilityMatrix {
+    /// CLI releases the matrix is expected to account for, so
+    /// [`Self::validate`] can flag a version nothing covers.
+    #[serde(default)]
+    pub cli_versions: Vec<String>,
+    pub extensions: BTreeMap<String, Vec<CompatibilityEntry>>,
+}
+
+/// One extension version's supported CLI range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityEntry {
+    pub extension_version: String,
+    pub cli_version_req: String,
+}
+
+/// A problem found while [`CompatibilityMatrix::validate`]ing a matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompatibilityProblem {
+    /// The matrix references an extension the registry has never heard of.
+    UnknownExtension { extension: String },
+    /// A `cli_version_req` isn't a parseable semver `VersionReq`.
+    InvalidVersionReq {
+        extension: String,
+        extension_version: String,
+        cli_version_req: String,
+        reason: String,
+    },
+    /// A declared CLI version isn't covered by any entry for this extension.
+    CliVersionGap { extension: String, cli_version: String },
+}
+
+impl fmt::Display for CompatibilityProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownExtension { extension } => {
+                write!(f, "{extension} is not a known extension")
+            }
+            Self::InvalidVersionReq {
+                extension,
+                extension_version,
+                cli_version_req,
+                reason,
+            } => write!(
+                f,
+                "{extension} {extension_version} has an unparseable cli_version_req \"{cli_version_req}\": {reason}"
+            ),
+            Self::CliVersionGap { extension, cli_version } => write!(
+                f,
+                "{extension} has no entry covering CLI version {cli_version}"
+            ),
+        }
+    }
+}
+
+impl CompatibilityMatrix {
+    /// Loads a matrix from JSON at `path`.
+    pub fn load(path: &Path) -> Result<Self, ExtensionError> {
+        let raw = fs::read(path).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(serde_json::from_slice(&raw)?)
+    }
+
+    /// Checks every referenced extension exists in `registry`, every
+    /// `cli_version_req` parses as a semver [`VersionReq`], and every
+    /// declared [`Self::cli_versions`] is covered by at least one entry,
+    /// per extension.
+    pub fn validate(&self, registry: &ExtensionRegistry) -> Vec<CompatibilityProblem> {
+        let known: HashSet<&str> = registry.entries().iter().map(|e| e.name.as_str()).collect();
+        let mut problems = Vec::new();
+
+        for (extension, entries) in &self.extensions {
+            if !known.contains(extension.as_str()) {
+                problems.push(CompatibilityProblem::UnknownExtension {
+                    extension: extension.clone(),
+                });
+            }
+
+            let mut parsed_reqs = Vec::new();
+            for entry in entries {
+                match VersionReq::parse(&entry.cli_version_req) {
+                    Ok(req) => parsed_reqs.push(req),
+                    Err(err) => problems.push(CompatibilityProblem::InvalidVersionReq {
+                        extension: extension.clone(),
+                        extension_version: entry.extension_version.clone(),
+                        cli_version_req: entry.cli_version_req.clone(),
+                        reason: err.to_string(),
+                    }),
+                }
+            }
+
+            for cli_version in &self.cli_versions {
+                let Ok(version) = Version::parse(cli_version) else {
+                    continue;
+                };
+                if !parsed_reqs.iter().any(|req| req.matches(&version)) {
+                    problems.push(CompatibilityProblem::CliVersionGap {
+                        extension: extension.clone(),
+                        cli_version: cli_version.clone(),
+                    });
+                }
+            }
+        }
+
+        problems
+    }
+
+    /// Explains whether `extension_version` supports `cli_version`, per the
+    /// matrix. An extension with no matrix entry at all is treated as
+    /// compatible — the matrix has nothing to say about it.
+    pub fn explain_version(
+        &self,
+        extension: &str,
+        extension_version: &str,
+        cli_version: &Version,
+    ) -> VersionExplanation {
+        let entry = self
+            .extensions
+            .get(extension)
+            .and_then(|entries| entries.iter().find(|e| e.extension_version == extension_version));
+
+        match entry {
+            None => VersionExplanation {
+                cli_version_req: None,
+                compatible: true,
+            },
+            Some(entry) => {
+                let compatible = VersionReq::parse(&entry.cli_version_req)
+                    .map(|req| req.matches(cli_version))
+                    .unwrap_or(false);
+                VersionExplanation {
+                    cli_version_req: Some(entry.cli_version_req.clone()),
+                    compatible,
+                }
+            }
+        }
+    }
+}
+
+/// Why [`CompatibilityMatrix::explain_version`] judged a version compatible
+/// or not with the running CLI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionExplanation {
+    /// The `cli_version_req` that applied, or `None` if the matrix has no
+    /// entry for this extension version.
+    pub cli_version_req: Option<String>,
+    pub compatible: bool,
+}
+
+impl fmt::Display for VersionExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cli_version_req {
+            Some(req) if self.compatible => write!(f, "cli range {req} (compatible)"),
+            Some(req) => write!(f, "cli range {req} (incompatible)"),
+            None => write!(f, "no compatibility data"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtensionEntry;
+
+    fn registry_with(name: &str) -> ExtensionRegistry {
+        ExtensionRegistry::new(vec![ExtensionEntry {
+            name: name.to_string(),
+            ..Default::default()
+        }])
+    }
+
+    #[test]
+    fn well_formed_matrix_has_no_problems() {
+        let matrix = CompatibilityMatrix {
+            cli_versions: vec!["1.0.0".to_string(), "1.5.0".to_string()],
+            extensions: BTreeMap::from([(
+                "kubernetes-tools".to_string(),
+                vec![CompatibilityEntry {
+                    extension_version: "2.0.0".to_string(),
+                    cli_version_req: ">=1.0.0, <2.0.0".to_string(),
+                }],
+            )]),
+        };
+
+        assert!(matrix.validate(&registry_with("kubernetes-tools")).is_empty());
+    }
+
+    #[test]
+    fn flags_an_extension_the_registry_has_never_heard_of() {
+        let matrix = CompatibilityMatrix {
+            cli_versions: vec![],
+            extensions: BTreeMap::from([(
+                "ghost-extension".to_string(),
+                vec![CompatibilityEntry {
+                    extension_version: "1.0.0".to_string(),
+                    cli_version_req: "*".to_string(),
+                }],
+            )]),
+        };
+
+        let problems = matrix.validate(&registry_with("kubernetes-tools"));
+        assert_eq!(
+            problems,
+            vec![CompatibilityProblem::UnknownExtension {
+                extension: "ghost-extension".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn flags_a_version_req_that_does_not_parse_as_semver() {
+        let matrix = CompatibilityMatrix {
+            cli_versions: vec![],
+            extensions: BTreeMap::from([(
+                "rust".to_string(),
+                vec![CompatibilityEntry {
+                    extension_version: "1.0.0".to_string(),
+                    cli_version_req: "not-a-version-req".to_string(),
+                }],
+            )]),
+        };
+
+        let problems = matrix.validate(&registry_with("rust"));
+        assert!(matches!(
+            problems.as_slice(),
+            [CompatibilityProblem::InvalidVersionReq { .. }]
+        ));
+    }
+
+    #[test]
+    fn flags_a_declared_cli_version_no_entry_covers() {
+        let matrix = CompatibilityMatrix {
+            cli_versions: vec!["3.0.0".to_string()],
+            extensions: BTreeMap::from([(
+                "rust".to_string(),
+                vec![CompatibilityEntry {
+                    extension_version: "1.0.0".to_string(),
+                    cli_version_req: ">=1.0.0, <2.0.0".to_string(),
+                }],
+            )]),
+        };
+
+        let problems = matrix.validate(&registry_with("rust"));
+        assert_eq!(
+            problems,
+            vec![CompatibilityProblem::CliVersionGap {
+                extension: "rust".to_string(),
+                cli_version: "3.0.0".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn explains_a_version_the_matrix_has_no_opinion_on_as_compatible() {
+        let matrix = CompatibilityMatrix::default();
+        let explanation = matrix.explain_version("rust", "1.0.0", &Version::parse("3.0.0").unwrap());
+        assert_eq!(explanation.cli_version_req, None);
+        assert!(explanation.compatible);
+    }
+
+    #[test]
+    fn explains_an_incompatible_version_with_its_cli_range() {
+        let matrix = CompatibilityMatrix {
+            cli_versions: vec![],
+            extensions: BTreeMap::from([(
+                "rust".to_string(),
+                vec![CompatibilityEntry {
+                    extension_version: "1.0.0".to_string(),
+                    cli_version_req: "^2".to_string(),
+                }],
+            )]),
+        };
+
+        let explanation = matrix.explain_version("rust", "1.0.0", &Version::parse("3.0.0").unwrap());
+        assert_eq!(explanation.cli_version_req, Some("^2".to_string()));
+        assert!(!explanation.compatible);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::ledger::{unix_now, ExtensionEvent, ExtensionLedger};
+use crate::ExtensionError;
+
+/// One file to render while configuring an extension: copies `source` to
+/// `dest`. A plain copy today, same as `StepKind::Template` — no variable
+/// substitution is applied yet.
+pub struct ConfigureTemplate {
+    pub source: PathBuf,
+    pub dest: PathBuf,
+}
+
+impl ConfigureTemplate {
+    pub fn new(source: impl Into<PathBuf>, dest: impl Into<PathBuf>) -> Self {
+        Self { source: source.into(), dest: dest.into() }
+    }
+}
+
+/// Runs an extension's configure phase — rendering templates and writing
+/// its env vars to `<env_file>` — and records
+/// `ConfigureStarted`/`ConfigureCompleted`/`ConfigureFailed` events to the
+/// ledger as it goes, so a failed configure step shows up in the event
+/// history the same way a failed install step does.
+pub struct ConfigureProcessor<'a> {
+    ledger: &'a ExtensionLedger,
+}
+
+impl<'a> ConfigureProcessor<'a> {
+    pub fn new(ledger: &'a ExtensionLedger) -> Self {
+        Self { ledger }
+    }
+
+    pub fn configure(
+        &self,
+        extension: &str,
+        templates: &[ConfigureTemplate],
+        env_vars: &BTreeMap<String, String>,
+        env_file: &Path,
+    ) -> Result<(), ExtensionError> {
+        if self.ledger.has_pending_configure(extension)? {
+            tracing::warn!(extension, "resuming a configure that was previously interrupted mid-run");
+        }
+
+        self.ledger.record(extension, &ExtensionEvent::ConfigureStarted { at: unix_now() })?;
+
+        match self.apply(templates, env_vars, env_file) {
+            Ok(()) => {
+                self.ledger.record(
+                    extension,
+                    &ExtensionEvent::ConfigureCompleted {
+                        at: unix_now(),
+                        templates_applied: templates.len(),
+                        env_vars_set: env_vars.len(),
+                    },
+                )?;
+                Ok(())
+            }
+            Err(reason) => {
+                self.ledger.record(extension, &ExtensionEvent::ConfigureFailed { at: unix_now(), reason: reason.clone() })?;
+                Err(ExtensionError::ConfigureFailed { extension: extension.to_string(), reason })
+            }
+        }
+    }
+
+    fn apply(
+        &self,
+        templates: &[ConfigureTemplate],
+        env_vars: &BTreeMap<String, String>,
+        env_file: &Path,
+    ) -> Result<(), String> {
+        for template in templates {
+            if let Some(parent) = template.dest.parent() {
+                fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+            }
+            fs::copy(&template.source, &template.dest).map_err(|err| {
+                format!("could not render template {}: {err}", template.source.display())
+            })?;
+        }
+
+        if !env_vars.is_empty() {
+            if let Some(parent) = env_file.parent() {
+                fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+            }
+            let contents: String =
+                env_vars.iter().map(|(key, value)| format!("{key}={value}\n")).collect();
+            fs::write(env_file, contents)
+                .map_err(|err| format!("could not write {}: {err}", env_file.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_templates_and_writes_env_vars_then_records_completion() {
+        let tmp = tempfile::tempdir().unwrap();
+        let source = tmp.path().join("template.txt");
+        fs::write(&source, "hello").unwrap();
+        let dest = tmp.path().join("out").join("rendered.txt");
+        let env_file = tmp.path().join("out").join(".env");
+
+        let ledger = ExtensionLedger::new(tmp.path().join("ledger"));
+        let processor = ConfigureProcessor::new(&ledger);
+
+        let mut env_vars = BTreeMap::new();
+        env_vars.insert("FOO".to_string(), "bar".to_string());
+
+        processor
+            .configure("demo", &[ConfigureTemplate::new(&source, &dest)], &env_vars, &env_file)
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "hello");
+        assert_eq!(fs::read_to_string(&env_file).unwrap(), "FOO=bar\n");
+
+        let history = ledger.history("demo").unwrap();
+        assert!(matches!(history[0], ExtensionEvent::ConfigureStarted { .. }));
+        assert!(matches!(
+            history[1],
+            ExtensionEvent::ConfigureCompleted { templates_applied: 1, env_vars_set: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn resumes_cleanly_after_an_interrupted_configure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let source = tmp.path().join("template.txt");
+        fs::write(&source, "hello").unwrap();
+        let dest = tmp.path().join("out").join("rendered.txt");
+        let env_file = tmp.path().join("out").join(".env");
+
+        let ledger = ExtensionLedger::new(tmp.path().join("ledger"));
+        ledger.record("demo", &ExtensionEvent::ConfigureStarted { at: 1 }).unwrap();
+
+        let processor = ConfigureProcessor::new(&ledger);
+        processor
+            .configure("demo", &[ConfigureTemplate::new(&source, &dest)], &BTreeMap::new(), &env_file)
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "hello");
+        assert!(matches!(ledger.history("demo").unwrap().last().unwrap(), ExtensionEvent::ConfigureCompleted { .. }));
+    }
+
+    #[test]
+    fn a_missing_template_source_fails_and_is_recorded() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path().join("ledger"));
+        let processor = ConfigureProcessor::new(&ledger);
+
+        let missing = ConfigureTemplate::new(tmp.path().join("nope.txt"), tmp.path().join("out.txt"));
+        let err = processor
+            .configure("demo", &[missing], &BTreeMap::new(), &tmp.path().join(".env"))
+            .unwrap_err();
+        assert!(matches!(err, ExtensionError::ConfigureFailed { .. }));
+
+        let history = ledger.history("demo").unwrap();
+        assert!(matches!(history.last().unwrap(), ExtensionEvent::ConfigureFailed { .. }));
+    }
+}
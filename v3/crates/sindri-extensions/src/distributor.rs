@@ -0,0 +1,347 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::manifest;
+use crate::ExtensionError;
+
+/// Manages on-disk extension installs under `extensions/<name>/<version>/`,
+/// maintaining an `extensions/<name>/current` symlink that always points at
+/// the active version.
+pub struct ExtensionDistributor {
+    root: PathBuf,
+}
+
+impl ExtensionDistributor {
+    /// Creates a distributor rooted at `extensions_dir` (typically
+    /// `~/.sindri/extensions`).
+    pub fn new(extensions_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            root: extensions_dir.into(),
+        }
+    }
+
+    /// Directory holding all versions of `name`.
+    pub fn extension_dir(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+
+    /// Directory holding the files for a specific `name`/`version` pair.
+    pub fn version_dir(&self, name: &str, version: &str) -> PathBuf {
+        self.extension_dir(name).join(version)
+    }
+
+    /// The `current` symlink path for `name`.
+    pub fn current_link(&self, name: &str) -> PathBuf {
+        self.extension_dir(name).join("current")
+    }
+
+    /// Resolves the version that `current` points at, if installed.
+    pub fn current_version(&self, name: &str) -> Option<String> {
+        let link = self.current_link(name);
+        let target = fs::read_link(&link).ok()?;
+        target.file_name()?.to_str().map(str::to_owned)
+    }
+
+    /// Populates `extensions/<name>/<version>/` via `populate`, then
+    /// atomically switches `current` to point at it.
+    ///
+    /// `populate` receives the version directory to write files into; it is
+    /// only invoked once the directory has been created.
+    pub fn install(
+        &self,
+        name: &str,
+        version: &str,
+        populate: impl FnOnce(&Path) -> std::io::Result<()>,
+    ) -> Result<(), ExtensionError> {
+        let version_dir = self.version_dir(name, version);
+        fs::create_dir_all(&version_dir).map_err(|source| ExtensionError::Io {
+            path: version_dir.clone(),
+            source,
+        })?;
+        populate(&version_dir).map_err(|source| ExtensionError::Io {
+            path: version_dir.clone(),
+            source,
+        })?;
+
+        let file_manifest = manifest::compute_manifest(&version_dir)?;
+        manifest::write_manifest(&version_dir, &file_manifest)?;
+
+        self.switch_current(name, version)
+    }
+
+    /// Recomputes checksums of `name`'s installed files at `version` against
+    /// the manifest recorded at install time, returning any drift. An empty
+    /// result means the installed files are byte-for-byte what was recorded.
+    pub fn verify_checksums(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> Result<Vec<manifest::ChecksumDrift>, ExtensionError> {
+        let version_dir = self.version_dir(name, version);
+        if !version_dir.is_dir() {
+            return Err(ExtensionError::VersionNotInstalled(
+                name.to_string(),
+                version.to_string(),
+            ));
+        }
+        manifest::verify_manifest(&version_dir)
+    }
+
+    /// Atomically points `extensions/<name>/current` at `version`.
+    ///
+    /// The switch is performed by creating a symlink at a temporary path and
+    /// renaming it over `current`, which is atomic on the same filesystem —
+    /// a crash mid-switch leaves either the old or the new target in place,
+    /// never a dangling link.
+    pub fn switch_current(&self, name: &str, version: &str) -> Result<(), ExtensionError> {
+        let version_dir = self.version_dir(name, version);
+        if !version_dir.is_dir() {
+            return Err(ExtensionError::VersionNotInstalled(
+                name.to_string(),
+                version.to_string(),
+            ));
+        }
+
+        let link = self.current_link(name);
+        let tmp_link = self.extension_dir(name).join(format!(".current.{version}.tmp"));
+
+        let _ = fs::remove_file(&tmp_link);
+        symlink(&version_dir, &tmp_link).map_err(|source| ExtensionError::SymlinkSwitch {
+            extension: name.to_string(),
+            version: version.to_string(),
+            path: tmp_link.clone(),
+            source,
+        })?;
+
+        fs::rename(&tmp_link, &link).map_err(|source| ExtensionError::SymlinkSwitch {
+            extension: name.to_string(),
+            version: version.to_string(),
+            path: link.clone(),
+            source,
+        })?;
+
+        Ok(())
+    }
+
+    /// Rolls `current` back to `version`, which must still be retained on
+    /// disk — a pure symlink flip, no reinstall.
+    pub fn rollback(&self, name: &str, version: &str) -> Result<(), ExtensionError> {
+        self.switch_current(name, version)
+    }
+
+    /// Drops the `current` symlink for `name`, leaving version directories
+    /// untouched on disk.
+    pub fn remove(&self, name: &str) -> Result<(), ExtensionError> {
+        let link = self.current_link(name);
+        match fs::remove_file(&link) {
+            Ok(()) => Ok(()),
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(source) => Err(ExtensionError::Io { path: link, source }),
+        }
+    }
+
+    /// Lists the names of extensions that have at least one version
+    /// installed under this distributor's root.
+    pub fn installed_extensions(&self) -> Result<Vec<String>, ExtensionError> {
+        let mut names = Vec::new();
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(names),
+            Err(source) => {
+                return Err(ExtensionError::Io {
+                    path: self.root.clone(),
+                    source,
+                })
+            }
+        };
+        for entry in entries {
+            let entry = entry.map_err(|source| ExtensionError::Io {
+                path: self.root.clone(),
+                source,
+            })?;
+            if entry.path().is_dir() {
+                if let Some(name) = entry.file_name().to_str() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Lists the version directories installed for `name`, most recently
+    /// modified first.
+    fn installed_versions(&self, name: &str) -> Result<Vec<PathBuf>, ExtensionError> {
+        let dir = self.extension_dir(name);
+        let mut versions = Vec::new();
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(versions),
+            Err(source) => return Err(ExtensionError::Io { path: dir, source }),
+        };
+        for entry in entries {
+            let entry = entry.map_err(|source| ExtensionError::Io {
+                path: dir.clone(),
+                source,
+            })?;
+            let path = entry.path();
+            if path.is_dir() && !path.is_symlink() {
+                versions.push(path);
+            }
+        }
+        versions.sort_by_key(|path| {
+            std::cmp::Reverse(
+                fs::metadata(path)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(std::time::SystemTime::UNIX_EPOCH),
+            )
+        });
+        Ok(versions)
+    }
+
+    /// Lists installed version names for `name`, most recently modified
+    /// first.
+    pub fn list_versions(&self, name: &str) -> Result<Vec<String>, ExtensionError> {
+        Ok(self
+            .installed_versions(name)?
+            .into_iter()
+            .filter_map(|path| path.file_name().and_then(|n| n.to_str()).map(str::to_owned))
+            .collect())
+    }
+
+    /// Deletes all but the `keep_last` most recently modified non-active
+    /// version directories for `name`. The active (`current`) version is
+    /// never deleted, even if it would otherwise fall outside the retained
+    /// window. Returns the number of bytes freed.
+    ///
+    /// Extension version history in the install ledger is unaffected by
+    /// pruning — only the on-disk copy of old versions is removed.
+    pub fn prune(&self, name: &str, keep_last: usize) -> Result<PruneReport, ExtensionError> {
+        let active = self.current_version(name);
+        let versions = self.installed_versions(name)?;
+
+        let non_active: Vec<&PathBuf> = versions
+            .iter()
+            .filter(|path| path.file_name().and_then(|n| n.to_str()) != active.as_deref())
+            .collect();
+
+        let mut report = PruneReport::default();
+        for path in non_active.into_iter().skip(keep_last) {
+            let freed = dir_size(path).unwrap_or(0);
+            fs::remove_dir_all(path).map_err(|source| ExtensionError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            report.removed_versions.push(
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            report.freed_bytes += freed;
+        }
+
+        Ok(report)
+    }
+}
+
+/// Result of an [`ExtensionDistributor::prune`] call.
+#[derive(Debug, Default, Clone)]
+pub struct PruneReport {
+    pub removed_versions: Vec<String>,
+    pub freed_bytes: u64,
+}
+
+fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            total += dir_size(&entry.path())?;
+        } else {
+            total += metadata.len();
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_dir(target, link)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn install_points_current_at_new_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dist = ExtensionDistributor::new(tmp.path());
+
+        dist.install("foo", "1.0.0", |dir| {
+            fs::write(dir.join("bin"), b"v1")
+        })
+        .unwrap();
+
+        assert_eq!(dist.current_version("foo"), Some("1.0.0".to_string()));
+
+        dist.install("foo", "1.1.0", |dir| {
+            fs::write(dir.join("bin"), b"v1.1")
+        })
+        .unwrap();
+
+        assert_eq!(dist.current_version("foo"), Some("1.1.0".to_string()));
+    }
+
+    #[test]
+    fn rollback_flips_current_without_reinstalling() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dist = ExtensionDistributor::new(tmp.path());
+
+        dist.install("foo", "1.0.0", |dir| fs::write(dir.join("bin"), b"v1")).unwrap();
+        dist.install("foo", "2.0.0", |dir| fs::write(dir.join("bin"), b"v2")).unwrap();
+
+        dist.rollback("foo", "1.0.0").unwrap();
+        assert_eq!(dist.current_version("foo"), Some("1.0.0".to_string()));
+    }
+
+    #[test]
+    fn prune_keeps_active_and_retained_window() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dist = ExtensionDistributor::new(tmp.path());
+
+        for version in ["1.0.0", "1.1.0", "1.2.0"] {
+            dist.install("foo", version, |dir| fs::write(dir.join("bin"), b"x"))
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        dist.rollback("foo", "1.1.0").unwrap();
+
+        let report = dist.prune("foo", 1).unwrap();
+
+        // Active (1.1.0) is always kept; newest non-active (1.2.0) is kept
+        // because keep_last=1; oldest (1.0.0) is pruned.
+        assert_eq!(report.removed_versions, vec!["1.0.0".to_string()]);
+        assert!(dist.version_dir("foo", "1.1.0").is_dir());
+        assert!(dist.version_dir("foo", "1.2.0").is_dir());
+        assert!(!dist.version_dir("foo", "1.0.0").is_dir());
+    }
+
+    #[test]
+    fn remove_drops_symlink_but_keeps_version_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dist = ExtensionDistributor::new(tmp.path());
+
+        dist.install("foo", "1.0.0", |dir| fs::write(dir.join("bin"), b"v1")).unwrap();
+        dist.remove("foo").unwrap();
+
+        assert_eq!(dist.current_version("foo"), None);
+        assert!(dist.version_dir("foo", "1.0.0").is_dir());
+    }
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors raised while resolving, installing, or managing extensions.
+#[derive(Debug, Error)]
+pub enum ExtensionError {
+    #[error("extension {0} is not installed")]
+    NotInstalled(String),
+
+    #[error("extension {0} has no version {1} installed")]
+    VersionNotInstalled(String, String),
+
+    #[error("failed to switch {extension} to version {version} at {path}: {source}")]
+    SymlinkSwitch {
+        extension: String,
+        version: String,
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("io error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("installing {extension} failed at step \"{step}\": {reason} (see {})", log_path.display())]
+    StepFailed {
+        extension: String,
+        step: String,
+        reason: String,
+        log_path: PathBuf,
+    },
+
+    #[error("extension {0} has no install logs")]
+    NoLogs(String),
+
+    #[error("invalid platform \"{0}\", expected os/arch (e.g. linux/arm64)")]
+    InvalidPlatform(String),
+
+    #[error("not enough {resource} to install: needs {required} bytes, {available} available (pass --force to override)")]
+    InsufficientResources {
+        resource: String,
+        required: u64,
+        available: u64,
+    },
+
+    #[error(transparent)]
+    Serde(#[from] serde_json::Error),
+
+    #[error("configuring {extension} failed: {reason}")]
+    ConfigureFailed { extension: String, reason: String },
+
+    #[error("failed to fetch {url}: {reason}")]
+    Fetch { url: String, reason: String },
+}
@@ -0,0 +1,430 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::log::{rotate_logs, ExtensionLogWriter};
+use crate::requirements::{self, ExtensionRequirements};
+use crate::ExtensionError;
+
+/// How many of an extension's install logs to keep by default.
+const DEFAULT_RETAINED_LOGS: usize = 10;
+
+/// How long any single step is allowed to run before it's killed.
+const DEFAULT_STEP_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// What happens to the install when a [`StepKind::Hook`] exits non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookFailure {
+    /// Stop the install, same as any other failing step.
+    Fail,
+    /// Log the failure and keep going.
+    Warn,
+}
+
+/// A single unit of work performed while installing an extension.
+pub enum StepKind {
+    Shell { program: String, args: Vec<String> },
+    /// Copies `source` to `dest`. A plain copy today — no variable
+    /// substitution is applied.
+    Template { source: PathBuf, dest: PathBuf },
+    Validate { program: String, args: Vec<String> },
+    /// A `pre_install` or `post_install` hook declared by the extension
+    /// itself. `script` must resolve inside `working_dir` (the extension's
+    /// own directory) — hooks can't reach outside of it.
+    Hook {
+        script: PathBuf,
+        args: Vec<String>,
+        working_dir: PathBuf,
+        on_failure: HookFailure,
+    },
+}
+
+impl StepKind {
+    fn on_failure(&self) -> HookFailure {
+        match self {
+            Self::Hook { on_failure, .. } => *on_failure,
+            Self::Shell { .. } | Self::Template { .. } | Self::Validate { .. } => HookFailure::Fail,
+        }
+    }
+}
+
+pub struct ExtensionStep {
+    pub name: String,
+    pub kind: StepKind,
+}
+
+impl ExtensionStep {
+    pub fn shell(name: impl Into<String>, program: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: StepKind::Shell {
+                program: program.into(),
+                args,
+            },
+        }
+    }
+
+    pub fn template(name: impl Into<String>, source: impl Into<PathBuf>, dest: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            kind: StepKind::Template {
+                source: source.into(),
+                dest: dest.into(),
+            },
+        }
+    }
+
+    pub fn validate(name: impl Into<String>, program: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: StepKind::Validate {
+                program: program.into(),
+                args,
+            },
+        }
+    }
+
+    /// A `pre_install` or `post_install` hook. `script` must live inside
+    /// `working_dir`; the hook runs with `working_dir` as its current
+    /// directory and is killed if it outlives the executor's step timeout.
+    pub fn hook(
+        name: impl Into<String>,
+        script: impl Into<PathBuf>,
+        args: Vec<String>,
+        working_dir: impl Into<PathBuf>,
+        on_failure: HookFailure,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            kind: StepKind::Hook {
+                script: script.into(),
+                args,
+                working_dir: working_dir.into(),
+                on_failure,
+            },
+        }
+    }
+}
+
+/// Runs an extension's install steps in order, routing every step's
+/// outcome through an [`ExtensionLogWriter`] so a complete record lands at
+/// `<logs_dir>/<extension>/<started_at>.log`. The first failing step stops
+/// the run and is marked clearly in the log, unless it's a [`StepKind::Hook`]
+/// configured with [`HookFailure::Warn`].
+pub struct ExtensionExecutor {
+    logs_dir: PathBuf,
+    retained_logs: usize,
+    timeout: Duration,
+}
+
+impl ExtensionExecutor {
+    pub fn new(logs_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            logs_dir: logs_dir.into(),
+            retained_logs: DEFAULT_RETAINED_LOGS,
+            timeout: DEFAULT_STEP_TIMEOUT,
+        }
+    }
+
+    pub fn with_retained_logs(mut self, retained_logs: usize) -> Self {
+        self.retained_logs = retained_logs;
+        self
+    }
+
+    /// Overrides the default per-step timeout (applies to every step kind,
+    /// including hooks).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Checks `required` (the sum of every extension's requirements for a
+    /// profile install) against what's actually available at `disk_path`
+    /// before [`Self::execute`] runs any step. `force` skips the check
+    /// entirely, for callers that pass `--force`.
+    pub fn check_requirements(
+        &self,
+        required: &ExtensionRequirements,
+        disk_path: &Path,
+        force: bool,
+    ) -> Result<(), ExtensionError> {
+        if force {
+            return Ok(());
+        }
+        requirements::check_requirements(required, disk_path)
+    }
+
+    /// Executes `steps` for `extension`, logging as it goes, and returns
+    /// the path to the log on success.
+    pub fn execute(
+        &self,
+        extension: &str,
+        steps: &[ExtensionStep],
+        started_at: u64,
+    ) -> Result<PathBuf, ExtensionError> {
+        let mut writer = ExtensionLogWriter::create(&self.logs_dir, extension, started_at)?;
+
+        for step in steps {
+            match run_step(&step.kind, self.timeout) {
+                Ok(output) => writer.log(&step.name, &output)?,
+                Err(reason) => match step.kind.on_failure() {
+                    HookFailure::Warn => writer.log_warning(&step.name, &reason)?,
+                    HookFailure::Fail => {
+                        writer.log_failure(&step.name, &reason)?;
+                        rotate_logs(&self.logs_dir, extension, self.retained_logs)?;
+                        return Err(ExtensionError::StepFailed {
+                            extension: extension.to_string(),
+                            step: step.name.clone(),
+                            reason,
+                            log_path: writer.path().to_path_buf(),
+                        });
+                    }
+                },
+            }
+        }
+
+        let log_path = writer.path().to_path_buf();
+        rotate_logs(&self.logs_dir, extension, self.retained_logs)?;
+        Ok(log_path)
+    }
+}
+
+fn run_step(kind: &StepKind, timeout: Duration) -> Result<String, String> {
+    match kind {
+        StepKind::Shell { program, args } => run_command(program, args, timeout),
+        StepKind::Validate { program, args } => run_command(program, args, timeout),
+        StepKind::Template { source, dest } => apply_template(source, dest),
+        StepKind::Hook { script, args, working_dir, .. } => run_hook(script, args, working_dir, timeout),
+    }
+}
+
+fn run_command(program: &str, args: &[String], timeout: Duration) -> Result<String, String> {
+    let mut command = Command::new(program);
+    command.args(args);
+    run_with_timeout(command, timeout)
+}
+
+fn run_hook(script: &Path, args: &[String], working_dir: &Path, timeout: Duration) -> Result<String, String> {
+    let working_dir = working_dir
+        .canonicalize()
+        .map_err(|err| format!("invalid extension directory {}: {err}", working_dir.display()))?;
+    let script = script
+        .canonicalize()
+        .map_err(|err| format!("invalid hook script {}: {err}", script.display()))?;
+    if !script.starts_with(&working_dir) {
+        return Err(format!(
+            "hook script {} escapes its extension directory {}",
+            script.display(),
+            working_dir.display()
+        ));
+    }
+
+    let mut command = Command::new(&script);
+    command.args(args).current_dir(&working_dir);
+    run_with_timeout(command, timeout)
+}
+
+/// Spawns `command`, killing it if it's still running after `timeout`.
+fn run_with_timeout(mut command: Command, timeout: Duration) -> Result<String, String> {
+    let description = format!("{command:?}");
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("could not run {description}: {err}"))?;
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait().map_err(|err| err.to_string())? {
+            let output = child.wait_with_output().map_err(|err| err.to_string())?;
+            if !status.success() {
+                return Err(format!(
+                    "{description} exited with status {:?}: {}",
+                    status.code(),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
+            }
+            return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+        }
+
+        if started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("{description} timed out after {timeout:?}"));
+        }
+
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn apply_template(source: &Path, dest: &Path) -> Result<String, String> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+    std::fs::copy(source, dest).map_err(|err| err.to_string())?;
+    Ok(format!("copied {} to {}", source.display(), dest.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::latest_log;
+
+    #[test]
+    fn logs_each_step_and_returns_the_log_path() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![ExtensionStep::shell("greet", "echo", vec!["hi".to_string()])];
+
+        let log_path = executor.execute("foo", &steps, 100).unwrap();
+        let contents = std::fs::read_to_string(log_path).unwrap();
+        assert!(contents.contains("[greet] hi"));
+    }
+
+    #[test]
+    fn stops_and_marks_the_failing_step() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![
+            ExtensionStep::shell("setup", "echo", vec!["ok".to_string()]),
+            ExtensionStep::validate("check", "false", vec![]),
+            ExtensionStep::shell("never-runs", "echo", vec!["unreachable".to_string()]),
+        ];
+
+        let err = executor.execute("foo", &steps, 200).unwrap_err();
+        let ExtensionError::StepFailed { step, log_path, .. } = &err else {
+            panic!("expected StepFailed, got {err:?}");
+        };
+        assert_eq!(step, "check");
+
+        let contents = std::fs::read_to_string(log_path).unwrap();
+        assert!(contents.contains("[check] FAILED:"));
+        assert!(!contents.contains("never-runs"));
+    }
+
+    #[test]
+    fn template_step_copies_the_file() {
+        let logs = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let source = project.path().join("template.txt");
+        std::fs::write(&source, "hello").unwrap();
+        let dest = project.path().join("out").join("rendered.txt");
+
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![ExtensionStep::template("apply", &source, &dest)];
+        executor.execute("foo", &steps, 300).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "hello");
+    }
+
+    #[test]
+    fn force_skips_the_requirements_check_even_when_unmet() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path());
+        let huge = ExtensionRequirements { disk_space: u64::MAX - 1, memory: 0, domains: Vec::new() };
+        executor.check_requirements(&huge, logs.path(), true).unwrap();
+    }
+
+    #[test]
+    fn requirements_easily_met_pass_without_force() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path());
+        let tiny = ExtensionRequirements { disk_space: 1, memory: 1, domains: Vec::new() };
+        executor.check_requirements(&tiny, logs.path(), false).unwrap();
+    }
+
+    #[test]
+    fn rotates_old_logs_after_a_run() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path()).with_retained_logs(1);
+        let steps = vec![ExtensionStep::shell("step", "echo", vec!["x".to_string()])];
+
+        executor.execute("foo", &steps, 100).unwrap();
+        executor.execute("foo", &steps, 200).unwrap();
+
+        let latest = latest_log(logs.path(), "foo").unwrap().unwrap();
+        assert_eq!(latest.file_name().unwrap().to_str().unwrap(), "200.log");
+        assert!(!logs.path().join("foo").join("100.log").exists());
+    }
+
+    #[test]
+    fn pre_install_hook_failure_stops_the_run() {
+        let logs = tempfile::tempdir().unwrap();
+        let extension_dir = tempfile::tempdir().unwrap();
+        let script = extension_dir.path().join("pre_install.sh");
+        std::fs::write(&script, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![
+            ExtensionStep::hook("pre-install", &script, vec![], extension_dir.path(), HookFailure::Fail),
+            ExtensionStep::shell("never-runs", "echo", vec!["unreachable".to_string()]),
+        ];
+
+        let err = executor.execute("foo", &steps, 100).unwrap_err();
+        let ExtensionError::StepFailed { step, .. } = &err else {
+            panic!("expected StepFailed, got {err:?}");
+        };
+        assert_eq!(step, "pre-install");
+    }
+
+    #[test]
+    fn post_install_hook_failure_only_warns() {
+        let logs = tempfile::tempdir().unwrap();
+        let extension_dir = tempfile::tempdir().unwrap();
+        let script = extension_dir.path().join("post_install.sh");
+        std::fs::write(&script, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![ExtensionStep::hook(
+            "post-install",
+            &script,
+            vec![],
+            extension_dir.path(),
+            HookFailure::Warn,
+        )];
+
+        let log_path = executor.execute("foo", &steps, 100).unwrap();
+        let contents = std::fs::read_to_string(log_path).unwrap();
+        assert!(contents.contains("[post-install] WARNING:"));
+    }
+
+    #[test]
+    fn hook_script_outside_the_extension_directory_is_rejected() {
+        let logs = tempfile::tempdir().unwrap();
+        let extension_dir = tempfile::tempdir().unwrap();
+        let outsider = tempfile::tempdir().unwrap();
+        let script = outsider.path().join("escape.sh");
+        std::fs::write(&script, "#!/bin/sh\nexit 0\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+
+        let executor = ExtensionExecutor::new(logs.path());
+        let steps = vec![ExtensionStep::hook(
+            "pre-install",
+            &script,
+            vec![],
+            extension_dir.path(),
+            HookFailure::Fail,
+        )];
+
+        let err = executor.execute("foo", &steps, 100).unwrap_err();
+        let ExtensionError::StepFailed { reason, .. } = &err else {
+            panic!("expected StepFailed, got {err:?}");
+        };
+        assert!(reason.contains("escapes its extension directory"));
+    }
+
+    #[test]
+    fn a_step_that_outlives_its_timeout_is_killed() {
+        let logs = tempfile::tempdir().unwrap();
+        let executor = ExtensionExecutor::new(logs.path()).with_timeout(Duration::from_millis(50));
+        let steps = vec![ExtensionStep::shell("sleep", "sleep", vec!["5".to_string()])];
+
+        let err = executor.execute("foo", &steps, 100).unwrap_err();
+        let ExtensionError::StepFailed { reason, .. } = &err else {
+            panic!("expected StepFailed, got {err:?}");
+        };
+        assert!(reason.contains("timed out"));
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::executor::ExtensionStep;
+
+/// How an extension gets installed. `Cargo` is the first method with
+/// first-class config; the rest of the methods documented in the
+/// extension guide (mise, apt, binary, npm, script, hybrid) are still
+/// expressed as hand-built [`ExtensionStep`]s until they gain one too.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstallMethod {
+    Cargo(CargoInstall),
+}
+
+impl InstallMethod {
+    /// The steps [`crate::ExtensionExecutor::execute`] should run to
+    /// install this method.
+    pub fn install_steps(&self) -> Vec<ExtensionStep> {
+        match self {
+            Self::Cargo(cargo) => vec![ExtensionStep::shell("cargo-install", "cargo", cargo.install_args())],
+        }
+    }
+
+    /// The steps that undo what [`Self::install_steps`] installed.
+    pub fn uninstall_steps(&self) -> Vec<ExtensionStep> {
+        match self {
+            Self::Cargo(cargo) => vec![ExtensionStep::shell(
+                "cargo-uninstall",
+                "cargo",
+                vec!["uninstall".to_string(), cargo.crate_name.clone()],
+            )],
+        }
+    }
+}
+
+/// Config for `InstallMethod::Cargo`: installs a crate's binaries via
+/// `cargo install`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CargoInstall {
+    pub crate_name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub locked: bool,
+}
+
+impl CargoInstall {
+    pub fn new(crate_name: impl Into<String>) -> Self {
+        Self {
+            crate_name: crate_name.into(),
+            version: None,
+            features: Vec::new(),
+            locked: false,
+        }
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn with_features(mut self, features: Vec<String>) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn with_locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
+    fn install_args(&self) -> Vec<String> {
+        let mut args = vec!["install".to_string(), self.crate_name.clone()];
+        if let Some(version) = &self.version {
+            args.push("--version".to_string());
+            args.push(version.clone());
+        }
+        if !self.features.is_empty() {
+            args.push("--features".to_string());
+            args.push(self.features.join(","));
+        }
+        if self.locked {
+            args.push("--locked".to_string());
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell_program_and_args(step: &ExtensionStep) -> (&str, &[String]) {
+        match &step.kind {
+            crate::executor::StepKind::Shell { program, args } => (program, args),
+            _ => panic!("expected a shell step"),
+        }
+    }
+
+    #[test]
+    fn install_steps_run_cargo_install_with_every_option() {
+        let method = InstallMethod::Cargo(
+            CargoInstall::new("ripgrep")
+                .with_version("14.0.0")
+                .with_features(vec!["pcre2".to_string(), "simd-accel".to_string()])
+                .with_locked(true),
+        );
+
+        let steps = method.install_steps();
+        assert_eq!(steps.len(), 1);
+        let (program, args) = shell_program_and_args(&steps[0]);
+        assert_eq!(program, "cargo");
+        assert_eq!(
+            args,
+            &[
+                "install".to_string(),
+                "ripgrep".to_string(),
+                "--version".to_string(),
+                "14.0.0".to_string(),
+                "--features".to_string(),
+                "pcre2,simd-accel".to_string(),
+                "--locked".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn install_steps_omit_optional_flags_when_unset() {
+        let method = InstallMethod::Cargo(CargoInstall::new("ripgrep"));
+        let steps = method.install_steps();
+        let (_, args) = shell_program_and_args(&steps[0]);
+        assert_eq!(args, &["install".to_string(), "ripgrep".to_string()]);
+    }
+
+    #[test]
+    fn uninstall_steps_run_cargo_uninstall() {
+        let method = InstallMethod::Cargo(CargoInstall::new("ripgrep"));
+        let steps = method.uninstall_steps();
+        assert_eq!(steps.len(), 1);
+        let (program, args) = shell_program_and_args(&steps[0]);
+        assert_eq!(program, "cargo");
+        assert_eq!(args, &["uninstall".to_string(), "ripgrep".to_string()]);
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ExtensionError;
+
+/// One recorded event in an extension's lifecycle ledger. Currently covers
+/// the configure phase (template rendering + env var wiring) performed by
+/// [`crate::ConfigureProcessor`]; other lifecycle phases (install, upgrade,
+/// remove, validate) join this enum as they gain ledger support.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtensionEvent {
+    ConfigureStarted {
+        at: u64,
+    },
+    ConfigureCompleted {
+        at: u64,
+        templates_applied: usize,
+        env_vars_set: usize,
+    },
+    ConfigureFailed {
+        at: u64,
+        reason: String,
+    },
+}
+
+impl ExtensionEvent {
+    /// Unix-seconds timestamp every variant carries, for filtering/sorting
+    /// across extensions without matching on each variant's own fields.
+    pub fn at(&self) -> u64 {
+        match self {
+            Self::ConfigureStarted { at }
+            | Self::ConfigureCompleted { at, .. }
+            | Self::ConfigureFailed { at, .. } => *at,
+        }
+    }
+}
+
+/// An [`ExtensionEvent`] together with which extension it belongs to, for
+/// exports that span every extension's history at once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub extension: String,
+    pub event: ExtensionEvent,
+}
+
+/// Narrows an [`ExtensionLedger`] export to events at or after `since`,
+/// capped at `limit` results (applied after sorting oldest-first, so
+/// `limit` keeps the earliest matches).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventFilter {
+    pub since: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+impl EventFilter {
+    fn matches(&self, envelope: &EventEnvelope) -> bool {
+        self.since.is_none_or(|since| envelope.event.at() >= since)
+    }
+}
+
+/// Renders `event` as a single human-readable line, for `extension history`
+/// and any other status display.
+pub fn format_event_summary(event: &ExtensionEvent) -> String {
+    match event {
+        ExtensionEvent::ConfigureStarted { at } => format!("{at} configure started"),
+        ExtensionEvent::ConfigureCompleted { at, templates_applied, env_vars_set } => format!(
+            "{at} configure completed ({templates_applied} template(s), {env_vars_set} env var(s))"
+        ),
+        ExtensionEvent::ConfigureFailed { at, reason } => format!("{at} configure failed: {reason}"),
+    }
+}
+
+/// Current unix time in seconds, floored to zero if the clock is somehow
+/// before the epoch.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Appends extension lifecycle events to `<ledger_dir>/<extension>.jsonl`
+/// and reads them back for status history. One JSON object per line, so a
+/// crash mid-write only loses the last event rather than the whole history.
+pub struct ExtensionLedger {
+    ledger_dir: PathBuf,
+}
+
+impl ExtensionLedger {
+    pub fn new(ledger_dir: impl Into<PathBuf>) -> Self {
+        Self { ledger_dir: ledger_dir.into() }
+    }
+
+    /// Appends `event` to `extension`'s history.
+    pub fn record(&self, extension: &str, event: &ExtensionEvent) -> Result<(), ExtensionError> {
+        fs::create_dir_all(&self.ledger_dir)
+            .map_err(|source| ExtensionError::Io { path: self.ledger_dir.clone(), source })?;
+
+        let path = self.path_for(extension);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+        let line = serde_json::to_string(event)?;
+        writeln!(file, "{line}").map_err(|source| ExtensionError::Io { path, source })
+    }
+
+    /// Every event recorded for `extension`, oldest first. Empty, not an
+    /// error, when nothing has been recorded yet.
+    pub fn history(&self, extension: &str) -> Result<Vec<ExtensionEvent>, ExtensionError> {
+        let path = self.path_for(extension);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => return Err(ExtensionError::Io { path, source }),
+        };
+
+        contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+
+    /// Names of every extension with a recorded history, derived from
+    /// `<ledger_dir>/*.jsonl` filenames. Empty, not an error, before
+    /// anything has been recorded.
+    fn extensions(&self) -> Result<Vec<String>, ExtensionError> {
+        let entries = match fs::read_dir(&self.ledger_dir) {
+            Ok(entries) => entries,
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => return Err(ExtensionError::Io { path: self.ledger_dir.clone(), source }),
+        };
+        Ok(entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                (path.extension().and_then(|ext| ext.to_str()) == Some("jsonl"))
+                    .then(|| path.file_stem()?.to_str().map(str::to_string))
+                    .flatten()
+            })
+            .collect())
+    }
+
+    fn all_envelopes(&self) -> Result<Vec<EventEnvelope>, ExtensionError> {
+        let mut envelopes = Vec::new();
+        for extension in self.extensions()? {
+            envelopes.extend(
+                self.history(&extension)?
+                    .into_iter()
+                    .map(|event| EventEnvelope { extension: extension.clone(), event }),
+            );
+        }
+        Ok(envelopes)
+    }
+
+    /// Writes every extension's history matching `filter`, ordered
+    /// oldest-first, as a single JSON array — one envelope serialized and
+    /// written at a time, rather than building the whole export as one
+    /// in-memory string first.
+    pub fn export(&self, filter: &EventFilter, out: &mut impl Write) -> Result<(), ExtensionError> {
+        let envelopes = self.filtered_envelopes(filter)?;
+
+        write!(out, "[").map_err(|source| ExtensionError::Io { path: self.ledger_dir.clone(), source })?;
+        for (i, envelope) in envelopes.iter().enumerate() {
+            if i > 0 {
+                write!(out, ",").map_err(|source| ExtensionError::Io { path: self.ledger_dir.clone(), source })?;
+            }
+            write!(out, "{}", serde_json::to_string(envelope)?)
+                .map_err(|source| ExtensionError::Io { path: self.ledger_dir.clone(), source })?;
+        }
+        writeln!(out, "]").map_err(|source| ExtensionError::Io { path: self.ledger_dir.clone(), source })
+    }
+
+    fn filtered_envelopes(&self, filter: &EventFilter) -> Result<Vec<EventEnvelope>, ExtensionError> {
+        let mut envelopes = self.all_envelopes()?;
+        envelopes.retain(|envelope| filter.matches(envelope));
+        envelopes.sort_by_key(|envelope| envelope.event.at());
+        if let Some(limit) = filter.limit {
+            envelopes.truncate(limit);
+        }
+        Ok(envelopes)
+    }
+
+    /// Polls every extension's ledger file for newly appended events,
+    /// writing each as a newline-delimited JSON envelope to `out` the
+    /// moment it's seen. Runs until the process is stopped — there's no
+    /// natural end to "follow".
+    pub fn follow(&self, filter: &EventFilter, interval: Duration, out: &mut impl Write) -> Result<(), ExtensionError> {
+        let mut offsets: HashMap<String, u64> = HashMap::new();
+        loop {
+            for extension in self.extensions()? {
+                let path = self.path_for(&extension);
+                let offset = offsets.entry(extension.clone()).or_insert(0);
+
+                let Ok(mut file) = fs::File::open(&path) else { continue };
+                file.seek(SeekFrom::Start(*offset))
+                    .map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+                let mut new_contents = String::new();
+                file.read_to_string(&mut new_contents)
+                    .map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+                *offset += new_contents.len() as u64;
+
+                for line in new_contents.lines().filter(|line| !line.is_empty()) {
+                    let event: ExtensionEvent = serde_json::from_str(line)?;
+                    let envelope = EventEnvelope { extension: extension.clone(), event };
+                    if filter.matches(&envelope) {
+                        writeln!(out, "{}", serde_json::to_string(&envelope)?)
+                            .map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+                        out.flush().map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+                    }
+                }
+            }
+            std::thread::sleep(interval);
+        }
+    }
+
+    /// Whether `extension`'s last recorded event is a `ConfigureStarted`
+    /// with no `ConfigureCompleted`/`ConfigureFailed` after it — i.e. a
+    /// previous configure was interrupted (crash, killed process) partway
+    /// through rather than having actually finished either way, leaving it
+    /// wedged. [`crate::ConfigureProcessor::configure`] checks this to
+    /// decide whether it's resuming one.
+    pub fn has_pending_configure(&self, extension: &str) -> Result<bool, ExtensionError> {
+        Ok(matches!(self.history(extension)?.last(), Some(ExtensionEvent::ConfigureStarted { .. })))
+    }
+
+    fn path_for(&self, extension: &str) -> PathBuf {
+        self.ledger_dir.join(format!("{extension}.jsonl"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_is_empty_when_nothing_was_recorded() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        assert_eq!(ledger.history("foo").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn records_and_replays_events_in_order() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 100 }).unwrap();
+        ledger
+            .record(
+                "foo",
+                &ExtensionEvent::ConfigureCompleted { at: 101, templates_applied: 2, env_vars_set: 3 },
+            )
+            .unwrap();
+
+        assert_eq!(
+            ledger.history("foo").unwrap(),
+            vec![
+                ExtensionEvent::ConfigureStarted { at: 100 },
+                ExtensionEvent::ConfigureCompleted { at: 101, templates_applied: 2, env_vars_set: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn events_from_different_extensions_do_not_mix() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 1 }).unwrap();
+        ledger.record("bar", &ExtensionEvent::ConfigureStarted { at: 2 }).unwrap();
+
+        assert_eq!(ledger.history("foo").unwrap(), vec![ExtensionEvent::ConfigureStarted { at: 1 }]);
+        assert_eq!(ledger.history("bar").unwrap(), vec![ExtensionEvent::ConfigureStarted { at: 2 }]);
+    }
+
+    #[test]
+    fn no_history_has_no_pending_configure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        assert!(!ledger.has_pending_configure("foo").unwrap());
+    }
+
+    #[test]
+    fn a_started_configure_with_no_terminal_event_is_pending() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 1 }).unwrap();
+        assert!(ledger.has_pending_configure("foo").unwrap());
+    }
+
+    #[test]
+    fn a_completed_configure_is_not_pending() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 1 }).unwrap();
+        ledger
+            .record("foo", &ExtensionEvent::ConfigureCompleted { at: 2, templates_applied: 0, env_vars_set: 0 })
+            .unwrap();
+        assert!(!ledger.has_pending_configure("foo").unwrap());
+    }
+
+    #[test]
+    fn a_failed_configure_is_not_pending() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 1 }).unwrap();
+        ledger.record("foo", &ExtensionEvent::ConfigureFailed { at: 2, reason: "boom".to_string() }).unwrap();
+        assert!(!ledger.has_pending_configure("foo").unwrap());
+    }
+
+    #[test]
+    fn formats_each_variant_as_a_readable_line() {
+        assert_eq!(format_event_summary(&ExtensionEvent::ConfigureStarted { at: 1 }), "1 configure started");
+        assert_eq!(
+            format_event_summary(&ExtensionEvent::ConfigureCompleted { at: 1, templates_applied: 2, env_vars_set: 3 }),
+            "1 configure completed (2 template(s), 3 env var(s))"
+        );
+        assert_eq!(
+            format_event_summary(&ExtensionEvent::ConfigureFailed { at: 1, reason: "boom".to_string() }),
+            "1 configure failed: boom"
+        );
+    }
+
+    #[test]
+    fn export_writes_a_json_array_of_every_extensions_events_oldest_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        ledger.record("bar", &ExtensionEvent::ConfigureStarted { at: 20 }).unwrap();
+        ledger.record("foo", &ExtensionEvent::ConfigureStarted { at: 10 }).unwrap();
+
+        let mut out = Vec::new();
+        ledger.export(&EventFilter::default(), &mut out).unwrap();
+        let envelopes: Vec<EventEnvelope> = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(
+            envelopes,
+            vec![
+                EventEnvelope { extension: "foo".to_string(), event: ExtensionEvent::ConfigureStarted { at: 10 } },
+                EventEnvelope { extension: "bar".to_string(), event: ExtensionEvent::ConfigureStarted { at: 20 } },
+            ]
+        );
+    }
+
+    #[test]
+    fn export_honors_since_and_limit() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+        for at in [10, 20, 30] {
+            ledger.record("foo", &ExtensionEvent::ConfigureStarted { at }).unwrap();
+        }
+
+        let mut out = Vec::new();
+        ledger
+            .export(&EventFilter { since: Some(15), limit: Some(1) }, &mut out)
+            .unwrap();
+        let envelopes: Vec<EventEnvelope> = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(envelopes, vec![EventEnvelope { extension: "foo".to_string(), event: ExtensionEvent::ConfigureStarted { at: 20 } }]);
+    }
+
+    #[test]
+    fn export_with_no_events_is_an_empty_array() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = ExtensionLedger::new(tmp.path());
+
+        let mut out = Vec::new();
+        ledger.export(&EventFilter::default(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), "[]");
+    }
+}
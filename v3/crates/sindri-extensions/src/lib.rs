@@ -0,0 +1,41 @@
+//! Extension registry, resolution, and lifecycle management.
+
+mod compat;
+mod configure;
+mod distributor;
+mod error;
+mod executor;
+mod install;
+mod ledger;
+mod lint;
+mod lockfile;
+mod log;
+pub mod manifest;
+mod platform;
+mod profile;
+mod registry;
+mod remote;
+mod requirements;
+mod rows;
+mod verify;
+
+pub use compat::{CompatibilityEntry, CompatibilityMatrix, CompatibilityProblem, VersionExplanation};
+pub use configure::{ConfigureProcessor, ConfigureTemplate};
+pub use distributor::{ExtensionDistributor, PruneReport};
+pub use error::ExtensionError;
+pub use executor::{ExtensionExecutor, ExtensionStep, HookFailure, StepKind};
+pub use install::{CargoInstall, InstallMethod};
+pub use ledger::{format_event_summary, EventEnvelope, EventFilter, ExtensionEvent, ExtensionLedger};
+pub use lint::lint;
+pub use lockfile::{LockDrift, LockEntry, Lockfile};
+pub use log::{latest_log, rotate_logs, ExtensionLogWriter};
+pub use platform::{Platform, PlatformAvailability, PlatformEntry, PlatformMatrix};
+pub use profile::{json_schema as profile_json_schema, Profile, ProfileDiff, ProfileFormat};
+pub use registry::{ExtensionCapabilities, ExtensionEntry, ExtensionRegistry, McpCapability};
+pub use remote::{load_federated, ExtensionSourceResolver, FederatedRegistry, RegistrySource};
+pub use requirements::{check_requirements, ExtensionRequirements};
+pub use rows::{
+    AvailableExtensionRow, HistoryRow, SearchResultRow, StatusRow, VersionExplainRow, VersionRow,
+};
+pub use sindri_core::{LintFinding, LintSeverity};
+pub use verify::{verify_extension_installed, VerifyReport};
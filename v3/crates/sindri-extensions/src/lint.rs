@@ -0,0 +1,134 @@
+use std::collections::BTreeSet;
+
+use sindri_core::{LintFinding, LintSeverity};
+
+use crate::Profile;
+
+/// A semantic check over an already-loaded, already-schema-valid
+/// [`Profile`] — the kind of foot-gun structural validation can't catch
+/// (an empty profile, a duplicated extension, an extension the registry
+/// doesn't actually have, ...). New rules are added by appending to
+/// [`lint`]'s rule list, not by modifying existing rules.
+///
+/// For semantic rules over the deploy config itself (a requested GPU the
+/// provider can't give, a home volume smaller than the image needs, ...),
+/// see `sindri_providers`'s lint over `sindri_core::MultiTargetConfig` —
+/// `Profile` only describes an extension set, not a deployment.
+trait LintRule {
+    fn check(&self, profile: &Profile, known_extensions: Option<&[String]>) -> Vec<LintFinding>;
+}
+
+struct EmptyProfile;
+
+impl LintRule for EmptyProfile {
+    fn check(&self, profile: &Profile, _known_extensions: Option<&[String]>) -> Vec<LintFinding> {
+        if profile.extensions.is_empty() {
+            vec![LintFinding {
+                severity: LintSeverity::Warning,
+                path: "extensions".to_string(),
+                message: "profile has no extensions; installing it does nothing".to_string(),
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct DuplicateExtension;
+
+impl LintRule for DuplicateExtension {
+    fn check(&self, profile: &Profile, _known_extensions: Option<&[String]>) -> Vec<LintFinding> {
+        let mut seen = BTreeSet::new();
+        profile
+            .extensions
+            .iter()
+            .filter(|name| !seen.insert(name.as_str()))
+            .map(|name| LintFinding {
+                severity: LintSeverity::Warning,
+                path: format!("extensions[{name}]"),
+                message: format!("{name:?} is listed more than once"),
+            })
+            .collect()
+    }
+}
+
+struct UnknownExtension;
+
+impl LintRule for UnknownExtension {
+    fn check(&self, profile: &Profile, known_extensions: Option<&[String]>) -> Vec<LintFinding> {
+        let Some(known) = known_extensions else {
+            return Vec::new();
+        };
+        profile
+            .extensions
+            .iter()
+            .filter(|name| !known.iter().any(|known| known == *name))
+            .map(|name| LintFinding {
+                severity: LintSeverity::Error,
+                path: format!("extensions[{name}]"),
+                message: format!("{name:?} is not in the extension registry"),
+            })
+            .collect()
+    }
+}
+
+/// Runs every built-in [`LintRule`] over `profile`, cross-referencing
+/// `known_extensions` (the registry's entry names) when given. Pass
+/// `None` to skip registry-dependent rules, e.g. linting offline with no
+/// registry fetched yet.
+pub fn lint(profile: &Profile, known_extensions: Option<&[String]>) -> Vec<LintFinding> {
+    let rules: Vec<Box<dyn LintRule>> =
+        vec![Box::new(EmptyProfile), Box::new(DuplicateExtension), Box::new(UnknownExtension)];
+    rules.iter().flat_map(|rule| rule.check(profile, known_extensions)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(extensions: &[&str]) -> Profile {
+        Profile { name: "test".to_string(), extensions: extensions.iter().map(ToString::to_string).collect() }
+    }
+
+    #[test]
+    fn warns_on_an_empty_profile() {
+        let findings = lint(&profile(&[]), None);
+        assert_eq!(findings, vec![LintFinding {
+            severity: LintSeverity::Warning,
+            path: "extensions".to_string(),
+            message: "profile has no extensions; installing it does nothing".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn warns_on_a_duplicated_extension() {
+        let findings = lint(&profile(&["nodejs", "nodejs"]), None);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, LintSeverity::Warning);
+        assert_eq!(findings[0].path, "extensions[nodejs]");
+    }
+
+    #[test]
+    fn errors_on_an_extension_missing_from_the_registry() {
+        let known = vec!["nodejs".to_string()];
+        let findings = lint(&profile(&["nodejs", "ghost"]), Some(&known));
+        assert_eq!(findings, vec![LintFinding {
+            severity: LintSeverity::Error,
+            path: "extensions[ghost]".to_string(),
+            message: "\"ghost\" is not in the extension registry".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn skips_the_registry_rule_without_known_extensions() {
+        let findings = lint(&profile(&["anything"]), None);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn a_clean_profile_has_no_findings() {
+        let known = vec!["nodejs".to_string(), "rust".to_string()];
+        let findings = lint(&profile(&["nodejs", "rust"]), Some(&known));
+        assert!(findings.is_empty());
+    }
+}
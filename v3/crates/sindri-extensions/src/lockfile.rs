@@ -0,0 +1,208 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::distributor::ExtensionDistributor;
+use crate::manifest;
+use crate::ExtensionError;
+
+/// One extension's pinned version and content hash, as recorded in a
+/// [`Lockfile`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockEntry {
+    pub name: String,
+    pub version: String,
+    pub content_hash: String,
+}
+
+/// A snapshot of exactly which version of each installed extension is in
+/// use, plus a hash of its files, so a team can reproduce the same
+/// environment byte-for-byte — analogous to `cargo --locked`/`Cargo.lock`.
+///
+/// Nothing in this crate yet resolves a version from the registry to
+/// install (there's no end-to-end `install` command to hang `--frozen`
+/// off of), so for now a lockfile is captured from, and checked against,
+/// what's already on disk via [`ExtensionDistributor`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub extensions: Vec<LockEntry>,
+}
+
+impl Lockfile {
+    /// Loads a lockfile from `path`.
+    pub fn load(path: &Path) -> Result<Self, ExtensionError> {
+        let raw = fs::read_to_string(path).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&raw).map_err(ExtensionError::from)
+    }
+
+    /// Writes `self` to `path` as pretty-printed JSON, entries sorted by
+    /// name, so the file diffs cleanly in version control.
+    pub fn write(&self, path: &Path) -> Result<(), ExtensionError> {
+        let mut entries = self.extensions.clone();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let raw = serde_json::to_vec_pretty(&Self { extensions: entries }).map_err(ExtensionError::from)?;
+        fs::write(path, raw).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Captures every installed extension's active version and content
+    /// hash as a new lockfile.
+    pub fn capture(distributor: &ExtensionDistributor) -> Result<Self, ExtensionError> {
+        let mut extensions = Vec::new();
+        for name in distributor.installed_extensions()? {
+            let Some(version) = distributor.current_version(&name) else {
+                continue;
+            };
+            let content_hash = manifest::content_hash(&distributor.version_dir(&name, &version))?;
+            extensions.push(LockEntry { name, version, content_hash });
+        }
+        extensions.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(Self { extensions })
+    }
+
+    /// Compares every locked extension against what [`distributor`] reports
+    /// is actually installed, returning one [`LockDrift`] per mismatch. An
+    /// empty result means the installed environment matches this lockfile
+    /// exactly — what a `--frozen` install should require before
+    /// proceeding.
+    pub fn verify(&self, distributor: &ExtensionDistributor) -> Result<Vec<LockDrift>, ExtensionError> {
+        let mut drift = Vec::new();
+        for entry in &self.extensions {
+            let Some(installed_version) = distributor.current_version(&entry.name) else {
+                drift.push(LockDrift::NotInstalled(entry.name.clone()));
+                continue;
+            };
+            if installed_version != entry.version {
+                drift.push(LockDrift::VersionMismatch {
+                    name: entry.name.clone(),
+                    locked: entry.version.clone(),
+                    installed: installed_version,
+                });
+                continue;
+            }
+            let content_hash =
+                manifest::content_hash(&distributor.version_dir(&entry.name, &installed_version))?;
+            if content_hash != entry.content_hash {
+                drift.push(LockDrift::ContentMismatch(entry.name.clone()));
+            }
+        }
+        Ok(drift)
+    }
+}
+
+/// A way a currently-installed extension can fail to match a [`Lockfile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockDrift {
+    /// The lock names an extension that isn't installed at all.
+    NotInstalled(String),
+    /// The installed version differs from what's locked.
+    VersionMismatch { name: String, locked: String, installed: String },
+    /// The installed version matches, but its files don't hash the same.
+    ContentMismatch(String),
+}
+
+impl std::fmt::Display for LockDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotInstalled(name) => write!(f, "{name}: locked but not installed"),
+            Self::VersionMismatch { name, locked, installed } => {
+                write!(f, "{name}: locked at {locked}, but {installed} is installed")
+            }
+            Self::ContentMismatch(name) => {
+                write!(f, "{name}: installed version matches, but its files have drifted")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install(distributor: &ExtensionDistributor, name: &str, version: &str, content: &[u8]) {
+        distributor
+            .install(name, version, |dir| fs::write(dir.join("bin"), content))
+            .unwrap();
+    }
+
+    #[test]
+    fn capture_then_verify_against_the_same_state_has_no_drift() {
+        let tmp = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(tmp.path());
+        install(&distributor, "nodejs", "20.0.0", b"v20");
+        install(&distributor, "rust", "1.80.0", b"v1.80");
+
+        let lockfile = Lockfile::capture(&distributor).unwrap();
+        assert_eq!(lockfile.extensions.len(), 2);
+        assert!(lockfile.verify(&distributor).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_reports_a_missing_extension() {
+        let tmp = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(tmp.path());
+        install(&distributor, "nodejs", "20.0.0", b"v20");
+        let lockfile = Lockfile::capture(&distributor).unwrap();
+
+        distributor.remove("nodejs").unwrap();
+
+        assert_eq!(
+            lockfile.verify(&distributor).unwrap(),
+            vec![LockDrift::NotInstalled("nodejs".to_string())]
+        );
+    }
+
+    #[test]
+    fn verify_reports_a_version_mismatch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(tmp.path());
+        install(&distributor, "nodejs", "20.0.0", b"v20");
+        let lockfile = Lockfile::capture(&distributor).unwrap();
+
+        install(&distributor, "nodejs", "21.0.0", b"v21");
+
+        assert_eq!(
+            lockfile.verify(&distributor).unwrap(),
+            vec![LockDrift::VersionMismatch {
+                name: "nodejs".to_string(),
+                locked: "20.0.0".to_string(),
+                installed: "21.0.0".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_reports_content_drift_on_the_same_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(tmp.path());
+        install(&distributor, "nodejs", "20.0.0", b"v20");
+        let lockfile = Lockfile::capture(&distributor).unwrap();
+
+        fs::write(distributor.version_dir("nodejs", "20.0.0").join("bin"), b"tampered").unwrap();
+
+        assert_eq!(
+            lockfile.verify(&distributor).unwrap(),
+            vec![LockDrift::ContentMismatch("nodejs".to_string())]
+        );
+    }
+
+    #[test]
+    fn write_then_load_round_trips() {
+        let tmp = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(tmp.path());
+        install(&distributor, "nodejs", "20.0.0", b"v20");
+        let lockfile = Lockfile::capture(&distributor).unwrap();
+
+        let path = tmp.path().join("sindri.lock");
+        lockfile.write(&path).unwrap();
+        let loaded = Lockfile::load(&path).unwrap();
+
+        assert_eq!(loaded, lockfile);
+    }
+}
@@ -0,0 +1,150 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::ExtensionError;
+
+/// Writes a timestamped log of every step run while installing an
+/// extension, to `<logs_dir>/<extension>/<started_at>.log`.
+pub struct ExtensionLogWriter {
+    file: File,
+    path: PathBuf,
+}
+
+impl ExtensionLogWriter {
+    /// Opens the log file for one install run of `extension`, started at
+    /// `started_at` (unix seconds). Creates the extension's log directory
+    /// if needed.
+    pub fn create(logs_dir: &Path, extension: &str, started_at: u64) -> Result<Self, ExtensionError> {
+        let dir = logs_dir.join(extension);
+        fs::create_dir_all(&dir).map_err(|source| ExtensionError::Io { path: dir.clone(), source })?;
+
+        let path = dir.join(format!("{started_at}.log"));
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+
+        Ok(Self { file, path })
+    }
+
+    /// Records a step's outcome.
+    pub fn log(&mut self, step: &str, message: &str) -> Result<(), ExtensionError> {
+        self.write_line(step, message)
+    }
+
+    /// Records that `step` failed, marked clearly so it stands out when
+    /// skimming the log for a bug report.
+    pub fn log_failure(&mut self, step: &str, reason: &str) -> Result<(), ExtensionError> {
+        self.write_line(step, &format!("FAILED: {reason}"))
+    }
+
+    /// Records that `step` failed but, unlike [`Self::log_failure`], didn't
+    /// stop the run.
+    pub fn log_warning(&mut self, step: &str, reason: &str) -> Result<(), ExtensionError> {
+        self.write_line(step, &format!("WARNING: {reason}"))
+    }
+
+    /// Path to the log file being written.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write_line(&mut self, step: &str, message: &str) -> Result<(), ExtensionError> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        writeln!(self.file, "{timestamp} [{step}] {message}").map_err(|source| ExtensionError::Io {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+/// Lists an extension's log files, oldest first, reading their timestamp
+/// from the filename rather than trusting filesystem metadata.
+fn logs_for(logs_dir: &Path, extension: &str) -> Result<Vec<PathBuf>, ExtensionError> {
+    let dir = logs_dir.join(extension);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(ExtensionError::Io { path: dir, source }),
+    };
+
+    let mut logs: Vec<(u64, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let started_at: u64 = path.file_stem()?.to_str()?.parse().ok()?;
+            Some((started_at, path))
+        })
+        .collect();
+    logs.sort_by_key(|(started_at, _)| *started_at);
+    Ok(logs.into_iter().map(|(_, path)| path).collect())
+}
+
+/// Path to `extension`'s most recent install log, if any.
+pub fn latest_log(logs_dir: &Path, extension: &str) -> Result<Option<PathBuf>, ExtensionError> {
+    Ok(logs_for(logs_dir, extension)?.pop())
+}
+
+/// Deletes all but the `keep_last` most recent log files for `extension`,
+/// so repeated installs don't grow the log directory unbounded.
+pub fn rotate_logs(logs_dir: &Path, extension: &str, keep_last: usize) -> Result<(), ExtensionError> {
+    let logs = logs_for(logs_dir, extension)?;
+    let cutoff = logs.len().saturating_sub(keep_last);
+    for path in &logs[..cutoff] {
+        fs::remove_file(path).map_err(|source| ExtensionError::Io { path: path.clone(), source })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_step_and_failure_lines() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut writer = ExtensionLogWriter::create(tmp.path(), "foo", 100).unwrap();
+        writer.log("install", "running npm install").unwrap();
+        writer.log_failure("validate", "exit code 1").unwrap();
+
+        let contents = fs::read_to_string(writer.path()).unwrap();
+        assert!(contents.contains("[install] running npm install"));
+        assert!(contents.contains("[validate] FAILED: exit code 1"));
+    }
+
+    #[test]
+    fn latest_log_picks_the_newest_timestamp() {
+        let tmp = tempfile::tempdir().unwrap();
+        ExtensionLogWriter::create(tmp.path(), "foo", 100).unwrap();
+        ExtensionLogWriter::create(tmp.path(), "foo", 200).unwrap();
+
+        let latest = latest_log(tmp.path(), "foo").unwrap().unwrap();
+        assert_eq!(latest.file_name().unwrap().to_str().unwrap(), "200.log");
+    }
+
+    #[test]
+    fn rotate_logs_keeps_only_the_most_recent() {
+        let tmp = tempfile::tempdir().unwrap();
+        for started_at in [100, 200, 300] {
+            ExtensionLogWriter::create(tmp.path(), "foo", started_at).unwrap();
+        }
+
+        rotate_logs(tmp.path(), "foo", 2).unwrap();
+
+        let remaining = logs_for(tmp.path(), "foo").unwrap();
+        let names: Vec<String> = remaining
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, vec!["200.log".to_string(), "300.log".to_string()]);
+    }
+
+    #[test]
+    fn no_logs_is_not_an_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(latest_log(tmp.path(), "foo").unwrap(), None);
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::ExtensionError;
+
+/// Filename, relative to a version directory, that stores the install-time
+/// file manifest used by checksum verification.
+pub const MANIFEST_FILE: &str = ".sindri-manifest.json";
+
+/// Maps each installed file's path (relative to the version directory) to
+/// its SHA-256 hex digest at install time.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct FileManifest {
+    pub files: BTreeMap<String, String>,
+}
+
+/// A single file whose installed checksum no longer matches the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChecksumDrift {
+    /// File content changed since install.
+    Modified(String),
+    /// File recorded in the manifest is missing on disk.
+    Missing(String),
+    /// File exists on disk but isn't in the manifest (added after install).
+    Unexpected(String),
+}
+
+/// Recursively hashes every file under `dir`, keyed by path relative to
+/// `dir`, skipping the manifest file itself.
+pub fn compute_manifest(dir: &Path) -> Result<FileManifest, ExtensionError> {
+    let mut files = BTreeMap::new();
+    hash_dir(dir, dir, &mut files)?;
+    Ok(FileManifest { files })
+}
+
+fn hash_dir(
+    root: &Path,
+    dir: &Path,
+    files: &mut BTreeMap<String, String>,
+) -> Result<(), ExtensionError> {
+    for entry in fs::read_dir(dir).map_err(|source| ExtensionError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })? {
+        let entry = entry.map_err(|source| ExtensionError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+        let path = entry.path();
+        if path.is_dir() {
+            hash_dir(root, &path, files)?;
+            continue;
+        }
+        if path.file_name().and_then(|n| n.to_str()) == Some(MANIFEST_FILE) {
+            continue;
+        }
+        let bytes = fs::read(&path).map_err(|source| ExtensionError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let digest = Sha256::digest(&bytes);
+        let rel = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        files.insert(rel, hex::encode(digest));
+    }
+    Ok(())
+}
+
+/// A single hash summarizing every file [`compute_manifest`] would record
+/// for `dir`, so a [`crate::Lockfile`] can detect drift without storing a
+/// full per-file manifest inside the lock.
+pub fn content_hash(dir: &Path) -> Result<String, ExtensionError> {
+    let manifest = compute_manifest(dir)?;
+    let mut hasher = Sha256::new();
+    for (path, file_hash) in &manifest.files {
+        hasher.update(path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(file_hash.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Compares the manifest at `dir/.sindri-manifest.json` against the files
+/// currently on disk under `dir`, returning any drift.
+pub fn verify_manifest(dir: &Path) -> Result<Vec<ChecksumDrift>, ExtensionError> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let recorded: FileManifest = {
+        let raw = fs::read_to_string(&manifest_path).map_err(|source| ExtensionError::Io {
+            path: manifest_path.clone(),
+            source,
+        })?;
+        serde_json::from_str(&raw).map_err(|source| ExtensionError::Io {
+            path: manifest_path.clone(),
+            source: std::io::Error::new(std::io::ErrorKind::InvalidData, source),
+        })?
+    };
+
+    let current = compute_manifest(dir)?;
+
+    let mut drift = Vec::new();
+    for (path, hash) in &recorded.files {
+        match current.files.get(path) {
+            None => drift.push(ChecksumDrift::Missing(path.clone())),
+            Some(current_hash) if current_hash != hash => {
+                drift.push(ChecksumDrift::Modified(path.clone()))
+            }
+            _ => {}
+        }
+    }
+    for path in current.files.keys() {
+        if !recorded.files.contains_key(path) {
+            drift.push(ChecksumDrift::Unexpected(path.clone()));
+        }
+    }
+    Ok(drift)
+}
+
+/// Writes `manifest` to `dir/.sindri-manifest.json`.
+pub fn write_manifest(dir: &Path, manifest: &FileManifest) -> Result<(), ExtensionError> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let raw = serde_json::to_vec_pretty(manifest).map_err(|source| ExtensionError::Io {
+        path: manifest_path.clone(),
+        source: std::io::Error::new(std::io::ErrorKind::InvalidData, source),
+    })?;
+    fs::write(&manifest_path, raw).map_err(|source| ExtensionError::Io {
+        path: manifest_path,
+        source,
+    })
+}
+
+/// Minimal hex-encoding helper so we don't pull in a whole `hex` crate for
+/// one call site.
+mod hex {
+    pub fn encode(bytes: impl AsRef<[u8]>) -> String {
+        bytes.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_modified_and_missing_and_unexpected_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+        fs::write(dir.join("b.txt"), b"world").unwrap();
+
+        let manifest = compute_manifest(dir).unwrap();
+        write_manifest(dir, &manifest).unwrap();
+
+        assert!(verify_manifest(dir).unwrap().is_empty());
+
+        fs::write(dir.join("a.txt"), b"tampered").unwrap();
+        fs::remove_file(dir.join("b.txt")).unwrap();
+        fs::write(dir.join("c.txt"), b"new").unwrap();
+
+        let mut drift = verify_manifest(dir).unwrap();
+        drift.sort_by_key(|d| format!("{d:?}"));
+
+        let mut expected = vec![
+            ChecksumDrift::Missing("b.txt".to_string()),
+            ChecksumDrift::Modified("a.txt".to_string()),
+            ChecksumDrift::Unexpected("c.txt".to_string()),
+        ];
+        expected.sort_by_key(|d| format!("{d:?}"));
+
+        assert_eq!(drift, expected);
+    }
+
+    #[test]
+    fn content_hash_changes_when_any_file_changes_and_is_stable_otherwise() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(dir.join("a.txt"), b"hello").unwrap();
+        fs::write(dir.join("b.txt"), b"world").unwrap();
+
+        let before = content_hash(dir).unwrap();
+        assert_eq!(before, content_hash(dir).unwrap());
+
+        fs::write(dir.join("a.txt"), b"tampered").unwrap();
+        assert_ne!(before, content_hash(dir).unwrap());
+    }
+}
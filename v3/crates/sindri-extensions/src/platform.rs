@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ExtensionError;
+
+/// An OS/architecture pair, as accepted by `--platform os/arch` (e.g.
+/// `linux/arm64`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Platform {
+    pub os: String,
+    pub arch: String,
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.os, self.arch)
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ExtensionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (os, arch) = s
+            .split_once('/')
+            .ok_or_else(|| ExtensionError::InvalidPlatform(s.to_string()))?;
+        if os.is_empty() || arch.is_empty() {
+            return Err(ExtensionError::InvalidPlatform(s.to_string()));
+        }
+        Ok(Self { os: os.to_string(), arch: arch.to_string() })
+    }
+}
+
+/// One platform's availability for an extension.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformEntry {
+    pub platform: Platform,
+    #[serde(default)]
+    pub available: bool,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Declares, per extension, which OS/architecture combinations it's
+/// available on. Loaded once from the fetched matrix file and queried by
+/// [`Self::availability`] so install flows can refuse an impossible install
+/// up front rather than failing mid-way through.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlatformMatrix {
+    pub extensions: BTreeMap<String, Vec<PlatformEntry>>,
+}
+
+/// Whether an extension is available on a platform, and why not if it
+/// isn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatformAvailability {
+    pub available: bool,
+    pub reason: Option<String>,
+}
+
+impl PlatformMatrix {
+    /// Loads a matrix from JSON at `path`.
+    pub fn load(path: &Path) -> Result<Self, ExtensionError> {
+        let raw = fs::read(path).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(serde_json::from_slice(&raw)?)
+    }
+
+    /// Looks up whether `extension` is available on `platform`. An
+    /// extension with no entry for that platform at all is treated as
+    /// available — the matrix has nothing to say against it.
+    pub fn availability(&self, extension: &str, platform: &Platform) -> PlatformAvailability {
+        let entry = self
+            .extensions
+            .get(extension)
+            .and_then(|entries| entries.iter().find(|e| &e.platform == platform));
+
+        match entry {
+            None => PlatformAvailability { available: true, reason: None },
+            Some(entry) => PlatformAvailability {
+                available: entry.available,
+                reason: entry.reason.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linux_arm64() -> Platform {
+        Platform { os: "linux".to_string(), arch: "arm64".to_string() }
+    }
+
+    #[test]
+    fn parses_os_slash_arch() {
+        let platform: Platform = "linux/arm64".parse().unwrap();
+        assert_eq!(platform, linux_arm64());
+    }
+
+    #[test]
+    fn rejects_a_platform_string_without_a_slash() {
+        assert!("linux".parse::<Platform>().is_err());
+    }
+
+    #[test]
+    fn unlisted_platform_is_assumed_available() {
+        let matrix = PlatformMatrix::default();
+        let availability = matrix.availability("kubernetes-tools", &linux_arm64());
+        assert!(availability.available);
+        assert_eq!(availability.reason, None);
+    }
+
+    #[test]
+    fn unavailable_platform_surfaces_its_reason() {
+        let matrix = PlatformMatrix {
+            extensions: BTreeMap::from([(
+                "kubernetes-tools".to_string(),
+                vec![PlatformEntry {
+                    platform: linux_arm64(),
+                    available: false,
+                    reason: Some("no arm64 build of kubectl is published yet".to_string()),
+                }],
+            )]),
+        };
+
+        let availability = matrix.availability("kubernetes-tools", &linux_arm64());
+        assert!(!availability.available);
+        assert_eq!(
+            availability.reason,
+            Some("no arm64 build of kubectl is published yet".to_string())
+        );
+    }
+}
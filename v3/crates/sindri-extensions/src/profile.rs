@@ -0,0 +1,234 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ExtensionError;
+
+/// A named, versionable set of extensions meant to be installed together.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub extensions: Vec<String>,
+}
+
+/// The file format a [`Profile`] was read from or should be written as.
+///
+/// This is the extension profile's own multi-format support, unrelated
+/// to `sindri.yaml`'s — for that, see
+/// `sindri_core::MultiTargetConfig::load`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ProfileFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Yaml => "YAML",
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+        }
+    }
+
+    /// Detects the format from a file's extension, defaulting to YAML for
+    /// an unrecognized or missing extension (the format this repo has
+    /// always used).
+    fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::Json,
+            Some("toml") => Self::Toml,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+impl Profile {
+    /// Loads a profile from a YAML, JSON, or TOML file (`name`,
+    /// `extensions`), detected from the file's extension. Whichever
+    /// format it's in, the result is the same [`Profile`] and is subject
+    /// to the same validation.
+    pub fn load(path: &Path) -> Result<Self, ExtensionError> {
+        let raw = fs::read_to_string(path).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let format = ProfileFormat::from_extension(path);
+        Self::parse(&raw, format).map_err(|reason| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source: std::io::Error::new(std::io::ErrorKind::InvalidData, reason),
+        })
+    }
+
+    fn parse(raw: &str, format: ProfileFormat) -> Result<Self, String> {
+        let result = match format {
+            ProfileFormat::Yaml => serde_yaml::from_str(raw).map_err(|e| e.to_string()),
+            ProfileFormat::Json => serde_json::from_str(raw).map_err(|e| e.to_string()),
+            ProfileFormat::Toml => toml::from_str(raw).map_err(|e| e.to_string()),
+        };
+        result.map_err(|reason| format!("{} parse error: {reason}", format.name()))
+    }
+}
+
+/// The JSON Schema for a [`Profile`] YAML file, for editor integration
+/// (point a YAML LSP at it) rather than for runtime validation — [`load`]
+/// goes straight through `serde_yaml` and doesn't consult this.
+///
+/// This is the extension profile's own schema, unrelated to a
+/// `sindri.yaml` deploy config — for that, see
+/// `sindri_core::multi_target_config_json_schema` (`sindri config
+/// schema`).
+///
+/// Hand-written rather than derived, since [`Profile`] is small and
+/// stable; revisit if it grows fields often enough that the two drift.
+pub fn json_schema() -> serde_json::Value {
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Profile",
+        "type": "object",
+        "required": ["name"],
+        "additionalProperties": false,
+        "properties": {
+            "name": {
+                "type": "string",
+                "description": "Name identifying this profile."
+            },
+            "extensions": {
+                "type": "array",
+                "items": { "type": "string" },
+                "default": [],
+                "description": "Names of extensions this profile installs together."
+            }
+        }
+    })
+}
+
+/// The result of comparing a [`Profile`]'s extension set against the
+/// extensions currently installed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProfileDiff {
+    /// In the profile but not installed yet.
+    pub will_install: Vec<String>,
+    /// In the profile and already installed.
+    pub already_have: Vec<String>,
+    /// Installed but not in the profile — only dropped if this profile
+    /// replaces the current set outright (e.g. `sindri profile switch`).
+    pub would_remove: Vec<String>,
+}
+
+impl ProfileDiff {
+    /// Computes the symmetric difference between `profile`'s extensions and
+    /// `installed`. Every list is sorted for stable, diffable output.
+    pub fn compute(profile: &Profile, installed: &[String]) -> Self {
+        let wanted: BTreeSet<&str> = profile.extensions.iter().map(String::as_str).collect();
+        let installed: BTreeSet<&str> = installed.iter().map(String::as_str).collect();
+
+        let mut will_install: Vec<String> =
+            wanted.difference(&installed).map(ToString::to_string).collect();
+        let mut already_have: Vec<String> =
+            wanted.intersection(&installed).map(ToString::to_string).collect();
+        let mut would_remove: Vec<String> =
+            installed.difference(&wanted).map(ToString::to_string).collect();
+        will_install.sort();
+        already_have.sort();
+        would_remove.sort();
+
+        Self { will_install, already_have, would_remove }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(extensions: &[&str]) -> Profile {
+        Profile {
+            name: "test".to_string(),
+            extensions: extensions.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn splits_into_install_keep_and_remove() {
+        let diff = ProfileDiff::compute(
+            &profile(&["nodejs", "python", "docker"]),
+            &["python".to_string(), "rust".to_string()],
+        );
+
+        assert_eq!(diff.will_install, vec!["docker".to_string(), "nodejs".to_string()]);
+        assert_eq!(diff.already_have, vec!["python".to_string()]);
+        assert_eq!(diff.would_remove, vec!["rust".to_string()]);
+    }
+
+    #[test]
+    fn identical_sets_have_nothing_to_install_or_remove() {
+        let diff = ProfileDiff::compute(&profile(&["nodejs"]), &["nodejs".to_string()]);
+        assert!(diff.will_install.is_empty());
+        assert!(diff.would_remove.is_empty());
+        assert_eq!(diff.already_have, vec!["nodejs".to_string()]);
+    }
+
+    #[test]
+    fn empty_profile_would_remove_everything_installed() {
+        let diff = ProfileDiff::compute(&profile(&[]), &["nodejs".to_string(), "python".to_string()]);
+        assert_eq!(diff.would_remove, vec!["nodejs".to_string(), "python".to_string()]);
+        assert!(diff.will_install.is_empty());
+        assert!(diff.already_have.is_empty());
+    }
+
+    #[test]
+    fn json_schema_declares_every_profile_field() {
+        let schema = json_schema();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("name"));
+        assert!(properties.contains_key("extensions"));
+        assert_eq!(schema["required"], serde_json::json!(["name"]));
+    }
+
+    #[test]
+    fn loads_a_profile_from_yaml() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("profile.yaml");
+        fs::write(&path, "name: web\nextensions: [nodejs, docker]\n").unwrap();
+
+        let profile = Profile::load(&path).unwrap();
+        assert_eq!(profile.name, "web");
+        assert_eq!(profile.extensions, vec!["nodejs".to_string(), "docker".to_string()]);
+    }
+
+    #[test]
+    fn loads_a_profile_from_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("profile.json");
+        fs::write(&path, r#"{"name": "web", "extensions": ["nodejs", "docker"]}"#).unwrap();
+
+        let profile = Profile::load(&path).unwrap();
+        assert_eq!(profile.name, "web");
+        assert_eq!(profile.extensions, vec!["nodejs".to_string(), "docker".to_string()]);
+    }
+
+    #[test]
+    fn loads_a_profile_from_toml() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("profile.toml");
+        fs::write(&path, "name = \"web\"\nextensions = [\"nodejs\", \"docker\"]\n").unwrap();
+
+        let profile = Profile::load(&path).unwrap();
+        assert_eq!(profile.name, "web");
+        assert_eq!(profile.extensions, vec!["nodejs".to_string(), "docker".to_string()]);
+    }
+
+    #[test]
+    fn names_the_format_in_a_parse_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("profile.toml");
+        fs::write(&path, "not valid toml {{{").unwrap();
+
+        let err = Profile::load(&path).unwrap_err().to_string();
+        assert!(err.contains("TOML parse error"), "unexpected message: {err}");
+    }
+}
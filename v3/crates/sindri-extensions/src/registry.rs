@@ -0,0 +1,293 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ExtensionError, ExtensionRequirements};
+
+/// A catalog entry describing an extension available for install, as
+/// distinct from one already on disk (see [`crate::ExtensionDistributor`]).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionEntry {
+    pub name: String,
+    pub description: String,
+    pub category: String,
+    /// Names of the BOM tools this extension installs (e.g. `kubectl`).
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Free-form facets for discovery (e.g. `gpu`), in addition to whatever
+    /// [`Self::derived_tags`] adds automatically.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub capabilities: ExtensionCapabilities,
+    /// Disk/memory this extension's install needs, checked against the
+    /// host before any step runs.
+    #[serde(default)]
+    pub requirements: ExtensionRequirements,
+    /// The `owner/repo` this entry was loaded from, when loaded via
+    /// [`ExtensionRegistry::load_from_github`] or
+    /// [`crate::load_federated`]. `None` for entries loaded from a local
+    /// file via [`ExtensionRegistry::load`].
+    #[serde(default)]
+    pub origin: Option<String>,
+}
+
+impl ExtensionEntry {
+    /// The tags this entry should be filterable by: its own [`Self::tags`]
+    /// plus any implied by [`Self::capabilities`] (e.g. `mcp` when
+    /// `capabilities.mcp.enabled`), deduplicated.
+    pub fn effective_tags(&self) -> Vec<String> {
+        let mut tags = self.tags.clone();
+        if self.capabilities.mcp.enabled && !tags.iter().any(|t| t == "mcp") {
+            tags.push("mcp".to_string());
+        }
+        tags
+    }
+}
+
+/// Capability flags that can drive auto-derived tags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionCapabilities {
+    #[serde(default)]
+    pub mcp: McpCapability,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct McpCapability {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// The catalog of extensions available for install, loaded once and
+/// searched/filtered entirely in memory so it works offline once cached.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionRegistry {
+    entries: Vec<ExtensionEntry>,
+}
+
+impl ExtensionRegistry {
+    pub fn new(entries: Vec<ExtensionEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Loads a registry from a JSON array of [`ExtensionEntry`] at `path`.
+    pub fn load(path: &Path) -> Result<Self, ExtensionError> {
+        let raw = fs::read(path).map_err(|source| ExtensionError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let entries: Vec<ExtensionEntry> = serde_json::from_slice(&raw)?;
+        Ok(Self::new(entries))
+    }
+
+    pub fn entries(&self) -> &[ExtensionEntry] {
+        &self.entries
+    }
+
+    /// Consumes the registry, returning its entries.
+    pub fn into_entries(self) -> Vec<ExtensionEntry> {
+        self.entries
+    }
+
+    /// Fetches a registry JSON file from a GitHub repo at a given ref,
+    /// tagging every entry with `source.repo` as its
+    /// [`ExtensionEntry::origin`]. Goes through
+    /// [`sindri_core::build_http_client`] so it honors the same
+    /// proxy/CA configuration as every other HTTP client in the CLI.
+    pub async fn load_from_github(source: &crate::remote::RegistrySource) -> Result<Self, ExtensionError> {
+        let url = source.raw_url(&source.path);
+        let body = sindri_core::build_http_client()
+            .get(&url)
+            .send()
+            .await
+            .map_err(|err| ExtensionError::Fetch { url: url.clone(), reason: err.to_string() })?
+            .error_for_status()
+            .map_err(|err| ExtensionError::Fetch { url: url.clone(), reason: err.to_string() })?
+            .text()
+            .await
+            .map_err(|err| ExtensionError::Fetch { url: url.clone(), reason: err.to_string() })?;
+
+        let mut entries: Vec<ExtensionEntry> = serde_json::from_str(&body)?;
+        for entry in &mut entries {
+            entry.origin = Some(source.repo.clone());
+        }
+        Ok(Self::new(entries))
+    }
+
+    /// Searches names, descriptions, and tool names for `query`, ranking
+    /// results by relevance. An exact field match scores highest, a literal
+    /// substring match next, and a fuzzy (in-order character subsequence)
+    /// match lowest — so `k8s` can surface `kubernetes-tools` via its
+    /// description even though `k8s` never appears in the name.
+    pub fn search(&self, query: &str) -> Vec<(String, u32)> {
+        let mut scored: Vec<(String, u32)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let score = score_entry(entry, query);
+                (score > 0).then(|| (entry.name.clone(), score))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scored
+    }
+
+    /// Entries carrying every one of `tags` (AND semantics), via
+    /// [`ExtensionEntry::effective_tags`].
+    pub fn filter_by_tags(&self, tags: &[String]) -> Vec<&ExtensionEntry> {
+        if tags.is_empty() {
+            return self.entries.iter().collect();
+        }
+        self.entries
+            .iter()
+            .filter(|entry| {
+                let effective = entry.effective_tags();
+                tags.iter().all(|tag| effective.iter().any(|t| t == tag))
+            })
+            .collect()
+    }
+}
+
+fn score_entry(entry: &ExtensionEntry, query: &str) -> u32 {
+    let mut score = field_score(&entry.name, query, 100, 60);
+    score = score.max(field_score(&entry.description, query, 70, 30));
+    for tool in &entry.tools {
+        score = score.max(field_score(tool, query, 80, 40));
+    }
+    score
+}
+
+fn field_score(field: &str, query: &str, substring: u32, fuzzy: u32) -> u32 {
+    if query.is_empty() {
+        return 0;
+    }
+    let field = field.to_lowercase();
+    let query = query.to_lowercase();
+
+    if field == query {
+        substring + 20
+    } else if field.contains(&query) {
+        substring
+    } else if is_subsequence(&field, &query) {
+        fuzzy
+    } else {
+        0
+    }
+}
+
+/// Whether every character of `needle` appears in `haystack`, in order
+/// (not necessarily contiguous).
+fn is_subsequence(haystack: &str, needle: &str) -> bool {
+    let mut haystack_chars = haystack.chars();
+    needle.chars().all(|nc| haystack_chars.any(|hc| hc == nc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kubernetes_tools() -> ExtensionEntry {
+        ExtensionEntry {
+            name: "kubernetes-tools".to_string(),
+            description: "Kubernetes (k8s) cluster tooling including kubectl and helm".to_string(),
+            category: "cloud".to_string(),
+            tools: vec!["kubectl".to_string(), "helm".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn rust_toolchain() -> ExtensionEntry {
+        ExtensionEntry {
+            name: "rust".to_string(),
+            description: "Rust compiler and cargo".to_string(),
+            category: "language".to_string(),
+            tools: vec!["cargo".to_string(), "rustc".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finds_entries_via_description_even_without_a_literal_name_match() {
+        let registry = ExtensionRegistry::new(vec![kubernetes_tools(), rust_toolchain()]);
+        let results = registry.search("k8s");
+        assert_eq!(results[0].0, "kubernetes-tools");
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn exact_name_match_outranks_substring_and_fuzzy_matches() {
+        let registry = ExtensionRegistry::new(vec![
+            ExtensionEntry {
+                name: "rust".to_string(),
+                description: "rusty things".to_string(),
+                category: "language".to_string(),
+                ..Default::default()
+            },
+            ExtensionEntry {
+                name: "rustfmt".to_string(),
+                description: "formatter".to_string(),
+                category: "language".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        let results = registry.search("rust");
+        assert_eq!(results[0].0, "rust");
+        assert!(results[0].1 > results[1].1);
+    }
+
+    #[test]
+    fn empty_query_matches_nothing() {
+        let registry = ExtensionRegistry::new(vec![rust_toolchain()]);
+        assert!(registry.search("").is_empty());
+    }
+
+    #[test]
+    fn fuzzy_subsequence_matches_when_no_substring_matches() {
+        let registry = ExtensionRegistry::new(vec![kubernetes_tools()]);
+        // "ktl" is a subsequence of "kubectl" but not a literal substring.
+        let results = registry.search("ktl");
+        assert_eq!(results[0].0, "kubernetes-tools");
+    }
+
+    #[test]
+    fn filter_by_tags_ands_multiple_tags() {
+        let gpu_mcp = ExtensionEntry {
+            name: "gpu-mcp".to_string(),
+            tags: vec!["gpu".to_string()],
+            capabilities: ExtensionCapabilities {
+                mcp: McpCapability { enabled: true },
+            },
+            ..Default::default()
+        };
+        let gpu_only = ExtensionEntry {
+            name: "gpu-only".to_string(),
+            tags: vec!["gpu".to_string()],
+            ..Default::default()
+        };
+        let registry = ExtensionRegistry::new(vec![gpu_mcp.clone(), gpu_only]);
+
+        let results = registry.filter_by_tags(&["gpu".to_string(), "mcp".to_string()]);
+        assert_eq!(results, vec![&gpu_mcp]);
+    }
+
+    #[test]
+    fn filter_by_tags_with_no_tags_returns_every_entry() {
+        let registry = ExtensionRegistry::new(vec![kubernetes_tools(), rust_toolchain()]);
+        assert_eq!(registry.filter_by_tags(&[]).len(), 2);
+    }
+
+    #[test]
+    fn mcp_capability_implies_mcp_tag_without_declaring_it_explicitly() {
+        let entry = ExtensionEntry {
+            name: "some-mcp-server".to_string(),
+            capabilities: ExtensionCapabilities {
+                mcp: McpCapability { enabled: true },
+            },
+            ..Default::default()
+        };
+        assert_eq!(entry.effective_tags(), vec!["mcp".to_string()]);
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::ExtensionEntry;
+use crate::{ExtensionError, ExtensionRegistry};
+
+/// One remote registry to pull extension catalog entries from: a GitHub
+/// repo at a specific ref, with the registry JSON file at `path` inside it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistrySource {
+    /// `owner/repo`.
+    pub repo: String,
+    /// Branch, tag, or commit to read from.
+    pub git_ref: String,
+    /// Path to the registry JSON file inside the repo.
+    pub path: String,
+}
+
+impl RegistrySource {
+    pub fn new(repo: impl Into<String>, git_ref: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            repo: repo.into(),
+            git_ref: git_ref.into(),
+            path: path.into(),
+        }
+    }
+
+    /// The raw-content URL for `relative_path` inside this source.
+    pub fn raw_url(&self, relative_path: &str) -> String {
+        format!("https://raw.githubusercontent.com/{}/{}/{relative_path}", self.repo, self.git_ref)
+    }
+}
+
+/// The result of merging an ordered list of [`RegistrySource`]s: earlier
+/// sources win on a name collision, and every collision is recorded in
+/// [`Self::collisions`] for the caller to warn about.
+pub struct FederatedRegistry {
+    pub registry: ExtensionRegistry,
+    pub collisions: Vec<String>,
+}
+
+/// Fetches every source in order and merges them into one registry.
+/// Entries are kept from the first source that defines a given name;
+/// later sources defining the same name are dropped and noted as a
+/// collision rather than silently overwriting the earlier one.
+pub async fn load_federated(sources: &[RegistrySource]) -> Result<FederatedRegistry, ExtensionError> {
+    let mut by_name: HashMap<String, ExtensionEntry> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+    let mut collisions = Vec::new();
+
+    for source in sources {
+        let loaded = ExtensionRegistry::load_from_github(source).await?;
+        for entry in loaded.into_entries() {
+            match by_name.get(&entry.name) {
+                Some(existing) => collisions.push(format!(
+                    "extension \"{}\" is defined by both {} and {} — keeping {}",
+                    entry.name,
+                    existing.origin.as_deref().unwrap_or("unknown"),
+                    source.repo,
+                    existing.origin.as_deref().unwrap_or("unknown"),
+                )),
+                None => {
+                    order.push(entry.name.clone());
+                    by_name.insert(entry.name.clone(), entry);
+                }
+            }
+        }
+    }
+
+    let entries = order.into_iter().filter_map(|name| by_name.remove(&name)).collect();
+    Ok(FederatedRegistry { registry: ExtensionRegistry::new(entries), collisions })
+}
+
+/// Resolves which [`RegistrySource`] an extension's files should be
+/// downloaded from, based on the `origin` [`load_federated`] recorded on
+/// its [`ExtensionEntry`].
+pub struct ExtensionSourceResolver {
+    origins: HashMap<String, RegistrySource>,
+}
+
+impl ExtensionSourceResolver {
+    pub fn new(sources: &[RegistrySource], registry: &ExtensionRegistry) -> Self {
+        let by_repo: HashMap<&str, &RegistrySource> =
+            sources.iter().map(|source| (source.repo.as_str(), source)).collect();
+
+        let origins = registry
+            .entries()
+            .iter()
+            .filter_map(|entry| {
+                let origin = entry.origin.as_deref()?;
+                let source = by_repo.get(origin)?;
+                Some((entry.name.clone(), (*source).clone()))
+            })
+            .collect();
+
+        Self { origins }
+    }
+
+    /// The source `name` should be downloaded from, if its origin is
+    /// known and still configured.
+    pub fn resolve(&self, name: &str) -> Option<&RegistrySource> {
+        self.origins.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, origin: &str) -> ExtensionEntry {
+        ExtensionEntry {
+            name: name.to_string(),
+            origin: Some(origin.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn raw_url_points_at_the_repo_ref_and_path() {
+        let source = RegistrySource::new("acme/private-extensions", "main", "registry.json");
+        assert_eq!(
+            source.raw_url("registry.json"),
+            "https://raw.githubusercontent.com/acme/private-extensions/main/registry.json"
+        );
+    }
+
+    #[test]
+    fn resolver_maps_extension_names_to_their_origin_source() {
+        let private = RegistrySource::new("acme/private-extensions", "main", "registry.json");
+        let upstream = RegistrySource::new("pacphi/sindri", "main", "registry.json");
+        let registry = ExtensionRegistry::new(vec![
+            entry("widget", "acme/private-extensions"),
+            entry("nodejs", "pacphi/sindri"),
+        ]);
+
+        let resolver = ExtensionSourceResolver::new(&[private.clone(), upstream.clone()], &registry);
+
+        assert_eq!(resolver.resolve("widget"), Some(&private));
+        assert_eq!(resolver.resolve("nodejs"), Some(&upstream));
+        assert_eq!(resolver.resolve("missing"), None);
+    }
+}
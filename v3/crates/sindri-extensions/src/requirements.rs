@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ExtensionError;
+
+/// Disk/memory, in bytes, an extension's install needs. Checked against the
+/// host before any install step runs so a install doesn't fail partway
+/// through on a small sandbox (E2B, RunPod, ...).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtensionRequirements {
+    #[serde(default)]
+    pub disk_space: u64,
+    #[serde(default)]
+    pub memory: u64,
+    /// Domains this extension needs to reach at install/run time (e.g. a
+    /// registry or cloud endpoint), checked by `sindri doctor --network`.
+    #[serde(default)]
+    pub domains: Vec<String>,
+}
+
+impl ExtensionRequirements {
+    /// Combines a batch of requirements (e.g. every extension in a profile
+    /// install) into the total disk/memory the batch needs at once.
+    pub fn sum(requirements: &[ExtensionRequirements]) -> ExtensionRequirements {
+        requirements.iter().fold(Self::default(), |acc, r| ExtensionRequirements {
+            disk_space: acc.disk_space.saturating_add(r.disk_space),
+            memory: acc.memory.saturating_add(r.memory),
+            domains: Vec::new(),
+        })
+    }
+}
+
+/// Checks `required` against what's actually available at `disk_path` and
+/// in system memory, returning a clear error naming the shortfall.
+/// Disk/memory that can't be determined (e.g. unsupported platform) is
+/// treated as unconstrained rather than blocking the install.
+pub fn check_requirements(required: &ExtensionRequirements, disk_path: &Path) -> Result<(), ExtensionError> {
+    if let Ok(available) = available_disk_bytes(disk_path) {
+        if available < required.disk_space {
+            return Err(ExtensionError::InsufficientResources {
+                resource: "disk space".to_string(),
+                required: required.disk_space,
+                available,
+            });
+        }
+    }
+
+    if let Ok(available) = available_memory_bytes() {
+        if available < required.memory {
+            return Err(ExtensionError::InsufficientResources {
+                resource: "memory".to_string(),
+                required: required.memory,
+                available,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn available_disk_bytes(path: &Path) -> std::io::Result<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(not(unix))]
+fn available_disk_bytes(_path: &Path) -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "free disk space checks are only implemented on unix",
+    ))
+}
+
+#[cfg(target_os = "linux")]
+fn available_memory_bytes() -> std::io::Result<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo")?;
+    for line in meminfo.lines() {
+        if let Some(kb) = line.strip_prefix("MemAvailable:") {
+            let kb: u64 = kb.trim().trim_end_matches(" kB").trim().parse().map_err(|_| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "unparseable MemAvailable line")
+            })?;
+            return Ok(kb * 1024);
+        }
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "MemAvailable not present in /proc/meminfo",
+    ))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn available_memory_bytes() -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "free memory checks are only implemented on linux",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_requirements_across_a_batch() {
+        let total = ExtensionRequirements::sum(&[
+            ExtensionRequirements { disk_space: 100, memory: 10, domains: Vec::new() },
+            ExtensionRequirements { disk_space: 200, memory: 20, domains: Vec::new() },
+        ]);
+        assert_eq!(total, ExtensionRequirements { disk_space: 300, memory: 30, domains: Vec::new() });
+    }
+
+    #[test]
+    fn sum_of_no_requirements_is_zero() {
+        assert_eq!(ExtensionRequirements::sum(&[]), ExtensionRequirements::default());
+    }
+
+    #[test]
+    fn passes_when_requirements_are_trivially_small() {
+        let tmp = tempfile::tempdir().unwrap();
+        let requirements = ExtensionRequirements { disk_space: 1, memory: 1, domains: Vec::new() };
+        check_requirements(&requirements, tmp.path()).unwrap();
+    }
+
+    #[test]
+    fn fails_when_disk_space_required_exceeds_what_is_actually_available() {
+        let tmp = tempfile::tempdir().unwrap();
+        let requirements = ExtensionRequirements { disk_space: u64::MAX - 1, memory: 0, domains: Vec::new() };
+        let err = check_requirements(&requirements, tmp.path());
+        #[cfg(unix)]
+        assert!(matches!(err, Err(ExtensionError::InsufficientResources { .. })));
+        #[cfg(not(unix))]
+        assert!(err.is_ok());
+    }
+}
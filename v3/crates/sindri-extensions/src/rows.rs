@@ -0,0 +1,118 @@
+use serde::Serialize;
+use sindri_core::TableRow;
+
+/// A row in `sindri extension list`. `current_version` is `None` and
+/// `installed` is `false` for a catalog entry surfaced only via
+/// `--all`, one that's in the registry but not yet installed.
+#[derive(Debug, Clone, Serialize)]
+pub struct AvailableExtensionRow {
+    pub name: String,
+    pub current_version: Option<String>,
+    pub installed: bool,
+}
+
+impl TableRow for AvailableExtensionRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["NAME", "CURRENT", "INSTALLED"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.current_version.clone().unwrap_or_else(|| "-".to_string()),
+            self.installed.to_string(),
+        ]
+    }
+}
+
+/// A row in `sindri extension status`.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusRow {
+    pub name: String,
+    pub version: String,
+    pub state: String,
+}
+
+impl TableRow for StatusRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["NAME", "VERSION", "STATE"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.name.clone(), self.version.clone(), self.state.clone()]
+    }
+}
+
+/// A row in `sindri extension search`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResultRow {
+    pub name: String,
+    pub score: u32,
+}
+
+impl TableRow for SearchResultRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["NAME", "SCORE"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.name.clone(), self.score.to_string()]
+    }
+}
+
+/// A row in `sindri extension versions`.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionRow {
+    pub version: String,
+    pub active: bool,
+}
+
+impl TableRow for VersionRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["VERSION", "ACTIVE"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.version.clone(), self.active.to_string()]
+    }
+}
+
+/// A row in `sindri extension history`.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryRow {
+    pub summary: String,
+}
+
+impl TableRow for HistoryRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["EVENT"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.summary.clone()]
+    }
+}
+
+/// A row in `sindri extension versions --explain`.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionExplainRow {
+    pub version: String,
+    pub active: bool,
+    pub compatible: bool,
+    pub reason: String,
+}
+
+impl TableRow for VersionExplainRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["VERSION", "ACTIVE", "COMPATIBLE", "REASON"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![
+            self.version.clone(),
+            self.active.to_string(),
+            self.compatible.to_string(),
+            self.reason.clone(),
+        ]
+    }
+}
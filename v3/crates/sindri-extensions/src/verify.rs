@@ -0,0 +1,52 @@
+use crate::manifest::ChecksumDrift;
+use crate::{ExtensionDistributor, ExtensionError};
+
+/// Result of verifying an installed extension.
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    /// Whether the extension's validation commands passed.
+    pub commands_ok: bool,
+    /// File checksum drift versus the install-time manifest, if checked.
+    pub checksum_drift: Option<Vec<ChecksumDrift>>,
+}
+
+impl VerifyReport {
+    /// True when validation commands passed and, if checked, no file drift
+    /// was found.
+    pub fn is_healthy(&self) -> bool {
+        self.commands_ok
+            && self
+                .checksum_drift
+                .as_ref()
+                .map(|drift| drift.is_empty())
+                .unwrap_or(true)
+    }
+}
+
+/// Verifies that `name`'s currently active version still passes its
+/// validation commands and, optionally, that its on-disk files match the
+/// manifest recorded at install time (catching tamper/corruption that a
+/// passing `--version` check alone would miss).
+pub fn verify_extension_installed(
+    distributor: &ExtensionDistributor,
+    name: &str,
+    run_validation_commands: impl FnOnce() -> bool,
+    check_checksums: bool,
+) -> Result<VerifyReport, ExtensionError> {
+    let version = distributor
+        .current_version(name)
+        .ok_or_else(|| ExtensionError::NotInstalled(name.to_string()))?;
+
+    let commands_ok = run_validation_commands();
+
+    let checksum_drift = if check_checksums {
+        Some(distributor.verify_checksums(name, &version)?)
+    } else {
+        None
+    };
+
+    Ok(VerifyReport {
+        commands_ok,
+        checksum_drift,
+    })
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImageError {
+    #[error("invalid image reference {0:?}: {1}")]
+    InvalidReference(String, &'static str),
+
+    #[error("reference {0} has no digest to look up referrers for")]
+    MissingDigest(String),
+
+    #[error("registry request failed: {0}")]
+    Registry(#[from] reqwest::Error),
+
+    #[error("no SBOM found for {0}")]
+    SbomNotFound(String),
+
+    #[error("invalid SBOM: {0}")]
+    InvalidSbom(String),
+
+    #[error("failed to read advisory database: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{0:?} is not a configured channel")]
+    UnknownChannel(String),
+
+    #[error("channel {channel} (digest {digest}) does not match any versioned tag for {reference}")]
+    ChannelVersionNotFound {
+        channel: String,
+        digest: String,
+        reference: String,
+    },
+
+    #[error("platform {platform} not found in the manifest list for {reference}")]
+    PlatformNotFound { platform: String, reference: String },
+
+    #[error("invalid manifest list for {0}: {1}")]
+    InvalidManifestList(String, serde_json::Error),
+
+    #[error("token exchange with {0} did not return a token")]
+    TokenExchangeFailed(String),
+}
@@ -0,0 +1,19 @@
+//! Container image management.
+
+mod error;
+mod osv;
+mod platform;
+mod reference;
+mod registry;
+mod sbom;
+mod scan;
+mod version;
+
+pub use error::ImageError;
+pub use osv::{OsvAdvisory, OsvDatabase};
+pub use platform::Platform;
+pub use reference::ImageReference;
+pub use registry::{Descriptor, RegistryClient, RegistryCredentials, SBOM_ARTIFACT_TYPE};
+pub use sbom::SbomPackage;
+pub use scan::{scan, ScanFinding};
+pub use version::VersionResolver;
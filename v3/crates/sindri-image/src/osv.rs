@@ -0,0 +1,109 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{ImageError, SbomPackage};
+
+/// An OSV advisory, matched against SBOM packages by exact name and exact
+/// version membership only — this is a lookup against a known-affected
+/// list, not a semver-range vulnerability scanner.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OsvAdvisory {
+    pub id: String,
+    pub summary: String,
+    #[serde(default)]
+    pub affected: Vec<OsvAffected>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OsvAffected {
+    pub package: OsvPackage,
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OsvPackage {
+    pub name: String,
+    pub ecosystem: String,
+}
+
+/// A set of OSV advisories loaded from a local directory snapshot — one
+/// JSON advisory per file. There is no network download or caching here;
+/// refreshing the snapshot is the caller's responsibility.
+#[derive(Debug, Default)]
+pub struct OsvDatabase {
+    advisories: Vec<OsvAdvisory>,
+}
+
+impl OsvDatabase {
+    /// Loads every `*.json` file directly inside `dir` as an [`OsvAdvisory`].
+    pub fn load(dir: &Path) -> Result<Self, ImageError> {
+        let mut advisories = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let contents = fs::read_to_string(&path)?;
+            let advisory: OsvAdvisory = serde_json::from_str(&contents)
+                .map_err(|err| ImageError::InvalidSbom(format!("{}: {err}", path.display())))?;
+            advisories.push(advisory);
+        }
+        Ok(Self { advisories })
+    }
+
+    /// Returns every advisory affecting `package`, matching by exact
+    /// package name and exact version membership in `affected.versions`.
+    pub fn matches(&self, package: &SbomPackage) -> Vec<&OsvAdvisory> {
+        let Some(version) = &package.version else {
+            return Vec::new();
+        };
+        self.advisories
+            .iter()
+            .filter(|advisory| {
+                advisory.affected.iter().any(|affected| {
+                    affected.package.name == package.name && affected.versions.contains(version)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advisory(name: &str, versions: &[&str]) -> OsvAdvisory {
+        OsvAdvisory {
+            id: "OSV-2024-0001".to_string(),
+            summary: "example advisory".to_string(),
+            affected: vec![OsvAffected {
+                package: OsvPackage { name: name.to_string(), ecosystem: "crates.io".to_string() },
+                versions: versions.iter().map(|v| v.to_string()).collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn matches_exact_name_and_version() {
+        let db = OsvDatabase { advisories: vec![advisory("openssl", &["1.1.1", "1.1.2"])] };
+        let package = SbomPackage { name: "openssl".to_string(), version: Some("1.1.1".to_string()) };
+        assert_eq!(db.matches(&package).len(), 1);
+    }
+
+    #[test]
+    fn does_not_match_unlisted_version() {
+        let db = OsvDatabase { advisories: vec![advisory("openssl", &["1.1.1"])] };
+        let package = SbomPackage { name: "openssl".to_string(), version: Some("1.1.2".to_string()) };
+        assert!(db.matches(&package).is_empty());
+    }
+
+    #[test]
+    fn packages_without_a_version_never_match() {
+        let db = OsvDatabase { advisories: vec![advisory("openssl", &["1.1.1"])] };
+        let package = SbomPackage { name: "openssl".to_string(), version: None };
+        assert!(db.matches(&package).is_empty());
+    }
+}
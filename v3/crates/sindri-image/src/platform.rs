@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An OS/architecture pair to resolve a multi-arch manifest list against,
+/// e.g. `linux/arm64`. Mirrors the `platform` object OCI image indexes and
+/// Docker manifest lists report per entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Platform {
+    pub os: String,
+    pub arch: String,
+}
+
+impl Platform {
+    pub fn new(os: impl Into<String>, arch: impl Into<String>) -> Self {
+        Self { os: os.into(), arch: arch.into() }
+    }
+
+    /// The platform this binary is running on, via `std::env::consts`.
+    /// Used as the default target when a caller doesn't name one
+    /// explicitly, so resolving an image without `--platform` never
+    /// silently pulls the wrong architecture.
+    pub fn host() -> Self {
+        Self::new(std::env::consts::OS, normalize_arch(std::env::consts::ARCH))
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.os, self.arch)
+    }
+}
+
+/// Maps Rust's `ARCH` names onto the ones OCI manifests use, where they
+/// differ (`x86_64`/`amd64`, `aarch64`/`arm64`); everything else passes
+/// through unchanged.
+fn normalize_arch(arch: &str) -> &str {
+    match arch {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_as_os_slash_arch() {
+        assert_eq!(Platform::new("linux", "arm64").to_string(), "linux/arm64");
+    }
+
+    #[test]
+    fn host_normalizes_rust_arch_names_to_oci_ones() {
+        let host = Platform::host();
+        assert!(!host.arch.contains('_'), "expected an OCI arch name, got {:?}", host.arch);
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ImageError, Platform};
+
+const DEFAULT_REGISTRY: &str = "docker.io";
+const DEFAULT_NAMESPACE: &str = "library";
+
+/// A fully-qualified OCI/Docker image reference:
+/// `[registry[:port]/]repository[:tag][@digest]`.
+///
+/// Parsing defaults a missing registry to `docker.io` and, for single-segment
+/// repositories under the default registry, prefixes the `library/`
+/// namespace — matching Docker's own resolution rules. `to_string` always
+/// reproduces this fully-qualified canonical form, so round-tripping a
+/// shorthand reference like `alpine:3.19` yields
+/// `docker.io/library/alpine:3.19`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageReference {
+    pub registry: String,
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+    /// The channel tag (e.g. `stable`) this reference was resolved from,
+    /// if any. Set by [`crate::VersionResolver::resolve_channel`]; parsing
+    /// a reference directly from a string never sets it.
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// The platform this reference's digest was resolved for, if any. Set
+    /// by [`crate::RegistryClient::resolve_platform`]; parsing a
+    /// reference directly from a string never sets it.
+    #[serde(default)]
+    pub platform: Option<Platform>,
+}
+
+impl FromStr for ImageReference {
+    type Err = ImageError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.is_empty() {
+            return Err(ImageError::InvalidReference(
+                input.to_string(),
+                "reference is empty",
+            ));
+        }
+
+        let (rest, digest) = match input.rsplit_once('@') {
+            Some((rest, digest)) => (rest, Some(digest.to_string())),
+            None => (input, None),
+        };
+        if rest.is_empty() {
+            return Err(ImageError::InvalidReference(
+                input.to_string(),
+                "missing repository before '@'",
+            ));
+        }
+
+        // A ':' after the last '/' is a tag separator; a ':' before it (or
+        // with no '/' at all, e.g. `localhost:5000/foo`) is a registry port.
+        let last_slash = rest.rfind('/');
+        let tag_colon = rest.rfind(':').filter(|&idx| match last_slash {
+            Some(slash_idx) => idx > slash_idx,
+            None => {
+                // No '/': a bare `name:tag` has a tag; `localhost:5000` alone
+                // (no repository) is invalid, but we don't need to special
+                // case it since the registry/repository split below always
+                // leaves a non-empty repository in that case.
+                true
+            }
+        });
+        let (rest, tag) = match tag_colon {
+            Some(idx) => (&rest[..idx], Some(rest[idx + 1..].to_string())),
+            None => (rest, None),
+        };
+        if rest.is_empty() {
+            return Err(ImageError::InvalidReference(
+                input.to_string(),
+                "missing repository before ':'",
+            ));
+        }
+
+        let (registry, repository) = match rest.split_once('/') {
+            Some((first, remainder)) if looks_like_registry(first) => {
+                (first.to_string(), remainder.to_string())
+            }
+            Some(_) => (DEFAULT_REGISTRY.to_string(), rest.to_string()),
+            None => (
+                DEFAULT_REGISTRY.to_string(),
+                format!("{DEFAULT_NAMESPACE}/{rest}"),
+            ),
+        };
+        if repository.is_empty() {
+            return Err(ImageError::InvalidReference(
+                input.to_string(),
+                "missing repository",
+            ));
+        }
+
+        Ok(Self {
+            registry,
+            repository,
+            tag,
+            digest,
+            channel: None,
+            platform: None,
+        })
+    }
+}
+
+fn looks_like_registry(segment: &str) -> bool {
+    segment == "localhost" || segment.contains('.') || segment.contains(':')
+}
+
+impl fmt::Display for ImageReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.registry, self.repository)?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{tag}")?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> ImageReference {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn defaults_registry_and_library_namespace() {
+        let r = parse("alpine");
+        assert_eq!(r.registry, "docker.io");
+        assert_eq!(r.repository, "library/alpine");
+        assert_eq!(r.tag, None);
+        assert_eq!(r.to_string(), "docker.io/library/alpine");
+    }
+
+    #[test]
+    fn shorthand_with_tag_round_trips() {
+        let r = parse("alpine:3.19");
+        assert_eq!(r.to_string(), "docker.io/library/alpine:3.19");
+    }
+
+    #[test]
+    fn namespaced_repository_without_explicit_registry() {
+        let r = parse("library/nginx:1.27");
+        assert_eq!(r.registry, "docker.io");
+        assert_eq!(r.repository, "library/nginx");
+        assert_eq!(r.tag, Some("1.27".to_string()));
+    }
+
+    #[test]
+    fn registry_with_port_and_digest() {
+        let r = parse("localhost:5000/foo@sha256:deadbeef");
+        assert_eq!(r.registry, "localhost:5000");
+        assert_eq!(r.repository, "foo");
+        assert_eq!(r.tag, None);
+        assert_eq!(r.digest, Some("sha256:deadbeef".to_string()));
+        assert_eq!(r.to_string(), "localhost:5000/foo@sha256:deadbeef");
+    }
+
+    #[test]
+    fn tag_and_digest_together() {
+        let r = parse("ghcr.io/pacphi/sindri:v3@sha256:abc123");
+        assert_eq!(r.registry, "ghcr.io");
+        assert_eq!(r.repository, "pacphi/sindri");
+        assert_eq!(r.tag, Some("v3".to_string()));
+        assert_eq!(r.digest, Some("sha256:abc123".to_string()));
+    }
+
+    #[test]
+    fn explicit_registry_with_port_and_namespace() {
+        let r = parse("registry.example.com:5000/team/app:latest");
+        assert_eq!(r.registry, "registry.example.com:5000");
+        assert_eq!(r.repository, "team/app");
+        assert_eq!(r.tag, Some("latest".to_string()));
+    }
+
+    #[test]
+    fn rejects_empty_reference() {
+        assert!("".parse::<ImageReference>().is_err());
+    }
+}
@@ -0,0 +1,463 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::Deserialize;
+
+use crate::{ImageError, ImageReference, Platform};
+
+/// Username/password presented during the bearer token exchange for
+/// registries that require authentication. Anonymous registries (most
+/// public images) never trigger the exchange, so these are only read on
+/// a 401.
+#[derive(Debug, Clone, Default)]
+pub struct RegistryCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// A bearer token cached for one repository, honoring the issuing
+/// registry's own expiry rather than a fixed TTL.
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+impl CachedToken {
+    fn is_valid(&self) -> bool {
+        Instant::now() < self.expires_at
+    }
+}
+
+/// A `WWW-Authenticate: Bearer ...` challenge, per the Docker/OCI
+/// distribution token-auth spec.
+#[derive(Debug, PartialEq, Eq)]
+struct BearerChallenge {
+    realm: String,
+    service: Option<String>,
+    scope: Option<String>,
+}
+
+/// Parses a `WWW-Authenticate` header value into its `Bearer` challenge
+/// parameters, e.g. `Bearer realm="https://auth.docker.io/token",
+/// service="registry.docker.io",scope="repository:library/alpine:pull"`.
+/// Returns `None` for any other auth scheme (Basic, Digest, ...), which
+/// this client doesn't attempt to satisfy.
+fn parse_bearer_challenge(header: &str) -> Option<BearerChallenge> {
+    let rest = header.strip_prefix("Bearer ")?;
+    let mut realm = None;
+    let mut service = None;
+    let mut scope = None;
+    for param in rest.split(',') {
+        let (key, value) = param.trim().split_once('=')?;
+        let value = value.trim_matches('"').to_string();
+        match key {
+            "realm" => realm = Some(value),
+            "service" => service = Some(value),
+            "scope" => scope = Some(value),
+            _ => {}
+        }
+    }
+    Some(BearerChallenge { realm: realm?, service, scope })
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Registries that don't send an explicit `expires_in`, per the
+/// distribution spec's documented default.
+const DEFAULT_TOKEN_TTL_SECS: u64 = 300;
+
+/// Accept header for manifest requests that may resolve to either a
+/// multi-arch manifest list/index or a single-platform manifest.
+const MANIFEST_LIST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, \
+    application/vnd.docker.distribution.manifest.list.v2+json, \
+    application/vnd.oci.image.manifest.v1+json, \
+    application/vnd.docker.distribution.manifest.v2+json";
+
+#[derive(Debug, Deserialize)]
+struct ManifestListEntry {
+    digest: String,
+    platform: ManifestListPlatform,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestListPlatform {
+    os: String,
+    architecture: String,
+}
+
+/// Either shape a `GET /v2/<repository>/manifests/<tag>` response can take:
+/// a multi-arch list/index, or a single-platform manifest with no
+/// `manifests` field at all.
+#[derive(Debug, Default, Deserialize)]
+struct ManifestOrList {
+    #[serde(default)]
+    manifests: Vec<ManifestListEntry>,
+}
+
+/// Media type used for SPDX JSON SBOMs, the artifact type we filter the
+/// referrers API by and the content type of the tag-convention fallback.
+pub const SBOM_ARTIFACT_TYPE: &str = "application/spdx+json";
+
+/// A descriptor for a referrer artifact (SBOM, provenance, signature, ...)
+/// as returned by the OCI referrers API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Descriptor {
+    #[serde(rename = "mediaType")]
+    pub media_type: String,
+    pub digest: String,
+    pub size: u64,
+    #[serde(rename = "artifactType", default)]
+    pub artifact_type: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ReferrersIndex {
+    #[serde(default)]
+    manifests: Vec<Descriptor>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TagsList {
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// A minimal OCI Distribution client for discovering artifacts attached to
+/// an image, such as SBOMs and provenance attestations.
+///
+/// Every request is tried anonymously first. On a 401 carrying a `Bearer`
+/// [`WWW-Authenticate` challenge](parse_bearer_challenge), it performs the
+/// token-exchange dance against the challenge's `realm` — using
+/// [`Self::with_credentials`]'s credentials if set, or anonymously
+/// otherwise, since some registries require the exchange even for public
+/// images — and retries with the resulting bearer token. Tokens are
+/// cached per repository until they expire, so the same code path works
+/// unmodified for public and private images.
+pub struct RegistryClient {
+    pub(crate) http: reqwest::Client,
+    credentials: Option<RegistryCredentials>,
+    token_cache: Mutex<HashMap<String, CachedToken>>,
+}
+
+impl Default for RegistryClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegistryClient {
+    pub fn new() -> Self {
+        Self {
+            http: sindri_core::build_http_client(),
+            credentials: None,
+            token_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Presents `credentials` during the token exchange if a registry
+    /// challenges a request with 401. Has no effect against a registry
+    /// that never challenges (fully anonymous/public).
+    pub fn with_credentials(mut self, credentials: RegistryCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub(crate) fn base_url(registry: &str) -> String {
+        if registry == "docker.io" {
+            "https://registry-1.docker.io".to_string()
+        } else {
+            format!("https://{registry}")
+        }
+    }
+
+    /// Sends a request to `url`, retrying once with a bearer token if the
+    /// first attempt (using any cached token for `reference`'s repository,
+    /// or anonymous if none) comes back 401 with a `Bearer` challenge. See
+    /// the type-level docs for the full anonymous-then-authenticated flow.
+    async fn send(
+        &self,
+        reference: &ImageReference,
+        method: Method,
+        url: &str,
+        accept: Option<&str>,
+    ) -> Result<Response, ImageError> {
+        let build = |token: Option<&str>| -> RequestBuilder {
+            let mut builder = self.http.request(method.clone(), url);
+            if let Some(accept) = accept {
+                builder = builder.header("Accept", accept);
+            }
+            if let Some(token) = token {
+                builder = builder.bearer_auth(token);
+            }
+            builder
+        };
+
+        if let Some(token) = self.cached_token(&reference.repository) {
+            return Ok(build(Some(&token)).send().await?);
+        }
+
+        let response = build(None).send().await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        let Some(challenge) = response
+            .headers()
+            .get("WWW-Authenticate")
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_bearer_challenge)
+        else {
+            return Ok(response);
+        };
+
+        let token = self.exchange_token(&challenge).await?;
+        self.cache_token(reference.repository.clone(), token.clone());
+        Ok(build(Some(&token.token)).send().await?)
+    }
+
+    fn cached_token(&self, repository: &str) -> Option<String> {
+        let cache = self.token_cache.lock().unwrap();
+        cache.get(repository).filter(|cached| cached.is_valid()).map(|cached| cached.token.clone())
+    }
+
+    fn cache_token(&self, repository: String, token: CachedToken) {
+        self.token_cache.lock().unwrap().insert(repository, token);
+    }
+
+    /// Exchanges `challenge` for a bearer token against its `realm`,
+    /// passing `service`/`scope` through unchanged and authenticating
+    /// with [`Self::with_credentials`]'s credentials if configured.
+    async fn exchange_token(&self, challenge: &BearerChallenge) -> Result<CachedToken, ImageError> {
+        let mut request = self.http.get(&challenge.realm);
+        let mut query = Vec::new();
+        if let Some(service) = &challenge.service {
+            query.push(("service", service.as_str()));
+        }
+        if let Some(scope) = &challenge.scope {
+            query.push(("scope", scope.as_str()));
+        }
+        request = request.query(&query);
+        if let Some(credentials) = &self.credentials {
+            request = request.basic_auth(&credentials.username, Some(&credentials.password));
+        }
+
+        let body: TokenResponse = request.send().await?.error_for_status()?.json().await?;
+        let token = body
+            .token
+            .or(body.access_token)
+            .ok_or_else(|| ImageError::TokenExchangeFailed(challenge.realm.clone()))?;
+        let ttl = body.expires_in.unwrap_or(DEFAULT_TOKEN_TTL_SECS);
+        Ok(CachedToken { token, expires_at: Instant::now() + Duration::from_secs(ttl) })
+    }
+
+    /// Queries `GET /v2/<repository>/referrers/<digest>`, optionally
+    /// filtered to `artifact_type`. Returns `Ok(vec![])` rather than an
+    /// error when the registry doesn't implement the referrers API (a 404)
+    /// — callers needing a fallback should treat an empty result as "not
+    /// supported, try something else" rather than "definitely nothing
+    /// attached".
+    pub async fn referrers(
+        &self,
+        reference: &ImageReference,
+        artifact_type: Option<&str>,
+    ) -> Result<Vec<Descriptor>, ImageError> {
+        let digest = reference
+            .digest
+            .as_ref()
+            .ok_or_else(|| ImageError::MissingDigest(reference.to_string()))?;
+        let mut url = format!(
+            "{}/v2/{}/referrers/{digest}",
+            Self::base_url(&reference.registry),
+            reference.repository,
+        );
+        if let Some(artifact_type) = artifact_type {
+            url.push_str("?artifactType=");
+            url.push_str(artifact_type);
+        }
+
+        let response = self.send(reference, Method::GET, &url, None).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        let index: ReferrersIndex = response.error_for_status()?.json().await?;
+        Ok(index.manifests)
+    }
+
+    /// Finds the SBOM attached to `reference`: first via the referrers API,
+    /// falling back to the `sha256-<digest>.sbom` tag convention (used by
+    /// tools such as syft) when the registry doesn't support referrers.
+    pub async fn find_sbom(&self, reference: &ImageReference) -> Result<Descriptor, ImageError> {
+        let via_referrers = self.referrers(reference, Some(SBOM_ARTIFACT_TYPE)).await?;
+        if let Some(descriptor) = via_referrers.into_iter().next() {
+            return Ok(descriptor);
+        }
+
+        let digest = reference
+            .digest
+            .as_ref()
+            .ok_or_else(|| ImageError::MissingDigest(reference.to_string()))?;
+        let fallback_tag = format!("{}.sbom", digest.replace(':', "-"));
+        let url = format!(
+            "{}/v2/{}/manifests/{fallback_tag}",
+            Self::base_url(&reference.registry),
+            reference.repository,
+        );
+        let response = self
+            .send(reference, Method::GET, &url, Some("application/vnd.oci.image.manifest.v1+json"))
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(ImageError::SbomNotFound(reference.to_string()));
+        }
+        let response = response.error_for_status()?;
+        let fallback_digest = response
+            .headers()
+            .get("Docker-Content-Digest")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(&fallback_tag)
+            .to_string();
+        let body = response.bytes().await?;
+        Ok(Descriptor {
+            media_type: "application/vnd.oci.image.manifest.v1+json".to_string(),
+            digest: fallback_digest,
+            size: body.len() as u64,
+            artifact_type: Some(SBOM_ARTIFACT_TYPE.to_string()),
+        })
+    }
+
+    /// Queries `GET /v2/<repository>/tags/list`, returning every tag the
+    /// registry reports for `reference`'s repository.
+    pub async fn list_tags(&self, reference: &ImageReference) -> Result<Vec<String>, ImageError> {
+        let url = format!(
+            "{}/v2/{}/tags/list",
+            Self::base_url(&reference.registry),
+            reference.repository,
+        );
+        let list: TagsList = self
+            .send(reference, Method::GET, &url, None)
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(list.tags)
+    }
+
+    /// Resolves `tag` to its content digest via a `HEAD` request against
+    /// the manifest endpoint, reading the `Docker-Content-Digest` header.
+    pub async fn digest_for_tag(
+        &self,
+        reference: &ImageReference,
+        tag: &str,
+    ) -> Result<String, ImageError> {
+        let url = format!(
+            "{}/v2/{}/manifests/{tag}",
+            Self::base_url(&reference.registry),
+            reference.repository,
+        );
+        let response = self
+            .send(reference, Method::HEAD, &url, Some("application/vnd.oci.image.manifest.v1+json"))
+            .await?
+            .error_for_status()?;
+        response
+            .headers()
+            .get("Docker-Content-Digest")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| ImageError::MissingDigest(format!("{reference}:{tag}")))
+    }
+
+    /// Resolves `tag` to `platform`'s manifest digest, pinning `reference`
+    /// to it and recording `platform` on it. If `tag` points at a
+    /// multi-arch manifest list/index, picks the entry matching
+    /// `platform`'s `os`/`arch`, erroring with
+    /// [`ImageError::PlatformNotFound`] if none does. If it points at a
+    /// single-platform manifest instead (no `manifests` list), that
+    /// manifest's own digest is returned as-is, since there's nothing to
+    /// pick between.
+    pub async fn resolve_platform(
+        &self,
+        reference: &ImageReference,
+        tag: &str,
+        platform: &Platform,
+    ) -> Result<ImageReference, ImageError> {
+        let url = format!(
+            "{}/v2/{}/manifests/{tag}",
+            Self::base_url(&reference.registry),
+            reference.repository,
+        );
+        let response = self
+            .send(reference, Method::GET, &url, Some(MANIFEST_LIST_ACCEPT))
+            .await?
+            .error_for_status()?;
+
+        let single_manifest_digest = response
+            .headers()
+            .get("Docker-Content-Digest")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let body = response.bytes().await?;
+        let parsed: ManifestOrList = serde_json::from_slice(&body)
+            .map_err(|err| ImageError::InvalidManifestList(reference.to_string(), err))?;
+
+        let digest = if parsed.manifests.is_empty() {
+            single_manifest_digest
+                .ok_or_else(|| ImageError::MissingDigest(format!("{reference}:{tag}")))?
+        } else {
+            parsed
+                .manifests
+                .into_iter()
+                .find(|entry| {
+                    entry.platform.os == platform.os && entry.platform.architecture == platform.arch
+                })
+                .map(|entry| entry.digest)
+                .ok_or_else(|| ImageError::PlatformNotFound {
+                    platform: platform.to_string(),
+                    reference: reference.to_string(),
+                })?
+        };
+
+        Ok(ImageReference {
+            registry: reference.registry.clone(),
+            repository: reference.repository.clone(),
+            tag: Some(tag.to_string()),
+            digest: Some(digest),
+            channel: None,
+            platform: Some(platform.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_realm_service_and_scope_from_a_bearer_challenge() {
+        let header = r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/alpine:pull""#;
+        let challenge = parse_bearer_challenge(header).unwrap();
+        assert_eq!(challenge.realm, "https://auth.docker.io/token");
+        assert_eq!(challenge.service, Some("registry.docker.io".to_string()));
+        assert_eq!(challenge.scope, Some("repository:library/alpine:pull".to_string()));
+    }
+
+    #[test]
+    fn a_challenge_with_no_service_or_scope_still_parses_the_realm() {
+        let challenge = parse_bearer_challenge(r#"Bearer realm="https://example.com/token""#).unwrap();
+        assert_eq!(challenge.realm, "https://example.com/token");
+        assert_eq!(challenge.service, None);
+        assert_eq!(challenge.scope, None);
+    }
+
+    #[test]
+    fn a_non_bearer_challenge_is_not_parsed() {
+        assert!(parse_bearer_challenge(r#"Basic realm="registry""#).is_none());
+    }
+}
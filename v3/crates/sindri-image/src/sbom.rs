@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+use crate::{ImageError, ImageReference, RegistryClient, SBOM_ARTIFACT_TYPE};
+
+/// A single package recorded in an SPDX SBOM, with just enough detail to
+/// cross-reference it against a vulnerability source.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SbomPackage {
+    pub name: String,
+    #[serde(rename = "versionInfo")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpdxDocument {
+    #[serde(default)]
+    packages: Vec<SbomPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OciManifest {
+    #[serde(default)]
+    layers: Vec<OciLayer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OciLayer {
+    digest: String,
+}
+
+impl RegistryClient {
+    /// Fetches `reference`'s SBOM via [`Self::find_sbom`] and parses it
+    /// as an SPDX document, returning its package list. Only SPDX's
+    /// `packages` array is read — anything else in the document is
+    /// ignored.
+    pub async fn fetch_sbom_packages(
+        &self,
+        reference: &ImageReference,
+    ) -> Result<Vec<SbomPackage>, ImageError> {
+        let descriptor = self.find_sbom(reference).await?;
+        let manifest_bytes = self
+            .fetch_blob(reference, "manifests", &descriptor.digest, Some(SBOM_ARTIFACT_TYPE))
+            .await?;
+        let manifest: OciManifest = serde_json::from_slice(&manifest_bytes)
+            .map_err(|err| ImageError::InvalidSbom(err.to_string()))?;
+        let layer = manifest
+            .layers
+            .first()
+            .ok_or_else(|| ImageError::InvalidSbom("SBOM manifest has no layers".to_string()))?;
+
+        let sbom_bytes = self.fetch_blob(reference, "blobs", &layer.digest, None).await?;
+        let document: SpdxDocument = serde_json::from_slice(&sbom_bytes)
+            .map_err(|err| ImageError::InvalidSbom(err.to_string()))?;
+        Ok(document.packages)
+    }
+
+    async fn fetch_blob(
+        &self,
+        reference: &ImageReference,
+        kind: &str,
+        digest: &str,
+        accept: Option<&str>,
+    ) -> Result<Vec<u8>, ImageError> {
+        let url =
+            format!("{}/v2/{}/{kind}/{digest}", Self::base_url(&reference.registry), reference.repository);
+        let mut request = self.http.get(&url);
+        if let Some(accept) = accept {
+            request = request.header("Accept", accept);
+        }
+        let response = request.send().await?.error_for_status()?;
+        Ok(response.bytes().await?.to_vec())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{ImageError, ImageReference, OsvAdvisory, OsvDatabase, RegistryClient, SbomPackage};
+
+/// An SBOM package matched against one or more OSV advisories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanFinding {
+    pub package: SbomPackage,
+    pub advisories: Vec<OsvAdvisory>,
+}
+
+/// Fetches `reference`'s SBOM and reports every package matched against
+/// `database`. Packages with no matching advisory are omitted.
+pub async fn scan(
+    client: &RegistryClient,
+    reference: &ImageReference,
+    database: &OsvDatabase,
+) -> Result<Vec<ScanFinding>, ImageError> {
+    let packages = client.fetch_sbom_packages(reference).await?;
+    Ok(packages
+        .into_iter()
+        .filter_map(|package| {
+            let advisories: Vec<OsvAdvisory> =
+                database.matches(&package).into_iter().cloned().collect();
+            if advisories.is_empty() {
+                None
+            } else {
+                Some(ScanFinding { package, advisories })
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use semver::{Comparator, Op, Version, VersionReq};
+
+use crate::{ImageError, ImageReference, RegistryClient};
+
+/// Resolves semver constraints and channel tags (`edge`, `stable`, ...)
+/// against a repository's published tags. Channel tags are configured up
+/// front so they can be excluded from semver matching and recognized by
+/// [`Self::resolve_channel`].
+#[derive(Debug, Clone, Default)]
+pub struct VersionResolver {
+    channels: HashSet<String>,
+}
+
+impl VersionResolver {
+    pub fn new(channels: impl IntoIterator<Item = String>) -> Self {
+        Self { channels: channels.into_iter().collect() }
+    }
+
+    /// Returns the highest tag in `available_tags` satisfying `constraint`,
+    /// ignoring configured channel tags and any tag that isn't valid
+    /// semver.
+    ///
+    /// Prerelease handling follows standard semver rules: a prerelease tag
+    /// (e.g. `3.1.0-rc.1`) is only considered when `constraint` itself
+    /// names a prerelease on the same major.minor.patch (e.g.
+    /// `>=3.2.0-rc`), so `^3.0.0` alone never picks one. Set
+    /// `allow_prerelease` to force prereleases into consideration even
+    /// when the constraint doesn't mention one.
+    pub fn resolve_version(
+        &self,
+        available_tags: &[String],
+        constraint: &VersionReq,
+        allow_prerelease: bool,
+    ) -> Option<String> {
+        available_tags
+            .iter()
+            .filter(|tag| !self.channels.contains(tag.as_str()))
+            .filter_map(|tag| Version::parse(tag).ok().map(|version| (version, tag)))
+            .filter(|(version, _)| {
+                constraint.matches(version)
+                    || (allow_prerelease
+                        && !version.pre.is_empty()
+                        && matches_ignoring_prerelease_gate(constraint, version))
+            })
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, tag)| tag.clone())
+    }
+
+    /// Resolves `channel` to the concrete versioned tag and digest it
+    /// currently points at, pinning `reference` to that digest while
+    /// recording `channel` on it — reproducible today, and re-resolvable
+    /// later to follow the channel forward.
+    pub async fn resolve_channel(
+        &self,
+        client: &RegistryClient,
+        reference: &ImageReference,
+        channel: &str,
+    ) -> Result<ImageReference, ImageError> {
+        if !self.channels.contains(channel) {
+            return Err(ImageError::UnknownChannel(channel.to_string()));
+        }
+
+        let mut channel_ref = reference.clone();
+        channel_ref.tag = Some(channel.to_string());
+        channel_ref.digest = None;
+        let digest = client.digest_for_tag(&channel_ref, channel).await?;
+
+        let tags = client.list_tags(reference).await?;
+        let mut versioned_tags: Vec<Version> = tags
+            .iter()
+            .filter(|tag| !self.channels.contains(tag.as_str()))
+            .filter_map(|tag| Version::parse(tag).ok())
+            .collect();
+        versioned_tags.sort();
+
+        for version in versioned_tags.into_iter().rev() {
+            let tag = version.to_string();
+            if client.digest_for_tag(reference, &tag).await? == digest {
+                return Ok(ImageReference {
+                    registry: reference.registry.clone(),
+                    repository: reference.repository.clone(),
+                    tag: Some(tag),
+                    digest: Some(digest),
+                    channel: Some(channel.to_string()),
+                    platform: None,
+                });
+            }
+        }
+
+        Err(ImageError::ChannelVersionNotFound {
+            channel: channel.to_string(),
+            digest,
+            reference: reference.to_string(),
+        })
+    }
+}
+
+/// Re-checks `version` against every comparator in `constraint` without
+/// the standard semver rule that excludes a prerelease version unless the
+/// matching comparator also names a prerelease — used only for the
+/// `allow_prerelease` override in [`VersionResolver::resolve_version`].
+fn matches_ignoring_prerelease_gate(constraint: &VersionReq, version: &Version) -> bool {
+    constraint.comparators.iter().all(|cmp| comparator_matches(cmp, version))
+}
+
+fn comparator_matches(cmp: &Comparator, ver: &Version) -> bool {
+    match cmp.op {
+        Op::Exact | Op::Wildcard => {
+            ver.major == cmp.major
+                && cmp.minor.is_none_or(|minor| ver.minor == minor)
+                && cmp.patch.is_none_or(|patch| ver.patch == patch)
+        }
+        Op::Greater => version_cmp(cmp, ver) == std::cmp::Ordering::Less,
+        Op::GreaterEq => version_cmp(cmp, ver) != std::cmp::Ordering::Greater,
+        Op::Less => version_cmp(cmp, ver) == std::cmp::Ordering::Greater,
+        Op::LessEq => version_cmp(cmp, ver) != std::cmp::Ordering::Less,
+        Op::Tilde => matches_tilde(cmp, ver),
+        Op::Caret => matches_caret(cmp, ver),
+        _ => false,
+    }
+}
+
+/// Compares `cmp`'s named major.minor.patch against `ver`, treating any
+/// component `cmp` leaves unspecified as matching. Ordering is undefined
+/// (returns `Equal`) once an unspecified component is reached, matching
+/// how `>`/`<` treat a partial comparator as always false in upstream
+/// semver — callers only use this for strict `Less`/`Greater` comparisons
+/// where a fully-specified `cmp` is expected.
+fn version_cmp(cmp: &Comparator, ver: &Version) -> std::cmp::Ordering {
+    ver.major.cmp(&cmp.major).then_with(|| match cmp.minor {
+        Some(minor) => ver.minor.cmp(&minor).then_with(|| match cmp.patch {
+            Some(patch) => ver.patch.cmp(&patch),
+            None => std::cmp::Ordering::Equal,
+        }),
+        None => std::cmp::Ordering::Equal,
+    })
+}
+
+fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return false;
+    }
+    match cmp.minor {
+        Some(minor) if ver.minor != minor => false,
+        Some(_) => cmp.patch.is_none_or(|patch| ver.patch >= patch),
+        None => true,
+    }
+}
+
+fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return false;
+    }
+    let Some(minor) = cmp.minor else { return true };
+    let Some(patch) = cmp.patch else {
+        return if cmp.major > 0 { ver.minor >= minor } else { ver.minor == minor };
+    };
+    if cmp.major > 0 {
+        ver.minor > minor || (ver.minor == minor && ver.patch >= patch)
+    } else if minor > 0 {
+        ver.minor == minor && ver.patch >= patch
+    } else {
+        ver.minor == minor && ver.patch == patch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> VersionResolver {
+        VersionResolver::new(["edge".to_string(), "stable".to_string()])
+    }
+
+    #[test]
+    fn resolves_highest_version_satisfying_the_constraint() {
+        let tags = vec!["1.0.0".to_string(), "1.2.0".to_string(), "2.0.0".to_string()];
+        let constraint = VersionReq::parse("^1").unwrap();
+        assert_eq!(resolver().resolve_version(&tags, &constraint, false), Some("1.2.0".to_string()));
+    }
+
+    #[test]
+    fn ignores_channel_tags_when_resolving_semver() {
+        let tags = vec!["edge".to_string(), "stable".to_string(), "1.0.0".to_string()];
+        let constraint = VersionReq::parse("*").unwrap();
+        assert_eq!(resolver().resolve_version(&tags, &constraint, false), Some("1.0.0".to_string()));
+    }
+
+    #[test]
+    fn returns_none_when_nothing_satisfies_the_constraint() {
+        let tags = vec!["1.0.0".to_string()];
+        let constraint = VersionReq::parse("^2").unwrap();
+        assert_eq!(resolver().resolve_version(&tags, &constraint, false), None);
+    }
+
+    #[test]
+    fn caret_constraint_excludes_prereleases_by_default() {
+        let tags = vec!["3.1.0-rc.1".to_string(), "3.0.5".to_string()];
+        let constraint = VersionReq::parse("^3.0.0").unwrap();
+        assert_eq!(resolver().resolve_version(&tags, &constraint, false), Some("3.0.5".to_string()));
+    }
+
+    #[test]
+    fn constraint_naming_its_own_prerelease_matches_it_without_the_override() {
+        let tags = vec!["3.2.0-rc.1".to_string(), "3.1.0".to_string()];
+        let constraint = VersionReq::parse(">=3.2.0-rc").unwrap();
+        assert_eq!(resolver().resolve_version(&tags, &constraint, false), Some("3.2.0-rc.1".to_string()));
+    }
+
+    #[test]
+    fn allow_prerelease_forces_inclusion_even_without_a_prerelease_in_the_constraint() {
+        let tags = vec!["3.1.0-rc.1".to_string(), "3.0.5".to_string()];
+        let constraint = VersionReq::parse("^3.0.0").unwrap();
+        assert_eq!(
+            resolver().resolve_version(&tags, &constraint, true),
+            Some("3.1.0-rc.1".to_string())
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::PackerError;
+
+/// Outcome of a single cloud's build, as recorded in a [`BuildState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildOutcome {
+    Success,
+    Failed,
+}
+
+/// The result of building for one cloud, persisted so a `--resume` can
+/// skip it next time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildRecord {
+    pub outcome: BuildOutcome,
+    /// The built image's id, if the build succeeded.
+    pub image_id: Option<String>,
+}
+
+/// Per-cloud build progress for one multi-cloud build run, keyed by cloud
+/// name (e.g. `"aws"`, `"gcp"`). Tied to the config that produced it via
+/// [`config_hash`] so a changed config can't resume against stale records.
+///
+/// There's no `build_multi_cloud` orchestration in this crate yet — no
+/// cloud has a Packer build backend wired up, only the image-lifecycle
+/// side (`list_images`/`delete_image`) does — so this only covers the
+/// state-persistence half of resumable builds; wiring a `--resume` flag
+/// into an actual build loop is future work for when that orchestration
+/// exists.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildState {
+    pub config_hash: String,
+    pub clouds: BTreeMap<String, BuildRecord>,
+}
+
+impl BuildState {
+    /// Hashes `config_contents` (the config file's raw bytes) so a saved
+    /// [`BuildState`] can be invalidated when the config it was built from
+    /// changes.
+    pub fn config_hash(config_contents: &[u8]) -> String {
+        hex::encode(Sha256::digest(config_contents))
+    }
+
+    /// Loads a previously saved state from `path`, or `None` if it doesn't
+    /// exist yet (e.g. this is the first build attempt).
+    pub fn load(path: &Path) -> Result<Option<Self>, PackerError> {
+        match fs::read_to_string(path) {
+            Ok(raw) => {
+                let state = serde_json::from_str(&raw).map_err(PackerError::InvalidState)?;
+                Ok(Some(state))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(PackerError::Io(err)),
+        }
+    }
+
+    /// Persists this state to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), PackerError> {
+        let raw = serde_json::to_vec_pretty(self).map_err(PackerError::InvalidState)?;
+        fs::write(path, raw).map_err(PackerError::Io)
+    }
+
+    /// Records `record` for `cloud`, overwriting any previous record for
+    /// it — call after each cloud's build attempt completes.
+    pub fn record(&mut self, cloud: impl Into<String>, record: BuildRecord) {
+        self.clouds.insert(cloud.into(), record);
+    }
+
+    /// Which of `requested_clouds` still need building: all of them if
+    /// `current_config_hash` doesn't match what this state was built
+    /// against (a changed config invalidates every prior result), or just
+    /// the ones that aren't already a recorded [`BuildOutcome::Success`]
+    /// otherwise.
+    pub fn clouds_to_build<'a>(
+        &self,
+        requested_clouds: &'a [String],
+        current_config_hash: &str,
+    ) -> Vec<&'a str> {
+        if self.config_hash != current_config_hash {
+            return requested_clouds.iter().map(String::as_str).collect();
+        }
+        requested_clouds
+            .iter()
+            .map(String::as_str)
+            .filter(|cloud| {
+                !matches!(
+                    self.clouds.get(*cloud),
+                    Some(BuildRecord { outcome: BuildOutcome::Success, .. })
+                )
+            })
+            .collect()
+    }
+}
+
+/// Minimal hex-encoding helper so we don't pull in a whole `hex` crate for
+/// one call site.
+mod hex {
+    pub fn encode(bytes: impl AsRef<[u8]>) -> String {
+        bytes.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_hash_changes_with_content() {
+        let a = BuildState::config_hash(b"clouds: [aws]");
+        let b = BuildState::config_hash(b"clouds: [aws, gcp]");
+        assert_ne!(a, b);
+        assert_eq!(a, BuildState::config_hash(b"clouds: [aws]"));
+    }
+
+    #[test]
+    fn a_changed_config_hash_invalidates_every_prior_result() {
+        let mut state = BuildState { config_hash: "old".to_string(), clouds: BTreeMap::new() };
+        state.record("aws", BuildRecord { outcome: BuildOutcome::Success, image_id: Some("ami-1".to_string()) });
+
+        let requested = vec!["aws".to_string(), "gcp".to_string()];
+        let to_build = state.clouds_to_build(&requested, "new");
+        assert_eq!(to_build, vec!["aws", "gcp"]);
+    }
+
+    #[test]
+    fn only_failed_or_pending_clouds_need_rebuilding() {
+        let mut state = BuildState { config_hash: "abc".to_string(), clouds: BTreeMap::new() };
+        state.record("aws", BuildRecord { outcome: BuildOutcome::Success, image_id: Some("ami-1".to_string()) });
+        state.record("gcp", BuildRecord { outcome: BuildOutcome::Failed, image_id: None });
+
+        let requested = vec!["aws".to_string(), "gcp".to_string(), "azure".to_string()];
+        let to_build = state.clouds_to_build(&requested, "abc");
+        assert_eq!(to_build, vec!["gcp", "azure"]);
+    }
+
+    #[test]
+    fn round_trips_through_save_and_load() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("build-state.json");
+
+        let mut state = BuildState { config_hash: "abc".to_string(), clouds: BTreeMap::new() };
+        state.record("aws", BuildRecord { outcome: BuildOutcome::Success, image_id: Some("ami-1".to_string()) });
+        state.save(&path).unwrap();
+
+        let loaded = BuildState::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.config_hash, "abc");
+        assert_eq!(loaded.clouds["aws"].outcome, BuildOutcome::Success);
+    }
+
+    #[test]
+    fn load_returns_none_when_missing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("does-not-exist.json");
+        assert!(BuildState::load(&path).unwrap().is_none());
+    }
+}
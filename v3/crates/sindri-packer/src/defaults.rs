@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::PackerError;
+
+/// A shared `defaults` block plus per-cloud overrides, merged per-cloud by
+/// [`CloudOverrides::effective`].
+///
+/// There's no `PackerConfig`/`create_aws_context` in this crate yet — only
+/// image lifecycle management (`list_images`/`delete_image`) exists, not
+/// build configuration loading — so this is the merge primitive a future
+/// multi-cloud config loader can build on top of, not a full config type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CloudOverrides {
+    /// Fields shared by every cloud unless overridden.
+    #[serde(default)]
+    pub defaults: BTreeMap<String, Value>,
+
+    /// Per-cloud overrides, keyed by cloud name (e.g. `"aws"`, `"gcp"`).
+    #[serde(default)]
+    pub clouds: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+impl CloudOverrides {
+    /// The effective field set for `cloud`: every default, with that
+    /// cloud's overrides applied on top. Fails if an override changes a
+    /// default field's JSON type (a string default overridden with a
+    /// number, etc.), since that's almost always a config mistake rather
+    /// than an intentional shape change.
+    pub fn effective(&self, cloud: &str) -> Result<BTreeMap<String, Value>, PackerError> {
+        let mut merged = self.defaults.clone();
+        let Some(overrides) = self.clouds.get(cloud) else {
+            return Ok(merged);
+        };
+
+        for (field, value) in overrides {
+            if let Some(default) = merged.get(field) {
+                if !same_json_type(default, value) {
+                    return Err(PackerError::IncompatibleOverride {
+                        cloud: cloud.to_string(),
+                        field: field.clone(),
+                    });
+                }
+            }
+            merged.insert(field.clone(), value.clone());
+        }
+        Ok(merged)
+    }
+}
+
+/// Whether `a` and `b` are the same JSON value variant, ignoring content.
+fn same_json_type(a: &Value, b: &Value) -> bool {
+    matches!(
+        (a, b),
+        (Value::Null, Value::Null)
+            | (Value::Bool(_), Value::Bool(_))
+            | (Value::Number(_), Value::Number(_))
+            | (Value::String(_), Value::String(_))
+            | (Value::Array(_), Value::Array(_))
+            | (Value::Object(_), Value::Object(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn overrides() -> CloudOverrides {
+        let mut defaults = BTreeMap::new();
+        defaults.insert("security".to_string(), json!("encrypted"));
+        defaults.insert("tags".to_string(), json!(["sindri"]));
+
+        let mut aws = BTreeMap::new();
+        aws.insert("security".to_string(), json!("encrypted-kms"));
+
+        let mut clouds = BTreeMap::new();
+        clouds.insert("aws".to_string(), aws);
+
+        CloudOverrides { defaults, clouds }
+    }
+
+    #[test]
+    fn a_cloud_with_no_overrides_gets_the_defaults_verbatim() {
+        let effective = overrides().effective("gcp").unwrap();
+        assert_eq!(effective.get("security"), Some(&json!("encrypted")));
+    }
+
+    #[test]
+    fn a_cloud_override_replaces_the_matching_default_field() {
+        let effective = overrides().effective("aws").unwrap();
+        assert_eq!(effective.get("security"), Some(&json!("encrypted-kms")));
+        assert_eq!(effective.get("tags"), Some(&json!(["sindri"])));
+    }
+
+    #[test]
+    fn an_override_changing_a_default_fields_type_is_rejected() {
+        let mut config = overrides();
+        config.clouds.get_mut("aws").unwrap().insert("security".to_string(), json!(true));
+        let err = config.effective("aws").unwrap_err();
+        assert!(matches!(err, PackerError::IncompatibleOverride { .. }));
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PackerError {
+    #[error("packer is not installed or not on PATH")]
+    PackerMissing,
+
+    #[error("cloud CLI for {0} is not installed or not authenticated")]
+    CloudCliMissing(String),
+
+    #[error("prerequisite check for {0} panicked")]
+    CheckPanicked(String),
+
+    #[error("image {0} is not tagged ManagedBy=sindri; pass --force to delete it anyway")]
+    NotManagedBySindri(String),
+
+    #[error("no image backend is configured for {0} yet")]
+    NoImageBackend(sindri_providers::CloudProvider),
+
+    #[error("{0} does not expose SSH connection details for launched instances yet")]
+    NoConnectTarget(sindri_providers::CloudProvider),
+
+    #[error("cloud {cloud:?} overrides field {field:?} with an incompatible value")]
+    IncompatibleOverride { cloud: String, field: String },
+
+    #[error("invalid build state: {0}")]
+    InvalidState(#[source] serde_json::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use sindri_providers::CloudProvider;
+
+use crate::PackerError;
+
+/// Tag every image Sindri builds via Packer carries, so [`PackerProvider::delete_image`]
+/// can refuse to remove images it doesn't own unless forced.
+pub const MANAGED_BY_TAG: (&str, &str) = ("ManagedBy", "sindri");
+
+/// A Packer-built image (AMI, disk image, ...) as reported by
+/// [`PackerProvider::list_images`].
+#[derive(Debug, Clone)]
+pub struct ImageInfo {
+    pub id: String,
+    pub name: String,
+    pub created_at: SystemTime,
+    pub size_bytes: u64,
+    pub tags: BTreeMap<String, String>,
+}
+
+impl ImageInfo {
+    /// Whether this image carries [`MANAGED_BY_TAG`], i.e. Sindri built it.
+    pub fn is_managed_by_sindri(&self) -> bool {
+        self.tags.get(MANAGED_BY_TAG.0).map(String::as_str) == Some(MANAGED_BY_TAG.1)
+    }
+}
+
+/// An image removed by [`PackerProvider::delete_image`], reported back so
+/// callers can total up freed storage.
+#[derive(Debug, Clone)]
+pub struct DeletedImage {
+    pub id: String,
+    pub freed_bytes: u64,
+}
+
+/// SSH connection details for an instance launched from a Packer-built
+/// image, as reported by [`PackerProvider::connect_target`].
+#[derive(Debug, Clone)]
+pub struct SshTarget {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+}
+
+/// The HCL2 Packer template a backend would feed `packer build`, as
+/// reported by [`PackerProvider::render_template`] for dry-run review.
+#[derive(Debug, Clone)]
+pub struct RenderedTemplate {
+    pub template_name: String,
+    pub hcl2: String,
+}
+
+/// A provisioning script a rendered template invokes, as reported by
+/// [`PackerProvider::render_scripts`] alongside its template.
+#[derive(Debug, Clone)]
+pub struct RenderedScript {
+    pub name: String,
+    pub contents: String,
+}
+
+/// A cloud backend that Packer builds images for and Sindri can clean up
+/// after. Mirrors [`sindri_providers::Provider`]'s per-cloud-adapter shape,
+/// but for the image lifecycle (list/delete) rather than deployment.
+#[async_trait]
+pub trait PackerProvider: Send + Sync {
+    fn cloud(&self) -> CloudProvider;
+
+    /// Lists images this backend knows about, most-recently-created first.
+    async fn list_images(&self) -> Result<Vec<ImageInfo>, PackerError>;
+
+    /// Deletes `image_id` and its backing snapshots/disks. Refuses with
+    /// [`PackerError::NotManagedBySindri`] when the image isn't tagged with
+    /// [`MANAGED_BY_TAG`], unless `force` is set — an untagged image might
+    /// be in active use outside Sindri.
+    async fn delete_image(&self, image_id: &str, force: bool) -> Result<DeletedImage, PackerError>;
+
+    /// SSH connection details for `instance_id`, a running instance
+    /// launched from an image this backend built. Mirrors
+    /// [`sindri_providers::Provider`]'s default-rejecting extension
+    /// methods (`open_tunnel`, `scale`, ...); no backend implements this
+    /// yet since there's no deploy-from-image path in this crate, only
+    /// the image lifecycle (`list_images`/`delete_image`) — a backend
+    /// that gains one should override this with real connection details.
+    async fn connect_target(&self, instance_id: &str) -> Result<SshTarget, PackerError> {
+        let _ = instance_id;
+        Err(PackerError::NoConnectTarget(self.cloud()))
+    }
+
+    /// Renders the HCL2 template this backend would feed `packer build`,
+    /// without building anything. Backs `sindri vm render`'s dry-run
+    /// review of generated config. No backend implements this yet for the
+    /// same reason none implements [`Self::connect_target`]: no cloud has
+    /// an image backend wired up, so there's no template to render.
+    async fn render_template(&self) -> Result<RenderedTemplate, PackerError> {
+        Err(PackerError::NoImageBackend(self.cloud()))
+    }
+
+    /// Renders the provisioning scripts this backend's template invokes,
+    /// alongside [`Self::render_template`]'s HCL2.
+    async fn render_scripts(&self) -> Result<Vec<RenderedScript>, PackerError> {
+        Err(PackerError::NoImageBackend(self.cloud()))
+    }
+}
+
+/// Resolves the concrete [`PackerProvider`] for `cloud`. No cloud has a
+/// backend wired up yet — this is the registration point for when one
+/// lands, mirroring how [`sindri_providers::Provider`] adapters are added
+/// per cloud as they're implemented.
+pub fn provider_for(cloud: CloudProvider) -> Result<Box<dyn PackerProvider>, PackerError> {
+    Err(PackerError::NoImageBackend(cloud))
+}
+
+/// Deletes every image `provider` lists that was created before `cutoff`,
+/// stopping at the first failure. Backs `sindri vm delete --older-than`,
+/// which enforces a retention window in bulk rather than one id at a time.
+pub async fn delete_older_than(
+    provider: &dyn PackerProvider,
+    cutoff: SystemTime,
+    force: bool,
+) -> Result<Vec<DeletedImage>, PackerError> {
+    let images = provider.list_images().await?;
+    let mut deleted = Vec::new();
+    for image in images.into_iter().filter(|image| image.created_at < cutoff) {
+        deleted.push(provider.delete_image(&image.id, force).await?);
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(tags: &[(&str, &str)]) -> ImageInfo {
+        ImageInfo {
+            id: "img-1".to_string(),
+            name: "sindri-base".to_string(),
+            created_at: SystemTime::UNIX_EPOCH,
+            size_bytes: 0,
+            tags: tags.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn recognizes_images_tagged_managed_by_sindri() {
+        assert!(image(&[("ManagedBy", "sindri")]).is_managed_by_sindri());
+    }
+
+    #[test]
+    fn does_not_recognize_untagged_or_differently_tagged_images() {
+        assert!(!image(&[]).is_managed_by_sindri());
+        assert!(!image(&[("ManagedBy", "someone-else")]).is_managed_by_sindri());
+    }
+}
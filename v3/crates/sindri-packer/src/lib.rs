@@ -0,0 +1,16 @@
+//! Image packing and distribution via Packer.
+
+mod build_state;
+mod defaults;
+mod error;
+mod image;
+mod prerequisites;
+
+pub use build_state::{BuildOutcome, BuildRecord, BuildState};
+pub use defaults::CloudOverrides;
+pub use error::PackerError;
+pub use image::{
+    delete_older_than, provider_for, DeletedImage, ImageInfo, PackerProvider, RenderedScript,
+    RenderedTemplate, SshTarget, MANAGED_BY_TAG,
+};
+pub use prerequisites::{check_all_prerequisites, check_cloud_prerequisites};
@@ -0,0 +1,82 @@
+use sindri_providers::CloudProvider;
+
+use crate::PackerError;
+
+/// Checks that `packer` and the given cloud's CLI are installed and usable.
+/// Shells out (e.g. `packer version`, `aws sts get-caller-identity`), so
+/// this is deliberately `async` even though the work is mostly blocking I/O.
+pub async fn check_cloud_prerequisites(cloud: CloudProvider) -> Result<(), PackerError> {
+    check_packer_installed().await?;
+    check_cloud_cli(cloud).await
+}
+
+async fn check_packer_installed() -> Result<(), PackerError> {
+    let status = tokio::process::Command::new("packer")
+        .arg("version")
+        .output()
+        .await;
+    match status {
+        Ok(output) if output.status.success() => Ok(()),
+        _ => Err(PackerError::PackerMissing),
+    }
+}
+
+async fn check_cloud_cli(cloud: CloudProvider) -> Result<(), PackerError> {
+    let binary = match cloud {
+        CloudProvider::Docker => "docker",
+        CloudProvider::Fly => "flyctl",
+        CloudProvider::DevPod => "devpod",
+        CloudProvider::K3d => "k3d",
+        CloudProvider::Northflank => "northflank",
+        CloudProvider::Packer => "packer",
+        CloudProvider::Runpod => "runpodctl",
+        CloudProvider::E2b => "e2b",
+    };
+    let status = tokio::process::Command::new(binary)
+        .arg("--version")
+        .output()
+        .await;
+    match status {
+        Ok(output) if output.status.success() => Ok(()),
+        _ => Err(PackerError::CloudCliMissing(cloud.to_string())),
+    }
+}
+
+/// Checks prerequisites for every `cloud` concurrently. A panic or hang in
+/// one cloud's check can't take down the others: each check runs in its own
+/// task, and a panicking task is reported as an error rather than
+/// propagating. Results preserve the order of `clouds`.
+pub async fn check_all_prerequisites(
+    clouds: &[CloudProvider],
+) -> Vec<(CloudProvider, Result<(), PackerError>)> {
+    let handles: Vec<_> = clouds
+        .iter()
+        .map(|&cloud| tokio::spawn(async move { (cloud, check_cloud_prerequisites(cloud).await) }))
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        match handle.await {
+            Ok((cloud, result)) => results.push((cloud, result)),
+            Err(join_err) => {
+                let cloud = clouds[results.len()];
+                tracing::error!(%cloud, %join_err, "prerequisite check task panicked");
+                results.push((cloud, Err(PackerError::CheckPanicked(cloud.to_string()))));
+            }
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn preserves_order_even_when_every_check_fails() {
+        let clouds = [CloudProvider::Runpod, CloudProvider::Docker, CloudProvider::Fly];
+        let results = check_all_prerequisites(&clouds).await;
+        let order: Vec<CloudProvider> = results.iter().map(|(cloud, _)| *cloud).collect();
+        assert_eq!(order, clouds.to_vec());
+    }
+}
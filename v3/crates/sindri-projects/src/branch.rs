@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use crate::git::run_git;
+use crate::ProjectError;
+
+/// A local branch and how it relates to its upstream, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchInfo {
+    pub name: String,
+    /// `<remote>/<branch>` this branch tracks, if it has one.
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    /// The tracked upstream ref once existed but has since been deleted.
+    pub upstream_gone: bool,
+}
+
+/// Creates a new local branch at the current `HEAD`.
+pub fn create_branch(repo: &Path, name: &str) -> Result<(), ProjectError> {
+    run_git(repo, &["branch", name])?;
+    Ok(())
+}
+
+/// Switches `repo`'s working tree to `name`.
+pub fn checkout_branch(repo: &Path, name: &str) -> Result<(), ProjectError> {
+    run_git(repo, &["checkout", name])?;
+    Ok(())
+}
+
+/// Whether a local branch named `name` exists.
+pub fn branch_exists(repo: &Path, name: &str) -> bool {
+    run_git(repo, &["show-ref", "--verify", "--quiet", &format!("refs/heads/{name}")]).is_ok()
+}
+
+/// Lists every local branch in `repo` with its upstream and ahead/behind
+/// counts. Detached `HEAD` isn't a branch and so never appears; a branch
+/// with no upstream reports `upstream: None` with zero ahead/behind rather
+/// than erroring, and a branch whose upstream was deleted reports
+/// `upstream_gone: true` instead.
+pub fn list_branches(repo: &Path) -> Result<Vec<BranchInfo>, ProjectError> {
+    let output = run_git(
+        repo,
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)%09%(upstream:short)%09%(upstream:track)",
+            "refs/heads",
+        ],
+    )?;
+
+    Ok(output.lines().filter(|line| !line.is_empty()).map(parse_branch_line).collect())
+}
+
+fn parse_branch_line(line: &str) -> BranchInfo {
+    let mut fields = line.split('\t');
+    let name = fields.next().unwrap_or_default().to_string();
+    let upstream = fields.next().filter(|value| !value.is_empty()).map(str::to_string);
+    let track = fields.next().unwrap_or_default();
+
+    let upstream_gone = track.contains("gone");
+    let ahead = parse_track_count(track, "ahead");
+    let behind = parse_track_count(track, "behind");
+
+    BranchInfo {
+        name,
+        upstream,
+        ahead,
+        behind,
+        upstream_gone,
+    }
+}
+
+/// Extracts the number following `label` out of a `%(upstream:track)` value
+/// like `[ahead 2, behind 1]`.
+fn parse_track_count(track: &str, label: &str) -> u32 {
+    track
+        .split(label)
+        .nth(1)
+        .and_then(|rest| rest.trim_start_matches(' ').split(|c: char| !c.is_ascii_digit()).next())
+        .and_then(|digits| digits.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{init_repo, RepositoryOptions};
+    use std::process::Command;
+
+    fn git_available() -> bool {
+        Command::new("git").arg("--version").output().is_ok()
+    }
+
+    fn init_with_commit(path: &Path) {
+        init_repo(path, &RepositoryOptions::default()).unwrap();
+        run_git(path, &["config", "user.name", "Test"]).unwrap();
+        run_git(path, &["config", "user.email", "test@example.com"]).unwrap();
+        std::fs::write(path.join("README.md"), b"hello").unwrap();
+        run_git(path, &["add", "README.md"]).unwrap();
+        run_git(path, &["commit", "-m", "initial"]).unwrap();
+    }
+
+    #[test]
+    fn create_and_check_existence() {
+        if !git_available() {
+            return;
+        }
+        let tmp = tempfile::tempdir().unwrap();
+        init_with_commit(tmp.path());
+
+        assert!(!branch_exists(tmp.path(), "feature"));
+        create_branch(tmp.path(), "feature").unwrap();
+        assert!(branch_exists(tmp.path(), "feature"));
+    }
+
+    #[test]
+    fn lists_branch_with_no_upstream() {
+        if !git_available() {
+            return;
+        }
+        let tmp = tempfile::tempdir().unwrap();
+        init_with_commit(tmp.path());
+        create_branch(tmp.path(), "feature").unwrap();
+
+        let branches = list_branches(tmp.path()).unwrap();
+        let feature = branches.iter().find(|b| b.name == "feature").unwrap();
+        assert_eq!(feature.upstream, None);
+        assert_eq!(feature.ahead, 0);
+        assert_eq!(feature.behind, 0);
+        assert!(!feature.upstream_gone);
+    }
+
+    #[test]
+    fn parses_ahead_and_behind_counts() {
+        let info = parse_branch_line("main\torigin/main\t[ahead 2, behind 1]");
+        assert_eq!(info.upstream, Some("origin/main".to_string()));
+        assert_eq!(info.ahead, 2);
+        assert_eq!(info.behind, 1);
+        assert!(!info.upstream_gone);
+    }
+
+    #[test]
+    fn parses_gone_upstream() {
+        let info = parse_branch_line("main\torigin/main\t[gone]");
+        assert!(info.upstream_gone);
+        assert_eq!(info.ahead, 0);
+    }
+}
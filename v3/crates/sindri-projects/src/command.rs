@@ -0,0 +1,113 @@
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::ProjectError;
+
+/// How often [`run_shell_command`] polls a running child for exit/timeout.
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Runs `program` with `args` in `cwd`, streaming its stdout/stderr through
+/// `tracing` as it's produced, and killing it if it hasn't exited within
+/// `timeout`.
+pub fn run_shell_command(
+    cwd: &Path,
+    program: &str,
+    args: &[&str],
+    timeout: Duration,
+) -> Result<(), ProjectError> {
+    let mut child = Command::new(program)
+        .args(args)
+        .current_dir(cwd)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = child.stdout.take().expect("piped stdout");
+    let stderr = child.stderr.take().expect("piped stderr");
+    let out_thread = spawn_stream_logger(program.to_string(), stdout, false);
+    let err_thread = spawn_stream_logger(program.to_string(), stderr, true);
+
+    let status = wait_with_timeout(&mut child, timeout, program)?;
+
+    let _ = out_thread.join();
+    let _ = err_thread.join();
+
+    if !status.success() {
+        return Err(ProjectError::CommandFailed {
+            program: program.to_string(),
+            args: args.join(" "),
+            status: status.code(),
+        });
+    }
+    Ok(())
+}
+
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Duration,
+    program: &str,
+) -> Result<std::process::ExitStatus, ProjectError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(ProjectError::CommandTimedOut {
+                program: program.to_string(),
+                timeout,
+            });
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn spawn_stream_logger(
+    program: String,
+    stream: impl Read + Send + 'static,
+    is_stderr: bool,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(stream).lines().map_while(Result::ok) {
+            if is_stderr {
+                tracing::warn!(command = %program, "{line}");
+            } else {
+                tracing::info!(command = %program, "{line}");
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_and_reaps_a_command_that_outlives_its_timeout() {
+        let tmp = tempfile::tempdir().unwrap();
+        let started = Instant::now();
+
+        let err = run_shell_command(tmp.path(), "sleep", &["5"], Duration::from_millis(200)).unwrap_err();
+
+        assert!(matches!(err, ProjectError::CommandTimedOut { .. }));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn streams_output_and_succeeds_within_timeout() {
+        let tmp = tempfile::tempdir().unwrap();
+        run_shell_command(tmp.path(), "echo", &["hello"], Duration::from_secs(5)).unwrap();
+    }
+
+    #[test]
+    fn surfaces_a_clear_error_on_nonzero_exit() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = run_shell_command(tmp.path(), "false", &[], Duration::from_secs(5)).unwrap_err();
+        assert!(matches!(err, ProjectError::CommandFailed { .. }));
+    }
+}
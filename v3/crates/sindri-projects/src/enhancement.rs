@@ -0,0 +1,281 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use sindri_extensions::ExtensionDistributor;
+
+use crate::command::run_shell_command;
+use crate::ProjectError;
+
+/// Default ceiling for a single dependency-install command, chosen to be
+/// generous enough for a cold `npm install` without hanging forever on a
+/// stuck network.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Options honored by [`EnhancementManager::enhance`].
+#[derive(Debug, Clone)]
+pub struct EnhancementOptions {
+    /// Only fetch dependencies instead of running a full build (e.g. `cargo
+    /// fetch` instead of `cargo build`, `npm install --ignore-scripts`
+    /// instead of `npm install`).
+    pub skip_build: bool,
+    /// How long to let the dependency-install command run before it's
+    /// killed.
+    pub timeout: Duration,
+}
+
+impl Default for EnhancementOptions {
+    fn default() -> Self {
+        Self {
+            skip_build: false,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// The kind of project found at a directory, as inferred from the files at
+/// its root. Drives which extension(s) [`EnhancementManager`] tries to
+/// activate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectType {
+    Rust,
+    Node,
+    Python,
+    Go,
+    Unknown,
+}
+
+impl ProjectType {
+    fn extension_name(self) -> Option<&'static str> {
+        match self {
+            ProjectType::Rust => Some("rust"),
+            ProjectType::Node => Some("node"),
+            ProjectType::Python => Some("python"),
+            ProjectType::Go => Some("go"),
+            ProjectType::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for ProjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ProjectType::Rust => "rust",
+            ProjectType::Node => "node",
+            ProjectType::Python => "python",
+            ProjectType::Go => "go",
+            ProjectType::Unknown => "unknown",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Inspects the files at the root of `dir` to guess what kind of project it
+/// is. Unrecognized layouts report [`ProjectType::Unknown`] rather than
+/// erroring.
+pub fn detect_project_type(dir: &Path) -> ProjectType {
+    if dir.join("Cargo.toml").is_file() {
+        ProjectType::Rust
+    } else if dir.join("package.json").is_file() {
+        ProjectType::Node
+    } else if dir.join("pyproject.toml").is_file() || dir.join("requirements.txt").is_file() {
+        ProjectType::Python
+    } else if dir.join("go.mod").is_file() {
+        ProjectType::Go
+    } else {
+        ProjectType::Unknown
+    }
+}
+
+/// Outcome of [`EnhancementManager::enhance`].
+#[derive(Debug, Clone)]
+pub struct EnhancementReport {
+    pub project_type: ProjectType,
+    pub claude_md_created: bool,
+    pub activated_extensions: Vec<String>,
+    pub dependencies_installed: bool,
+}
+
+/// Turns a plain checkout into a Claude-ready project: detects its type,
+/// creates a starter `CLAUDE.md` if one isn't already there, and activates
+/// whichever installed extension matches the detected type.
+pub struct EnhancementManager<'a> {
+    distributor: &'a ExtensionDistributor,
+}
+
+impl<'a> EnhancementManager<'a> {
+    pub fn new(distributor: &'a ExtensionDistributor) -> Self {
+        Self { distributor }
+    }
+
+    pub fn enhance(
+        &self,
+        project_dir: &Path,
+        options: &EnhancementOptions,
+    ) -> Result<EnhancementReport, ProjectError> {
+        let project_type = detect_project_type(project_dir);
+        let claude_md_created = self.ensure_claude_md(project_dir, project_type)?;
+        let dependencies_installed = self.install_dependencies(project_dir, project_type, options)?;
+
+        let mut activated_extensions = Vec::new();
+        if let Some(name) = project_type.extension_name() {
+            if self.distributor.current_version(name).is_some() {
+                activated_extensions.push(name.to_string());
+            }
+        }
+
+        Ok(EnhancementReport {
+            project_type,
+            claude_md_created,
+            activated_extensions,
+            dependencies_installed,
+        })
+    }
+
+    /// Fetches (or, unless `options.skip_build`, fully builds) the detected
+    /// project's dependencies, subject to `options.timeout`. Projects with
+    /// no recognized dependency manager are left untouched.
+    fn install_dependencies(
+        &self,
+        project_dir: &Path,
+        project_type: ProjectType,
+        options: &EnhancementOptions,
+    ) -> Result<bool, ProjectError> {
+        let Some((program, full_args, fetch_args)) = dependency_command(project_dir, project_type) else {
+            return Ok(false);
+        };
+        let args = if options.skip_build { &fetch_args } else { &full_args };
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        run_shell_command(project_dir, program, &args, options.timeout)?;
+        Ok(true)
+    }
+
+    /// Writes a starter `CLAUDE.md`. Never overwrites one that's already
+    /// there — enhancement should never clobber a project's existing
+    /// instructions.
+    fn ensure_claude_md(&self, project_dir: &Path, project_type: ProjectType) -> Result<bool, ProjectError> {
+        let path = project_dir.join("CLAUDE.md");
+        if path.is_file() {
+            return Ok(false);
+        }
+
+        std::fs::write(&path, claude_md_template(project_type))?;
+        Ok(true)
+    }
+}
+
+/// Resolves the dependency-install command for `project_type`, returning
+/// `(program, full_build_args, fetch_only_args)`. `None` for project types
+/// with no recognized dependency manager.
+fn dependency_command(
+    project_dir: &Path,
+    project_type: ProjectType,
+) -> Option<(&'static str, Vec<String>, Vec<String>)> {
+    let strs = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect();
+    match project_type {
+        ProjectType::Rust => Some(("cargo", strs(&["build"]), strs(&["fetch"]))),
+        ProjectType::Node => Some(("npm", strs(&["install"]), strs(&["install", "--ignore-scripts"]))),
+        ProjectType::Python if project_dir.join("requirements.txt").is_file() => Some((
+            "pip",
+            strs(&["install", "-r", "requirements.txt"]),
+            strs(&["install", "--no-deps", "-r", "requirements.txt"]),
+        )),
+        ProjectType::Python => Some(("pip", strs(&["install", "."]), strs(&["install", "--no-deps", "."]))),
+        ProjectType::Go => Some(("go", strs(&["build", "./..."]), strs(&["mod", "download"]))),
+        ProjectType::Unknown => None,
+    }
+}
+
+fn claude_md_template(project_type: ProjectType) -> String {
+    format!(
+        "# Project Configuration\n\nDetected project type: {project_type}\n\n\
+         Add project-specific instructions for Claude here.\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_rust_project() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), "[package]").unwrap();
+        assert_eq!(detect_project_type(tmp.path()), ProjectType::Rust);
+    }
+
+    #[test]
+    fn unrecognized_layout_is_unknown() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(detect_project_type(tmp.path()), ProjectType::Unknown);
+    }
+
+    #[test]
+    fn creates_claude_md_when_absent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let extensions = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(extensions.path());
+        let manager = EnhancementManager::new(&distributor);
+
+        let report = manager.enhance(tmp.path(), &EnhancementOptions::default()).unwrap();
+        assert!(report.claude_md_created);
+        assert!(tmp.path().join("CLAUDE.md").is_file());
+    }
+
+    #[test]
+    fn does_not_overwrite_existing_claude_md() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("CLAUDE.md"), "custom instructions").unwrap();
+        let extensions = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(extensions.path());
+        let manager = EnhancementManager::new(&distributor);
+
+        let report = manager.enhance(tmp.path(), &EnhancementOptions::default()).unwrap();
+        assert!(!report.claude_md_created);
+        assert_eq!(
+            std::fs::read_to_string(tmp.path().join("CLAUDE.md")).unwrap(),
+            "custom instructions"
+        );
+    }
+
+    #[test]
+    fn activates_extension_matching_detected_type() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_minimal_crate(tmp.path());
+
+        let extensions = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(extensions.path());
+        distributor.install("rust", "1.0.0", |dest| std::fs::create_dir_all(dest)).unwrap();
+
+        let manager = EnhancementManager::new(&distributor);
+        let options = EnhancementOptions {
+            skip_build: true,
+            ..EnhancementOptions::default()
+        };
+        let report = manager.enhance(tmp.path(), &options).unwrap();
+        assert_eq!(report.activated_extensions, vec!["rust".to_string()]);
+        assert!(report.dependencies_installed);
+    }
+
+    #[test]
+    fn unknown_project_type_skips_dependency_install() {
+        let tmp = tempfile::tempdir().unwrap();
+        let extensions = tempfile::tempdir().unwrap();
+        let distributor = ExtensionDistributor::new(extensions.path());
+        let manager = EnhancementManager::new(&distributor);
+
+        let report = manager.enhance(tmp.path(), &EnhancementOptions::default()).unwrap();
+        assert!(!report.dependencies_installed);
+    }
+
+    fn write_minimal_crate(dir: &Path) {
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"temp-enhance-test\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+    }
+}
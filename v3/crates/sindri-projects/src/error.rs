@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors raised while scaffolding or managing a project's git repository.
+#[derive(Debug, Error)]
+pub enum ProjectError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("git {args} failed in {path}: {stderr}")]
+    Git {
+        path: PathBuf,
+        args: String,
+        stderr: String,
+    },
+
+    #[error("commit signing was requested but no signing options were configured")]
+    SigningNotConfigured,
+
+    #[error("signing key {key} is not usable: {reason}")]
+    SigningKeyUnusable { key: String, reason: String },
+
+    #[error("`{program} {args}` exited with status {status:?}")]
+    CommandFailed {
+        program: String,
+        args: String,
+        status: Option<i32>,
+    },
+
+    #[error("`{program}` did not finish within {timeout:?} and was killed")]
+    CommandTimedOut {
+        program: String,
+        timeout: std::time::Duration,
+    },
+}
@@ -0,0 +1,206 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::ProjectError;
+
+/// Committer identity applied to a repository via `git config`.
+#[derive(Debug, Clone)]
+pub struct GitIdentity {
+    pub name: String,
+    pub email: String,
+}
+
+/// How a signing key should be presented to git. Mirrors `gpg.format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningFormat {
+    Gpg,
+    Ssh,
+}
+
+impl SigningFormat {
+    fn as_git_value(self) -> &'static str {
+        match self {
+            SigningFormat::Gpg => "openpgp",
+            SigningFormat::Ssh => "ssh",
+        }
+    }
+}
+
+/// Commit/tag signing configuration for a repository. `key` is a GPG key id
+/// for [`SigningFormat::Gpg`], or a path to a public/private key file for
+/// [`SigningFormat::Ssh`].
+#[derive(Debug, Clone)]
+pub struct SigningOptions {
+    pub key: String,
+    pub format: SigningFormat,
+}
+
+/// Options honored by [`init_repo`] and [`clone_repo`] when scaffolding a
+/// project's repository.
+#[derive(Debug, Clone, Default)]
+pub struct RepositoryOptions {
+    pub identity: Option<GitIdentity>,
+    pub sign_commits: bool,
+    pub signing: Option<SigningOptions>,
+}
+
+/// Initializes a new git repository at `path` and applies `options`.
+pub fn init_repo(path: &Path, options: &RepositoryOptions) -> Result<(), ProjectError> {
+    std::fs::create_dir_all(path)?;
+    run_git(path, &["init"])?;
+    apply_repository_options(path, options)
+}
+
+/// Clones `url` into `dest` and applies `options` to the resulting
+/// repository.
+pub fn clone_repo(url: &str, dest: &Path, options: &RepositoryOptions) -> Result<(), ProjectError> {
+    let parent = dest.parent().unwrap_or_else(|| Path::new("."));
+    run_git(parent, &["clone", url, &dest.display().to_string()])?;
+    apply_repository_options(dest, options)
+}
+
+fn apply_repository_options(repo: &Path, options: &RepositoryOptions) -> Result<(), ProjectError> {
+    if let Some(identity) = &options.identity {
+        configure_user(repo, identity)?;
+    }
+
+    if !options.sign_commits {
+        return Ok(());
+    }
+
+    let signing = options.signing.as_ref().ok_or(ProjectError::SigningNotConfigured)?;
+    verify_signing_key(signing)?;
+    configure_signing(repo, signing)
+}
+
+/// Sets `user.name`/`user.email` on `repo`.
+pub fn configure_user(repo: &Path, identity: &GitIdentity) -> Result<(), ProjectError> {
+    run_git(repo, &["config", "user.name", &identity.name])?;
+    run_git(repo, &["config", "user.email", &identity.email])?;
+    Ok(())
+}
+
+/// Enables commit and tag signing on `repo` with `options`. Callers should
+/// call [`verify_signing_key`] first — this function trusts the key is
+/// usable and only wires up git's configuration.
+pub fn configure_signing(repo: &Path, options: &SigningOptions) -> Result<(), ProjectError> {
+    run_git(repo, &["config", "gpg.format", options.format.as_git_value()])?;
+    run_git(repo, &["config", "user.signingkey", &options.key])?;
+    run_git(repo, &["config", "commit.gpgsign", "true"])?;
+    run_git(repo, &["config", "tag.gpgsign", "true"])?;
+    Ok(())
+}
+
+/// Checks that `options.key` can actually be used to sign, failing clearly
+/// rather than letting an unsigned commit through that the remote will
+/// reject. GPG keys are checked against the local secret keyring; SSH keys
+/// are checked by reading the key file and confirming it parses.
+pub fn verify_signing_key(options: &SigningOptions) -> Result<(), ProjectError> {
+    match options.format {
+        SigningFormat::Gpg => verify_gpg_key(&options.key),
+        SigningFormat::Ssh => verify_ssh_key(&options.key),
+    }
+}
+
+fn verify_gpg_key(key: &str) -> Result<(), ProjectError> {
+    let output = Command::new("gpg")
+        .args(["--list-secret-keys", "--with-colons", key])
+        .output()
+        .map_err(|source| unusable(key, format!("could not run gpg: {source}")))?;
+
+    if !output.status.success() || output.stdout.is_empty() {
+        return Err(unusable(key, "no matching secret key in the local keyring".to_string()));
+    }
+    Ok(())
+}
+
+fn verify_ssh_key(key: &str) -> Result<(), ProjectError> {
+    let path = Path::new(key);
+    if !path.is_file() {
+        return Err(unusable(key, "key file not found".to_string()));
+    }
+
+    let output = Command::new("ssh-keygen")
+        .args(["-y", "-f", key])
+        .output()
+        .map_err(|source| unusable(key, format!("could not run ssh-keygen: {source}")))?;
+
+    if !output.status.success() {
+        return Err(unusable(key, String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(())
+}
+
+fn unusable(key: &str, reason: String) -> ProjectError {
+    ProjectError::SigningKeyUnusable {
+        key: key.to_string(),
+        reason,
+    }
+}
+
+pub(crate) fn run_git(repo: &Path, args: &[&str]) -> Result<String, ProjectError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(ProjectError::Git {
+            path: repo.to_path_buf(),
+            args: args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git_available() -> bool {
+        Command::new("git").arg("--version").output().is_ok()
+    }
+
+    #[test]
+    fn init_repo_applies_identity() {
+        if !git_available() {
+            return;
+        }
+        let tmp = tempfile::tempdir().unwrap();
+        let options = RepositoryOptions {
+            identity: Some(GitIdentity {
+                name: "Sindri Bot".to_string(),
+                email: "sindri@example.com".to_string(),
+            }),
+            sign_commits: false,
+            signing: None,
+        };
+        init_repo(tmp.path(), &options).unwrap();
+
+        let name = run_git(tmp.path(), &["config", "user.name"]).unwrap();
+        assert_eq!(name, "Sindri Bot");
+    }
+
+    #[test]
+    fn init_repo_fails_fast_when_signing_requested_without_options() {
+        if !git_available() {
+            return;
+        }
+        let tmp = tempfile::tempdir().unwrap();
+        let options = RepositoryOptions {
+            identity: None,
+            sign_commits: true,
+            signing: None,
+        };
+        let err = init_repo(tmp.path(), &options).unwrap_err();
+        assert!(matches!(err, ProjectError::SigningNotConfigured));
+    }
+
+    #[test]
+    fn verify_ssh_key_fails_for_missing_file() {
+        let err = verify_ssh_key("/nonexistent/id_ed25519").unwrap_err();
+        assert!(matches!(err, ProjectError::SigningKeyUnusable { .. }));
+    }
+}
@@ -0,0 +1,18 @@
+//! Project scaffolding and management.
+
+mod branch;
+mod command;
+mod enhancement;
+mod error;
+mod git;
+
+pub use branch::{branch_exists, checkout_branch, create_branch, list_branches, BranchInfo};
+pub use command::run_shell_command;
+pub use enhancement::{
+    detect_project_type, EnhancementManager, EnhancementOptions, EnhancementReport, ProjectType,
+};
+pub use error::ProjectError;
+pub use git::{
+    clone_repo, configure_signing, configure_user, init_repo, verify_signing_key, GitIdentity,
+    RepositoryOptions, SigningFormat, SigningOptions,
+};
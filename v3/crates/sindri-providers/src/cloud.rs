@@ -0,0 +1,361 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sindri_core::TemplateContext;
+
+use crate::{PortForward, ProviderError, TunnelHandle};
+
+/// The set of deployment targets Sindri knows how to provision into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CloudProvider {
+    Docker,
+    Fly,
+    DevPod,
+    K3d,
+    Northflank,
+    Packer,
+    Runpod,
+    E2b,
+}
+
+impl std::fmt::Display for CloudProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CloudProvider::Docker => "docker",
+            CloudProvider::Fly => "fly",
+            CloudProvider::DevPod => "devpod",
+            CloudProvider::K3d => "k3d",
+            CloudProvider::Northflank => "northflank",
+            CloudProvider::Packer => "packer",
+            CloudProvider::Runpod => "runpod",
+            CloudProvider::E2b => "e2b",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::str::FromStr for CloudProvider {
+    type Err = String;
+
+    /// Parses the same spellings [`Display`](std::fmt::Display) prints,
+    /// e.g. from a CLI `--provider` flag or a `sindri.yaml` target's
+    /// `provider` field.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "docker" => Self::Docker,
+            "fly" => Self::Fly,
+            "devpod" => Self::DevPod,
+            "k3d" => Self::K3d,
+            "northflank" => Self::Northflank,
+            "packer" => Self::Packer,
+            "runpod" => Self::Runpod,
+            "e2b" => Self::E2b,
+            other => return Err(format!("unknown cloud provider {other:?}")),
+        })
+    }
+}
+
+/// Outcome of validating a single provider's prerequisites and credentials.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ValidationOutcome {
+    Ok,
+    Failed { reason: String },
+    TimedOut,
+}
+
+/// A cloud deployment target that Sindri can validate and deploy into.
+#[async_trait]
+pub trait Provider: Send + Sync {
+    fn kind(&self) -> CloudProvider;
+
+    /// Checks that the provider's CLI/credentials are present and usable.
+    async fn validate(&self) -> Result<(), ProviderError>;
+
+    /// Opens `forwards` through this provider's native tunneling mechanism
+    /// (SSH `-L`, `kubectl port-forward`, `fly proxy`, ...). The returned
+    /// [`TunnelHandle`] tears the forwards down when dropped. Providers that
+    /// don't support arbitrary port forwarding can leave the default, which
+    /// rejects every request.
+    async fn open_tunnel(&self, forwards: &[PortForward]) -> Result<TunnelHandle, ProviderError> {
+        let _ = forwards;
+        Err(ProviderError::CommandFailed(format!(
+            "{} does not support tunneling",
+            self.kind()
+        )))
+    }
+
+    /// Scales the deployment to `replicas` instances (`fly scale count`,
+    /// `kubectl scale`, Northflank's instances field, ...). Providers that
+    /// are single-instance by nature (Docker, E2B) leave the default, which
+    /// rejects every request; the next `status` call reflects the new
+    /// count once a provider supports it.
+    async fn scale(&self, replicas: u32) -> Result<(), ProviderError> {
+        let _ = replicas;
+        Err(ProviderError::CommandFailed(format!(
+            "{} does not support scaling",
+            self.kind()
+        )))
+    }
+
+    /// Whether a deployment into this provider is actually reachable (a
+    /// health check, SSH reachability, a pod's `Ready` condition, ...),
+    /// used by `sindri deploy --wait` to poll for readiness with one
+    /// contract across providers. The default delegates to [`Self::validate`],
+    /// since no provider here tracks richer per-instance readiness yet; a
+    /// provider with real instance tracking should override this with
+    /// something sharper.
+    async fn is_ready(&self) -> Result<(), ProviderError> {
+        self.validate().await
+    }
+
+    /// Renders this provider's native artifact (a compose file, `fly.toml`,
+    /// k8s manifests, an API request payload, ...) from `context` and
+    /// writes it under `out_dir` without deploying anything, returning the
+    /// paths written. Exists as an escape hatch for users who want to
+    /// check the raw artifact into their own repo or run it manually.
+    /// Providers that don't render a standalone artifact leave the
+    /// default, which rejects every request.
+    async fn export_config(
+        &self,
+        context: &TemplateContext,
+        out_dir: &Path,
+    ) -> Result<Vec<PathBuf>, ProviderError> {
+        let _ = (context, out_dir);
+        Err(ProviderError::CommandFailed(format!(
+            "{} does not support config export",
+            self.kind()
+        )))
+    }
+
+    /// Runs `command` on `target` (a container name, a pod name, ...) via
+    /// this provider's native exec (`docker exec`, `kubectl exec`),
+    /// writing `stdin` to it and returning whatever it wrote to stdout.
+    ///
+    /// Used by `sindri backup --to-provider`/`restore --from-provider` to
+    /// run `tar` on the remote and stream the archive across `exec`'s
+    /// stdio instead of requiring a manual `scp`. The whole payload is
+    /// buffered in memory on both ends — there's no chunked streaming or
+    /// resumability yet, so this isn't a good fit for archives that don't
+    /// comfortably fit in RAM.
+    ///
+    /// Providers without a native exec leave the default, which rejects
+    /// every request.
+    async fn exec(&self, target: &str, command: &[&str], stdin: &[u8]) -> Result<Vec<u8>, ProviderError> {
+        let _ = (target, command, stdin);
+        Err(ProviderError::CommandFailed(format!("{} does not support remote exec", self.kind())))
+    }
+}
+
+/// Polls `provider.is_ready()` until it succeeds or `timeout` elapses,
+/// sleeping `poll_interval` between attempts and invoking `on_attempt`
+/// after every attempt so callers can print progress. Returns the last
+/// observed error if `timeout` elapses before readiness.
+pub async fn wait_until_ready(
+    provider: &dyn Provider,
+    timeout: Duration,
+    poll_interval: Duration,
+    mut on_attempt: impl FnMut(&Result<(), ProviderError>),
+) -> Result<(), ProviderError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let result = provider.is_ready().await;
+        on_attempt(&result);
+        if result.is_ok() || tokio::time::Instant::now() >= deadline {
+            return result;
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Default per-provider timeout applied by [`validate_multi_cloud`].
+pub const DEFAULT_VALIDATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum jitter applied before a provider's validation starts, to avoid
+/// thundering-herd requests against provider APIs.
+const MAX_JITTER: Duration = Duration::from_millis(250);
+
+/// Validates every provider with bounded concurrency and a per-provider
+/// timeout, returning results ordered by [`CloudProvider`] regardless of
+/// completion order.
+///
+/// `max_concurrency` caps how many validations run at once so a long list of
+/// providers doesn't hammer every cloud's API simultaneously. A timed-out
+/// provider is reported as [`ValidationOutcome::TimedOut`] instead of
+/// stalling the rest of the batch.
+pub async fn validate_multi_cloud(
+    providers: &[Box<dyn Provider>],
+    max_concurrency: usize,
+    timeout: Duration,
+) -> Vec<(CloudProvider, ValidationOutcome)> {
+    use futures::stream::{self, StreamExt};
+
+    let max_concurrency = max_concurrency.max(1);
+
+    let mut results = stream::iter(providers.iter())
+        .map(|provider| async move {
+            let jitter =
+                Duration::from_millis(rand::random::<u64>() % MAX_JITTER.as_millis() as u64);
+            tokio::time::sleep(jitter).await;
+
+            let kind = provider.kind();
+            let outcome = match tokio::time::timeout(timeout, provider.validate()).await {
+                Ok(Ok(())) => ValidationOutcome::Ok,
+                Ok(Err(err)) => ValidationOutcome::Failed {
+                    reason: err.to_string(),
+                },
+                Err(_) => {
+                    tracing::warn!(provider = %kind, ?timeout, "provider validation timed out");
+                    ValidationOutcome::TimedOut
+                }
+            };
+            (kind, outcome)
+        })
+        .buffer_unordered(max_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+    results.sort_by_key(|(kind, _)| *kind);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeProvider {
+        kind: CloudProvider,
+        delay: Duration,
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl Provider for FakeProvider {
+        fn kind(&self) -> CloudProvider {
+            self.kind
+        }
+
+        async fn validate(&self) -> Result<(), ProviderError> {
+            tokio::time::sleep(self.delay).await;
+            if self.fails {
+                return Err(ProviderError::NotConfigured(self.kind.to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn orders_results_by_provider_regardless_of_completion_order() {
+        let providers: Vec<Box<dyn Provider>> = vec![
+            Box::new(FakeProvider {
+                kind: CloudProvider::Runpod,
+                delay: Duration::from_millis(30),
+                fails: false,
+            }),
+            Box::new(FakeProvider {
+                kind: CloudProvider::Docker,
+                delay: Duration::from_millis(5),
+                fails: false,
+            }),
+            Box::new(FakeProvider {
+                kind: CloudProvider::Fly,
+                delay: Duration::from_millis(15),
+                fails: true,
+            }),
+        ];
+
+        let results = validate_multi_cloud(&providers, 8, Duration::from_secs(1)).await;
+        let order: Vec<CloudProvider> = results.iter().map(|(kind, _)| *kind).collect();
+        assert_eq!(
+            order,
+            vec![CloudProvider::Docker, CloudProvider::Fly, CloudProvider::Runpod]
+        );
+        assert!(matches!(results[1].1, ValidationOutcome::Failed { .. }));
+    }
+
+    #[tokio::test]
+    async fn wait_until_ready_succeeds_once_the_provider_reports_ready() {
+        let provider = FakeProvider {
+            kind: CloudProvider::Docker,
+            delay: Duration::ZERO,
+            fails: false,
+        };
+        let mut attempts = 0;
+        let result = wait_until_ready(&provider, Duration::from_secs(1), Duration::from_millis(1), |_| {
+            attempts += 1;
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn wait_until_ready_times_out_with_the_last_observed_error() {
+        let provider = FakeProvider {
+            kind: CloudProvider::Docker,
+            delay: Duration::ZERO,
+            fails: true,
+        };
+        let mut attempts = 0;
+        let result = wait_until_ready(&provider, Duration::from_millis(20), Duration::from_millis(5), |_| {
+            attempts += 1;
+        })
+        .await;
+        assert!(result.is_err());
+        assert!(attempts > 1);
+    }
+
+    #[tokio::test]
+    async fn providers_without_a_scale_override_reject_every_request() {
+        let provider = FakeProvider {
+            kind: CloudProvider::Docker,
+            delay: Duration::ZERO,
+            fails: false,
+        };
+        assert!(provider.scale(3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn slow_provider_reports_timed_out_without_blocking_others() {
+        let providers: Vec<Box<dyn Provider>> = vec![
+            Box::new(FakeProvider {
+                kind: CloudProvider::Docker,
+                delay: Duration::from_millis(5),
+                fails: false,
+            }),
+            Box::new(FakeProvider {
+                kind: CloudProvider::Fly,
+                delay: Duration::from_secs(5),
+                fails: false,
+            }),
+        ];
+
+        let results = validate_multi_cloud(&providers, 8, Duration::from_millis(50)).await;
+        assert!(matches!(results[0].1, ValidationOutcome::Ok));
+        assert!(matches!(results[1].1, ValidationOutcome::TimedOut));
+    }
+
+    #[test]
+    fn every_provider_round_trips_through_display_and_from_str() {
+        for provider in [
+            CloudProvider::Docker,
+            CloudProvider::Fly,
+            CloudProvider::DevPod,
+            CloudProvider::K3d,
+            CloudProvider::Northflank,
+            CloudProvider::Packer,
+            CloudProvider::Runpod,
+            CloudProvider::E2b,
+        ] {
+            let parsed: CloudProvider = provider.to_string().parse().unwrap();
+            assert_eq!(parsed, provider);
+        }
+    }
+
+    #[test]
+    fn an_unknown_provider_name_is_rejected() {
+        assert!("openstack".parse::<CloudProvider>().is_err());
+    }
+}
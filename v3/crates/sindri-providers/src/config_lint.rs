@@ -0,0 +1,155 @@
+use sindri_core::{LintFinding, LintSeverity, MultiTargetConfig};
+
+use crate::CloudProvider;
+
+/// A semantic check over an already-loaded, already-schema-valid
+/// [`MultiTargetConfig`] — the kind of foot-gun structural validation
+/// can't catch (no targets declared, a target naming a provider this
+/// build can't deploy to, a retry override that would fail
+/// [`sindri_core::RetryPolicyConfig::validate`], ...). New rules are
+/// added by appending to [`lint`]'s rule list, not by modifying existing
+/// rules.
+///
+/// This workspace has no provider-capability model (which providers
+/// support a GPU request) or image/volume-size model (a home volume
+/// smaller than the image needs) — no such fields exist on
+/// [`MultiTargetConfig`] today — so those two rules from the original
+/// request can't be expressed yet; provider validity and retry-policy
+/// sanity are what a `sindri.yaml` actually has to check today.
+trait LintRule {
+    fn check(&self, config: &MultiTargetConfig) -> Vec<LintFinding>;
+}
+
+struct EmptyTargets;
+
+impl LintRule for EmptyTargets {
+    fn check(&self, config: &MultiTargetConfig) -> Vec<LintFinding> {
+        if config.targets.is_empty() {
+            vec![LintFinding {
+                severity: LintSeverity::Warning,
+                path: "targets".to_string(),
+                message: "no targets declared; nothing to deploy".to_string(),
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct UnknownProvider;
+
+impl LintRule for UnknownProvider {
+    fn check(&self, config: &MultiTargetConfig) -> Vec<LintFinding> {
+        config
+            .targets
+            .iter()
+            .filter(|(_, target)| target.provider.parse::<CloudProvider>().is_err())
+            .map(|(name, target)| LintFinding {
+                severity: LintSeverity::Error,
+                path: format!("targets.{name}.provider"),
+                message: format!("{:?} is not a provider this build knows how to deploy to", target.provider),
+            })
+            .collect()
+    }
+}
+
+struct InvalidRetry;
+
+impl LintRule for InvalidRetry {
+    fn check(&self, config: &MultiTargetConfig) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+        if let Some(retry) = &config.common.retry {
+            if let Err(err) = retry.validate() {
+                findings.push(LintFinding {
+                    severity: LintSeverity::Error,
+                    path: "common.retry".to_string(),
+                    message: err.to_string(),
+                });
+            }
+        }
+        for (name, target) in &config.targets {
+            if let Some(retry) = &target.retry {
+                if let Err(err) = retry.validate() {
+                    findings.push(LintFinding {
+                        severity: LintSeverity::Error,
+                        path: format!("targets.{name}.retry"),
+                        message: err.to_string(),
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+/// Runs every built-in [`LintRule`] over `config`.
+pub fn lint(config: &MultiTargetConfig) -> Vec<LintFinding> {
+    let rules: Vec<Box<dyn LintRule>> =
+        vec![Box::new(EmptyTargets), Box::new(UnknownProvider), Box::new(InvalidRetry)];
+    rules.iter().flat_map(|rule| rule.check(config)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sindri_core::{CommonConfig, RetryPolicyConfig, TargetConfig};
+
+    use super::*;
+
+    fn config_with(targets: BTreeMap<String, TargetConfig>) -> MultiTargetConfig {
+        MultiTargetConfig { name: "test".to_string(), common: CommonConfig::default(), targets }
+    }
+
+    #[test]
+    fn warns_on_no_targets() {
+        let findings = lint(&config_with(BTreeMap::new()));
+        assert_eq!(findings, vec![LintFinding {
+            severity: LintSeverity::Warning,
+            path: "targets".to_string(),
+            message: "no targets declared; nothing to deploy".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn errors_on_an_unrecognized_provider() {
+        let mut targets = BTreeMap::new();
+        targets.insert(
+            "local".to_string(),
+            TargetConfig { provider: "openstack".to_string(), ..Default::default() },
+        );
+        let findings = lint(&config_with(targets));
+        assert_eq!(findings, vec![LintFinding {
+            severity: LintSeverity::Error,
+            path: "targets.local.provider".to_string(),
+            message: "\"openstack\" is not a provider this build knows how to deploy to".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn errors_on_an_invalid_retry_override() {
+        let mut targets = BTreeMap::new();
+        targets.insert(
+            "local".to_string(),
+            TargetConfig {
+                provider: "docker".to_string(),
+                retry: Some(RetryPolicyConfig { max_attempts: 0, base_delay_ms: 200 }),
+                ..Default::default()
+            },
+        );
+        let findings = lint(&config_with(targets));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, "targets.local.retry");
+        assert_eq!(findings[0].severity, LintSeverity::Error);
+    }
+
+    #[test]
+    fn a_clean_config_has_no_findings() {
+        let mut targets = BTreeMap::new();
+        targets.insert(
+            "local".to_string(),
+            TargetConfig { provider: "docker".to_string(), ..Default::default() },
+        );
+        assert!(lint(&config_with(targets)).is_empty());
+    }
+}
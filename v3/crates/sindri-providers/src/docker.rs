@@ -0,0 +1,405 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use sindri_core::TemplateContext;
+
+use crate::retry::RetryPolicy;
+use crate::utils::{run_command, run_piped};
+use crate::{CloudProvider, PortForward, Provider, ProviderError, TunnelHandle};
+
+const GVISOR_SETUP_URL: &str = "https://gvisor.dev/docs/user_guide/install/";
+
+/// Tag prefix for images built by [`DockerProvider::build`], so a
+/// `docker images` listing can tell a Sindri-built image apart from one
+/// pulled from a registry.
+const BUILD_TAG_PREFIX: &str = "sindri-build";
+
+/// Built-in compose template rendered by [`DockerProvider::export_config`].
+/// Deliberately minimal: it covers exactly the fields Sindri itself knows
+/// about today (the service name, an optional runtime, and an optional
+/// local build context), not a general-purpose compose authoring tool.
+const COMPOSE_TEMPLATE: &str = r#"services:
+  {{ name }}:
+{%- if build_context %}
+    build:
+      context: {{ build_context }}
+{%- if dockerfile %}
+      dockerfile: {{ dockerfile }}
+{%- endif %}
+{%- else %}
+    image: {{ name }}
+{%- endif %}
+{%- if runtime %}
+    runtime: {{ runtime }}
+{%- endif %}
+{%- if memory %}
+    mem_limit: {{ memory }}
+{%- endif %}
+{%- if cpus %}
+    cpus: "{{ cpus }}"
+{%- endif %}
+"#;
+
+/// Where to build a deployment image from, set via
+/// [`DockerProvider::with_build`].
+#[derive(Debug, Clone)]
+struct BuildSource {
+    context_dir: PathBuf,
+    dockerfile: Option<PathBuf>,
+}
+
+/// A Docker deployment target, pinned to a specific `docker context` so
+/// that `status`/`connect`/`destroy` for a deployment always operate
+/// against the same daemon the original `deploy` used. `context: None`
+/// defers to the ambient `DOCKER_HOST` or the CLI's default context.
+#[derive(Debug, Clone, Default)]
+pub struct DockerProvider {
+    pub context: Option<String>,
+    /// OCI runtime to run containers under (e.g. `runsc` for gVisor).
+    /// `None` uses the daemon's default runtime.
+    pub runtime: Option<String>,
+    build: Option<BuildSource>,
+    retry_policy: RetryPolicy,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerInfo {
+    #[serde(rename = "Runtimes", default)]
+    runtimes: BTreeMap<String, serde_json::Value>,
+}
+
+impl DockerProvider {
+    pub fn new(context: Option<String>) -> Self {
+        Self { context, ..Self::default() }
+    }
+
+    pub fn with_runtime(mut self, runtime: Option<String>) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Builds the deployment image locally from `context_dir` (and,
+    /// optionally, a `dockerfile` elsewhere in the tree) via [`Self::build`]
+    /// instead of expecting it to already exist. Also switches
+    /// [`Self::export_config`]'s rendered compose service from `image:` to
+    /// a `build:` section.
+    pub fn with_build(mut self, context_dir: PathBuf, dockerfile: Option<PathBuf>) -> Self {
+        self.build = Some(BuildSource { context_dir, dockerfile });
+        self
+    }
+
+    /// Overrides the retry policy applied to `docker` invocations, e.g.
+    /// `RetryPolicy::disabled()` in CI where a transient failure should
+    /// fail fast rather than be retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    fn docker_command(&self) -> tokio::process::Command {
+        let mut command = tokio::process::Command::new("docker");
+        if let Some(context) = &self.context {
+            command.arg("--context").arg(context);
+        }
+        command
+    }
+
+    fn target_description(&self) -> String {
+        self.context.clone().unwrap_or_else(|| {
+            std::env::var("DOCKER_HOST").unwrap_or_else(|_| "default context".to_string())
+        })
+    }
+
+    async fn fetch_info(&self) -> Result<DockerInfo, ProviderError> {
+        let stdout = run_command(
+            || {
+                let mut command = self.docker_command();
+                command.arg("info").arg("--format").arg("{{json .}}");
+                command
+            },
+            &self.retry_policy,
+        )
+        .await
+        .map_err(|err| match err {
+            ProviderError::CommandFailed(message) => ProviderError::CommandFailed(format!(
+                "docker daemon unreachable via {}: {message}",
+                self.target_description()
+            )),
+            other => other,
+        })?;
+
+        serde_json::from_str(&stdout).map_err(|err| {
+            ProviderError::CommandFailed(format!("failed to parse `docker info` output: {err}"))
+        })
+    }
+
+    /// Builds the image configured via [`Self::with_build`], tagging it
+    /// with a hash of the build context's contents so an unchanged context
+    /// always produces the same tag. An image already present under that
+    /// tag is reused — `docker build` is skipped entirely — so a build
+    /// only actually runs when the context has changed. Returns the tag.
+    ///
+    /// `sindri deploy --build-context` calls this before validating the
+    /// target, so a local Dockerfile is built ahead of time instead of
+    /// assuming a pulled image. See [`Self::image_source`] for reading
+    /// this tag prefix back off a running container, e.g. for `status`.
+    pub async fn build(&self) -> Result<String, ProviderError> {
+        let build = self.build.as_ref().ok_or_else(|| {
+            ProviderError::CommandFailed(
+                "no build context configured; call DockerProvider::with_build first".to_string(),
+            )
+        })?;
+
+        let hash = content_hash(&build.context_dir)?;
+        let tag = format!("{BUILD_TAG_PREFIX}:{hash}");
+
+        let already_built = run_command(
+            || {
+                let mut command = self.docker_command();
+                command.arg("image").arg("inspect").arg(&tag);
+                command
+            },
+            &RetryPolicy::disabled(),
+        )
+        .await
+        .is_ok();
+
+        if already_built {
+            return Ok(tag);
+        }
+
+        run_command(
+            || {
+                let mut command = self.docker_command();
+                command.arg("build").arg("-t").arg(&tag);
+                if let Some(dockerfile) = &build.dockerfile {
+                    command.arg("-f").arg(dockerfile);
+                }
+                command.arg(&build.context_dir);
+                command
+            },
+            &self.retry_policy,
+        )
+        .await?;
+
+        Ok(tag)
+    }
+
+    /// Reports whether `container`'s image came from [`Self::build`] (tagged
+    /// under [`BUILD_TAG_PREFIX`]) or was pulled from a registry, by
+    /// inspecting the image it's actually running. Returns `Ok(None)` if
+    /// `container` can't be found rather than failing outright, the same
+    /// way a missing runtime/replica count elsewhere in `DeploymentStatus`
+    /// degrades instead of erroring.
+    pub async fn image_source(&self, container: &str) -> Result<Option<String>, ProviderError> {
+        let image = match run_command(
+            || {
+                let mut command = self.docker_command();
+                command
+                    .arg("inspect")
+                    .arg("--format")
+                    .arg("{{.Config.Image}}")
+                    .arg(container);
+                command
+            },
+            &RetryPolicy::disabled(),
+        )
+        .await
+        {
+            Ok(image) => image,
+            Err(_) => return Ok(None),
+        };
+
+        Ok(Some(if image.starts_with(BUILD_TAG_PREFIX) { "built".to_string() } else { "pulled".to_string() }))
+    }
+}
+
+/// Recursively hashes every file under `context_dir`, combining their
+/// per-file digests into one hash that changes if any file's path or
+/// content changes — mirrors `sindri_extensions::manifest::content_hash`'s
+/// approach for the same problem (detecting when a directory's contents
+/// have changed) applied to a Docker build context instead of an
+/// installed extension.
+fn content_hash(context_dir: &Path) -> Result<String, ProviderError> {
+    let mut files = BTreeMap::new();
+    hash_dir(context_dir, context_dir, &mut files)?;
+
+    let mut hasher = Sha256::new();
+    for (path, file_hash) in &files {
+        hasher.update(path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(file_hash.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(hex_encode(hasher.finalize()))
+}
+
+fn hash_dir(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) -> Result<(), ProviderError> {
+    let entries = fs::read_dir(dir).map_err(|source| {
+        ProviderError::CommandFailed(format!("failed to read build context {}: {source}", dir.display()))
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(ProviderError::Io)?;
+        let path = entry.path();
+        if path.is_dir() {
+            hash_dir(root, &path, files)?;
+            continue;
+        }
+        let bytes = fs::read(&path).map_err(ProviderError::Io)?;
+        let rel = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+        files.insert(rel, hex_encode(Sha256::digest(&bytes)));
+    }
+    Ok(())
+}
+
+/// Minimal hex-encoding helper so we don't pull in a whole `hex` crate for
+/// one call site.
+fn hex_encode(bytes: impl AsRef<[u8]>) -> String {
+    bytes.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[async_trait]
+impl Provider for DockerProvider {
+    fn kind(&self) -> CloudProvider {
+        CloudProvider::Docker
+    }
+
+    /// Confirms the selected context/daemon is reachable and, if a
+    /// `runtime` was requested, that the daemon actually has it installed
+    /// — rather than silently falling back to the default runtime.
+    async fn validate(&self) -> Result<(), ProviderError> {
+        let info = self.fetch_info().await?;
+
+        if let Some(runtime) = &self.runtime {
+            if !info.runtimes.contains_key(runtime) {
+                let available = info.runtimes.keys().cloned().collect::<Vec<_>>().join(", ");
+                return Err(ProviderError::CommandFailed(format!(
+                    "runtime {runtime:?} is not installed on the docker daemon at {} \
+                     (available: {available}); see {GVISOR_SETUP_URL} to install gVisor",
+                    self.target_description()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Docker publishes container ports at `run`/`create` time; there's no
+    /// way to remap a *new* host port onto a running container. A forward
+    /// where `local == remote` is treated as already published and is a
+    /// no-op; anything else fails with a clear explanation instead of
+    /// pretending to set up a tunnel that can't exist.
+    async fn open_tunnel(&self, forwards: &[PortForward]) -> Result<TunnelHandle, ProviderError> {
+        if let Some(unsupported) = forwards.iter().find(|forward| forward.local != forward.remote) {
+            return Err(ProviderError::CommandFailed(format!(
+                "docker can't remap local port {} onto remote port {} on a running container; \
+                 republish the port at deploy time instead",
+                unsupported.local, unsupported.remote
+            )));
+        }
+        Ok(TunnelHandle::noop())
+    }
+
+    /// Renders a `docker-compose.yml` from [`COMPOSE_TEMPLATE`] and writes
+    /// it to `out_dir`, folding in this provider's `runtime` alongside
+    /// `context`'s built-in fields. Renders a `build:` section instead of
+    /// `image:` when [`Self::with_build`] configured a local build
+    /// context.
+    async fn export_config(
+        &self,
+        context: &TemplateContext,
+        out_dir: &Path,
+    ) -> Result<Vec<PathBuf>, ProviderError> {
+        let mut tera_context = context.to_tera_context();
+        tera_context.insert("runtime", &self.runtime);
+        tera_context.insert(
+            "build_context",
+            &self.build.as_ref().map(|build| build.context_dir.display().to_string()),
+        );
+        tera_context.insert(
+            "dockerfile",
+            &self.build.as_ref().and_then(|build| build.dockerfile.as_ref()).map(|path| path.display().to_string()),
+        );
+
+        let rendered = tera::Tera::one_off(COMPOSE_TEMPLATE, &tera_context, false).map_err(|err| {
+            ProviderError::CommandFailed(format!("failed to render docker-compose.yml: {err}"))
+        })?;
+
+        tokio::fs::create_dir_all(out_dir).await?;
+        let path = out_dir.join("docker-compose.yml");
+        tokio::fs::write(&path, rendered).await?;
+        Ok(vec![path])
+    }
+
+    /// Runs `command` via `docker exec -i <target>`, where `target` is a
+    /// container name or id.
+    async fn exec(&self, target: &str, command: &[&str], stdin: &[u8]) -> Result<Vec<u8>, ProviderError> {
+        let mut docker_command = self.docker_command();
+        docker_command.arg("exec").arg("-i").arg(target).args(command);
+        run_piped(docker_command, stdin.to_vec()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn renders_a_compose_file_with_the_requested_runtime() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = DockerProvider::new(None).with_runtime(Some("runsc".to_string()));
+        let context = TemplateContext::builder().name("dev-box").build().unwrap();
+
+        let written = provider.export_config(&context, dir.path()).await.unwrap();
+
+        assert_eq!(written, vec![dir.path().join("docker-compose.yml")]);
+        let contents = std::fs::read_to_string(&written[0]).unwrap();
+        assert!(contents.contains("dev-box"));
+        assert!(contents.contains("runtime: runsc"));
+        assert!(contents.contains("image: dev-box"));
+    }
+
+    #[tokio::test]
+    async fn renders_a_build_section_instead_of_image_when_a_build_context_is_set() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = DockerProvider::new(None)
+            .with_build(PathBuf::from("."), Some(PathBuf::from("docker/Dockerfile")));
+        let context = TemplateContext::builder().name("dev-box").build().unwrap();
+
+        let written = provider.export_config(&context, dir.path()).await.unwrap();
+
+        let contents = std::fs::read_to_string(&written[0]).unwrap();
+        assert!(contents.contains("build:"));
+        assert!(contents.contains("context: ."));
+        assert!(contents.contains("dockerfile: docker/Dockerfile"));
+        assert!(!contents.contains("image: dev-box"));
+    }
+
+    #[test]
+    fn content_hash_changes_when_a_file_in_the_build_context_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Dockerfile"), b"FROM alpine").unwrap();
+
+        let before = content_hash(dir.path()).unwrap();
+        assert_eq!(before, content_hash(dir.path()).unwrap());
+
+        std::fs::write(dir.path().join("Dockerfile"), b"FROM alpine:3.19").unwrap();
+        assert_ne!(before, content_hash(dir.path()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn build_without_a_configured_context_errors() {
+        let provider = DockerProvider::new(None);
+        assert!(provider.build().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn image_source_is_none_for_a_container_that_cannot_be_inspected() {
+        let provider = DockerProvider::new(None);
+        assert_eq!(provider.image_source("no-such-container").await.unwrap(), None);
+    }
+}
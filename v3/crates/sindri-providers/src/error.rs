@@ -0,0 +1,46 @@
+use thiserror::Error;
+
+/// Errors that can occur while validating or operating a cloud provider.
+///
+/// [`Self::CommandFailed`] is the catch-all a provider falls back to when a
+/// failure doesn't match one of the more specific variants below; callers
+/// that want to react to a particular failure kind (the deploy command
+/// suggesting a fix for [`Self::AuthRequired`], for instance) should match
+/// on those instead of parsing [`Self::CommandFailed`]'s message.
+#[derive(Debug, Error)]
+pub enum ProviderError {
+    #[error("provider {0} is not configured")]
+    NotConfigured(String),
+
+    #[error("provider {0} validation timed out after {1:?}")]
+    Timeout(String, std::time::Duration),
+
+    #[error("provider command failed: {0}")]
+    CommandFailed(String),
+
+    /// The provider's CLI ({name}) isn't on `PATH`.
+    #[error("required tool {name:?} is not installed")]
+    ToolNotInstalled {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The provider rejected the request for lack of (or invalid)
+    /// credentials.
+    #[error("provider authentication required: {0}")]
+    AuthRequired(String),
+
+    /// The provider reported that a referenced resource (container,
+    /// namespace, pod, ...) doesn't exist.
+    #[error("resource not found: {0}")]
+    ResourceNotFound(String),
+
+    /// The provider rejected the request for exceeding a quota or rate
+    /// limit.
+    #[error("provider quota exceeded: {0}")]
+    QuotaExceeded(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
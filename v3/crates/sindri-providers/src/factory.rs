@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cloud::DEFAULT_VALIDATION_TIMEOUT;
+use crate::retry::RetryPolicy;
+use crate::{CloudProvider, DockerProvider, Provider, ProviderError};
+
+/// Common knobs threaded into a provider at construction, so the CLI can
+/// apply global flags uniformly instead of each command re-reading its
+/// own config and wiring them in one at a time.
+///
+/// `timeout` and `config_dir` aren't consumed by [`create_provider_with`]
+/// itself — no [`Provider`] implementation has a matching construction
+/// hook yet — but are carried through for callers that need them (e.g.
+/// `sindri deploy --wait`'s timeout), so they only have to be threaded
+/// once.
+#[derive(Debug, Clone)]
+pub struct ProviderOptions {
+    pub timeout: Duration,
+    pub retry_policy: RetryPolicy,
+    pub dry_run: bool,
+    pub config_dir: Option<PathBuf>,
+}
+
+impl Default for ProviderOptions {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_VALIDATION_TIMEOUT,
+            retry_policy: RetryPolicy::default(),
+            dry_run: false,
+            config_dir: None,
+        }
+    }
+}
+
+/// Builds a [`Provider`] for a given name from [`ProviderOptions`].
+type ProviderBuilder = Arc<dyn Fn(ProviderOptions) -> Result<Box<dyn Provider>, ProviderError> + Send + Sync>;
+
+/// A lookup from provider name to constructor, so a consumer that links
+/// against this crate as a library can add support for a provider this
+/// crate doesn't ship (a proprietary cloud, say) without forking it:
+/// implement [`Provider`], then [`ProviderRegistry::register`] a
+/// constructor for it under whatever name their own CLI surface uses.
+///
+/// [`ProviderRegistry::builtin`] populates the same providers
+/// [`create_provider`] does, under [`CloudProvider::Display`]'s name
+/// (`"docker"`, `"fly"`, ...), so a consumer can mix registering a plugin
+/// with using everything this crate already supports.
+pub struct ProviderRegistry {
+    builders: HashMap<String, ProviderBuilder>,
+}
+
+impl ProviderRegistry {
+    /// An empty registry, with no providers (not even the built-in ones)
+    /// registered.
+    pub fn new() -> Self {
+        Self { builders: HashMap::new() }
+    }
+
+    /// A registry pre-populated with every provider this crate ships an
+    /// adapter for, exactly matching [`create_provider_with`]'s behavior.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register(CloudProvider::Docker.to_string(), |options| {
+            Ok(Box::new(DockerProvider::new(None).with_retry_policy(options.retry_policy)))
+        });
+        registry
+    }
+
+    /// Registers `builder` under `name`, overwriting whatever (built-in or
+    /// otherwise) was previously registered under it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl Fn(ProviderOptions) -> Result<Box<dyn Provider>, ProviderError> + Send + Sync + 'static,
+    ) {
+        self.builders.insert(name.into(), Arc::new(builder));
+    }
+
+    /// Builds the provider registered under `name`, applying `options`.
+    pub fn create(&self, name: &str, options: ProviderOptions) -> Result<Box<dyn Provider>, ProviderError> {
+        let builder = self
+            .builders
+            .get(name)
+            .ok_or_else(|| ProviderError::NotConfigured(format!("no provider adapter is implemented for {name} yet")))?;
+        builder(options)
+    }
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Builds a provider for `kind` with [`ProviderOptions::default`]. See
+/// [`create_provider_with`] to thread specific options through instead.
+pub fn create_provider(kind: CloudProvider) -> Result<Box<dyn Provider>, ProviderError> {
+    create_provider_with(kind, ProviderOptions::default())
+}
+
+/// Builds a provider for `kind`, applying `options` uniformly (currently
+/// just the retry policy, the only knob every [`Provider`] supports so
+/// far). Provider-specific identifying data that a [`CloudProvider`]
+/// discriminant alone can't supply (a Docker `--context`, a k3d
+/// `--namespace`, ...) is out of scope here and stays threaded by the
+/// caller, same as today.
+///
+/// A thin wrapper over [`ProviderRegistry::builtin`] for callers that
+/// don't need to register their own providers; see [`ProviderRegistry`]
+/// for that.
+pub fn create_provider_with(kind: CloudProvider, options: ProviderOptions) -> Result<Box<dyn Provider>, ProviderError> {
+    ProviderRegistry::builtin().create(&kind.to_string(), options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_a_docker_provider_with_default_options() {
+        let provider = create_provider(CloudProvider::Docker).unwrap();
+        assert_eq!(provider.kind(), CloudProvider::Docker);
+    }
+
+    #[test]
+    fn rejects_clouds_without_a_provider_adapter() {
+        assert!(create_provider(CloudProvider::Fly).is_err());
+    }
+
+    #[test]
+    fn a_registered_plugin_is_found_by_name() {
+        let mut registry = ProviderRegistry::builtin();
+        registry.register("acme-cloud", |options| {
+            Ok(Box::new(DockerProvider::new(None).with_retry_policy(options.retry_policy)))
+        });
+
+        let provider = registry.create("acme-cloud", ProviderOptions::default()).unwrap();
+        assert_eq!(provider.kind(), CloudProvider::Docker);
+    }
+
+    #[test]
+    fn a_plugin_can_override_a_built_in_provider_by_name() {
+        let mut registry = ProviderRegistry::builtin();
+        registry.register(CloudProvider::Fly.to_string(), |options| {
+            Ok(Box::new(DockerProvider::new(None).with_retry_policy(options.retry_policy)))
+        });
+
+        let provider = registry.create("fly", ProviderOptions::default()).unwrap();
+        assert_eq!(provider.kind(), CloudProvider::Docker);
+    }
+
+    #[test]
+    fn an_empty_registry_has_no_built_ins() {
+        let registry = ProviderRegistry::new();
+        assert!(registry.create("docker", ProviderOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+
+use crate::retry::RetryPolicy;
+use crate::utils::{run_command, run_piped};
+use crate::{CloudProvider, Provider, ProviderError};
+
+/// Label applied to the namespace itself when Sindri created it, so
+/// `destroy` knows it's allowed to remove the namespace once empty rather
+/// than one a user created and pointed Sindri at.
+const OWNS_NAMESPACE_LABEL: &str = "sindri.dev/owns-namespace=true";
+
+/// Label applied to every resource Sindri creates inside a namespace, so
+/// `destroy` can remove only what it manages via a label selector, even
+/// when the namespace is shared with resources Sindri didn't create.
+pub const MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by=sindri";
+
+/// A Kubernetes deployment target, scoped to a single namespace. `deploy`
+/// creates the namespace if it's missing (unless opted out), and labels it
+/// so a later `destroy` can tell whether it's safe to remove.
+#[derive(Debug, Clone)]
+pub struct KubernetesProvider {
+    pub namespace: String,
+    pub context: Option<String>,
+    create_namespace_if_missing: bool,
+    retry_policy: RetryPolicy,
+}
+
+impl KubernetesProvider {
+    pub fn new(namespace: impl Into<String>, context: Option<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            context,
+            create_namespace_if_missing: true,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_create_namespace_if_missing(mut self, create_namespace_if_missing: bool) -> Self {
+        self.create_namespace_if_missing = create_namespace_if_missing;
+        self
+    }
+
+    /// Overrides the retry policy applied to `kubectl` invocations, e.g.
+    /// `RetryPolicy::disabled()` in CI where a transient failure should
+    /// fail fast rather than be retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    fn kubectl_command(&self) -> tokio::process::Command {
+        let mut command = tokio::process::Command::new("kubectl");
+        if let Some(context) = &self.context {
+            command.arg("--context").arg(context);
+        }
+        command
+    }
+
+    async fn run(&self, args: &[&str]) -> Result<String, ProviderError> {
+        run_command(
+            || {
+                let mut command = self.kubectl_command();
+                command.args(args);
+                command
+            },
+            &self.retry_policy,
+        )
+        .await
+        .map_err(|err| match err {
+            ProviderError::CommandFailed(message) => {
+                ProviderError::CommandFailed(format!("kubectl {}: {message}", args.join(" ")))
+            }
+            other => other,
+        })
+    }
+
+    async fn namespace_exists(&self) -> Result<bool, ProviderError> {
+        let output = self
+            .kubectl_command()
+            .args(["get", "namespace", &self.namespace])
+            .output()
+            .await?;
+        Ok(output.status.success())
+    }
+
+    /// Ensures [`Self::namespace`] exists, creating and labeling it if it's
+    /// missing and [`Self::with_create_namespace_if_missing`] wasn't set to
+    /// `false`. Returns whether this call is what created it.
+    pub async fn ensure_namespace(&self) -> Result<bool, ProviderError> {
+        if self.namespace_exists().await? {
+            return Ok(false);
+        }
+        if !self.create_namespace_if_missing {
+            return Err(ProviderError::NotConfigured(format!(
+                "namespace {} does not exist and namespace auto-create is disabled",
+                self.namespace
+            )));
+        }
+        self.run(&["create", "namespace", &self.namespace]).await?;
+        self.run(&["label", "namespace", &self.namespace, OWNS_NAMESPACE_LABEL])
+            .await?;
+        Ok(true)
+    }
+
+    /// Whether Sindri created [`Self::namespace`] (as opposed to a
+    /// pre-existing namespace it was pointed at).
+    async fn owns_namespace(&self) -> Result<bool, ProviderError> {
+        let output = self
+            .kubectl_command()
+            .args(["get", "namespace", &self.namespace, "-o", "jsonpath={.metadata.labels.sindri\\.dev/owns-namespace}"])
+            .output()
+            .await?;
+        Ok(output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "true")
+    }
+
+    /// Whether the namespace has no resources left in it at all (not just
+    /// Sindri-managed ones) — the bar for [`Self::destroy`] to also remove
+    /// the namespace itself.
+    async fn namespace_is_empty(&self) -> Result<bool, ProviderError> {
+        let remaining = self
+            .run(&["get", "all", "-n", &self.namespace, "--no-headers"])
+            .await?;
+        Ok(remaining.is_empty())
+    }
+
+    /// Removes only the resources Sindri manages in [`Self::namespace`]
+    /// (via [`MANAGED_BY_LABEL`]), then removes the namespace itself if and
+    /// only if Sindri created it and it's now empty.
+    pub async fn destroy(&self) -> Result<(), ProviderError> {
+        self.run(&["delete", "all", "-n", &self.namespace, "-l", MANAGED_BY_LABEL])
+            .await?;
+
+        if self.owns_namespace().await? && self.namespace_is_empty().await? {
+            self.run(&["delete", "namespace", &self.namespace]).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Provider for KubernetesProvider {
+    fn kind(&self) -> CloudProvider {
+        CloudProvider::K3d
+    }
+
+    /// Confirms `kubectl` can reach the cluster. Namespace existence is
+    /// checked separately by [`Self::ensure_namespace`], since `deploy`
+    /// wants to create it rather than fail outright.
+    async fn validate(&self) -> Result<(), ProviderError> {
+        self.run(&["cluster-info"]).await.map(|_| ())
+    }
+
+    /// Runs `command` via `kubectl exec -i -n <namespace> <target> --
+    /// <command>`, where `target` is a pod name.
+    async fn exec(&self, target: &str, command: &[&str], stdin: &[u8]) -> Result<Vec<u8>, ProviderError> {
+        let mut kubectl_command = self.kubectl_command();
+        kubectl_command
+            .args(["exec", "-i", "-n", &self.namespace, target, "--"])
+            .args(command);
+        run_piped(kubectl_command, stdin.to_vec()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_creating_a_missing_namespace() {
+        let provider = KubernetesProvider::new("sindri-dev", None);
+        assert!(provider.create_namespace_if_missing);
+    }
+
+    #[test]
+    fn opt_out_disables_namespace_auto_create() {
+        let provider = KubernetesProvider::new("sindri-dev", None).with_create_namespace_if_missing(false);
+        assert!(!provider.create_namespace_if_missing);
+    }
+
+    #[test]
+    fn kind_is_k3d() {
+        let provider = KubernetesProvider::new("sindri-dev", None);
+        assert_eq!(provider.kind(), CloudProvider::K3d);
+    }
+}
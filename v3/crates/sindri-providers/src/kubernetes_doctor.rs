@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::retry::RetryPolicy;
+use crate::utils::run_command;
+use crate::ProviderError;
+
+/// How severe a [`ClusterProblem`] is. [`KubernetesDoctor::diagnose`] sorts
+/// its report most-severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Warning,
+    Critical,
+}
+
+/// One problem found while [`KubernetesDoctor::diagnose`]ing a cluster.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterProblem {
+    pub severity: Severity,
+    pub check: String,
+    pub message: String,
+}
+
+/// Minimum cluster resources a deployment profile needs, checked against
+/// the sum of every node's allocatable capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceProfile {
+    #[serde(default)]
+    pub min_memory_bytes: u64,
+    #[serde(default)]
+    pub min_cpu_millis: u64,
+}
+
+/// Deep diagnostics against a live cluster: API reachability, default
+/// storage class, DNS, allocatable resources for a [`ResourceProfile`],
+/// and pending pods in a namespace with their events. Works against both
+/// Sindri-created clusters and arbitrary kubeconfig contexts — it only
+/// assumes `kubectl` and a reachable context, the same as
+/// [`crate::KubernetesProvider`].
+#[derive(Debug, Clone)]
+pub struct KubernetesDoctor {
+    pub namespace: String,
+    pub context: Option<String>,
+    retry_policy: RetryPolicy,
+}
+
+impl KubernetesDoctor {
+    pub fn new(namespace: impl Into<String>, context: Option<String>) -> Self {
+        Self { namespace: namespace.into(), context, retry_policy: RetryPolicy::disabled() }
+    }
+
+    fn kubectl_command(&self) -> tokio::process::Command {
+        let mut command = tokio::process::Command::new("kubectl");
+        if let Some(context) = &self.context {
+            command.arg("--context").arg(context);
+        }
+        command
+    }
+
+    async fn run(&self, args: &[&str]) -> Result<String, ProviderError> {
+        run_command(
+            || {
+                let mut command = self.kubectl_command();
+                command.args(args);
+                command
+            },
+            &self.retry_policy,
+        )
+        .await
+    }
+
+    /// Runs every check, returning problems in priority order (most severe
+    /// first). An empty list means the cluster looks healthy. Once the API
+    /// server itself is unreachable every other check is skipped, since
+    /// they'd only report the same root cause.
+    pub async fn diagnose(&self, profile: &ResourceProfile) -> Vec<ClusterProblem> {
+        let mut problems = Vec::new();
+
+        if let Err(err) = self.run(&["cluster-info"]).await {
+            problems.push(ClusterProblem {
+                severity: Severity::Critical,
+                check: "api-server".to_string(),
+                message: format!("API server is not reachable: {err}"),
+            });
+            return problems;
+        }
+
+        problems.extend(self.check_default_storage_class().await);
+        problems.extend(self.check_dns().await);
+        problems.extend(self.check_resources(profile).await);
+        problems.extend(self.check_pending_pods().await);
+
+        problems.sort_by_key(|problem| std::cmp::Reverse(problem.severity));
+        problems
+    }
+
+    async fn check_default_storage_class(&self) -> Vec<ClusterProblem> {
+        match self.run(&["get", "storageclass", "-o", "json"]).await {
+            Ok(output) => {
+                let has_default = parse_json(&output)
+                    .and_then(|value| value["items"].as_array().cloned())
+                    .unwrap_or_default()
+                    .iter()
+                    .any(|item| {
+                        item["metadata"]["annotations"]["storageclass.kubernetes.io/is-default-class"]
+                            == "true"
+                    });
+                if has_default {
+                    Vec::new()
+                } else {
+                    vec![ClusterProblem {
+                        severity: Severity::Warning,
+                        check: "default-storage-class".to_string(),
+                        message: "no default StorageClass is set; PVCs without storageClassName will stay Pending".to_string(),
+                    }]
+                }
+            }
+            Err(err) => vec![ClusterProblem {
+                severity: Severity::Warning,
+                check: "default-storage-class".to_string(),
+                message: format!("could not list storage classes: {err}"),
+            }],
+        }
+    }
+
+    async fn check_dns(&self) -> Vec<ClusterProblem> {
+        match self
+            .run(&[
+                "get",
+                "deployment",
+                "coredns",
+                "-n",
+                "kube-system",
+                "-o",
+                "jsonpath={.status.readyReplicas}",
+            ])
+            .await
+        {
+            Ok(ready) if ready.trim().parse::<u32>().unwrap_or(0) > 0 => Vec::new(),
+            Ok(_) => vec![ClusterProblem {
+                severity: Severity::Critical,
+                check: "dns".to_string(),
+                message: "CoreDNS has no ready replicas; pod DNS resolution will fail".to_string(),
+            }],
+            Err(err) => vec![ClusterProblem {
+                severity: Severity::Warning,
+                check: "dns".to_string(),
+                message: format!("could not check CoreDNS status: {err}"),
+            }],
+        }
+    }
+
+    async fn check_resources(&self, profile: &ResourceProfile) -> Vec<ClusterProblem> {
+        if profile.min_memory_bytes == 0 && profile.min_cpu_millis == 0 {
+            return Vec::new();
+        }
+
+        let output = match self.run(&["get", "nodes", "-o", "json"]).await {
+            Ok(output) => output,
+            Err(err) => {
+                return vec![ClusterProblem {
+                    severity: Severity::Warning,
+                    check: "allocatable-resources".to_string(),
+                    message: format!("could not list nodes: {err}"),
+                }]
+            }
+        };
+        let Some(value) = parse_json(&output) else {
+            return vec![ClusterProblem {
+                severity: Severity::Warning,
+                check: "allocatable-resources".to_string(),
+                message: "could not parse node list".to_string(),
+            }];
+        };
+        let items = value["items"].as_array().cloned().unwrap_or_default();
+        let total_memory: u64 = items
+            .iter()
+            .filter_map(|node| node["status"]["allocatable"]["memory"].as_str())
+            .filter_map(parse_quantity_bytes)
+            .sum();
+        let total_cpu_millis: u64 = items
+            .iter()
+            .filter_map(|node| node["status"]["allocatable"]["cpu"].as_str())
+            .filter_map(parse_cpu_millis)
+            .sum();
+
+        let mut problems = Vec::new();
+        if profile.min_memory_bytes > total_memory {
+            problems.push(ClusterProblem {
+                severity: Severity::Critical,
+                check: "allocatable-resources".to_string(),
+                message: format!(
+                    "profile needs {} bytes of memory but the cluster has {total_memory} allocatable",
+                    profile.min_memory_bytes
+                ),
+            });
+        }
+        if profile.min_cpu_millis > total_cpu_millis {
+            problems.push(ClusterProblem {
+                severity: Severity::Critical,
+                check: "allocatable-resources".to_string(),
+                message: format!(
+                    "profile needs {}m CPU but the cluster has {total_cpu_millis}m allocatable",
+                    profile.min_cpu_millis
+                ),
+            });
+        }
+        problems
+    }
+
+    async fn check_pending_pods(&self) -> Vec<ClusterProblem> {
+        let output = match self
+            .run(&[
+                "get",
+                "pods",
+                "-n",
+                &self.namespace,
+                "--field-selector=status.phase=Pending",
+                "-o",
+                "json",
+            ])
+            .await
+        {
+            Ok(output) => output,
+            Err(err) => {
+                return vec![ClusterProblem {
+                    severity: Severity::Warning,
+                    check: "pending-pods".to_string(),
+                    message: format!("could not list pods in {}: {err}", self.namespace),
+                }]
+            }
+        };
+        let Some(value) = parse_json(&output) else {
+            return Vec::new();
+        };
+        let items = value["items"].as_array().cloned().unwrap_or_default();
+
+        let mut problems = Vec::with_capacity(items.len());
+        for pod in items {
+            let name = pod["metadata"]["name"].as_str().unwrap_or("<unknown>").to_string();
+            let selector = format!("--field-selector=involvedObject.name={name}");
+            let events = self
+                .run(&[
+                    "get",
+                    "events",
+                    "-n",
+                    &self.namespace,
+                    &selector,
+                    "-o",
+                    "jsonpath={range .items[*]}{.reason}: {.message}\n{end}",
+                ])
+                .await
+                .unwrap_or_default();
+            let events = events.trim();
+            problems.push(ClusterProblem {
+                severity: Severity::Critical,
+                check: "pending-pods".to_string(),
+                message: if events.is_empty() {
+                    format!("pod {name} is pending with no recorded events")
+                } else {
+                    format!("pod {name} is pending: {events}")
+                },
+            });
+        }
+        problems
+    }
+}
+
+fn parse_json(output: &str) -> Option<Value> {
+    serde_json::from_str(output).ok()
+}
+
+/// Parses a Kubernetes memory quantity (`512Mi`, `2Gi`, `1024`, ...) into
+/// bytes. Only the binary (`Ki`/`Mi`/`Gi`/`Ti`) suffixes actually emitted
+/// by `kubectl get nodes -o json` for allocatable memory are handled.
+fn parse_quantity_bytes(quantity: &str) -> Option<u64> {
+    const UNITS: &[(&str, u64)] =
+        &[("Ki", 1024), ("Mi", 1024 * 1024), ("Gi", 1024 * 1024 * 1024), ("Ti", 1024 * 1024 * 1024 * 1024)];
+
+    for (suffix, multiplier) in UNITS {
+        if let Some(number) = quantity.strip_suffix(suffix) {
+            return number.parse::<u64>().ok().map(|n| n * multiplier);
+        }
+    }
+    quantity.parse().ok()
+}
+
+/// Parses a Kubernetes CPU quantity (`4`, `250m`) into millicores.
+fn parse_cpu_millis(quantity: &str) -> Option<u64> {
+    if let Some(milli) = quantity.strip_suffix('m') {
+        milli.parse().ok()
+    } else {
+        quantity.parse::<f64>().ok().map(|cores| (cores * 1000.0) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_binary_memory_suffixes() {
+        assert_eq!(parse_quantity_bytes("512Ki"), Some(512 * 1024));
+        assert_eq!(parse_quantity_bytes("2Gi"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_quantity_bytes("1024"), Some(1024));
+    }
+
+    #[test]
+    fn parses_cpu_in_millicores_and_whole_cores() {
+        assert_eq!(parse_cpu_millis("250m"), Some(250));
+        assert_eq!(parse_cpu_millis("4"), Some(4000));
+    }
+
+    #[test]
+    fn sorts_problems_most_severe_first() {
+        let mut problems = [
+            ClusterProblem { severity: Severity::Warning, check: "a".to_string(), message: String::new() },
+            ClusterProblem { severity: Severity::Critical, check: "b".to_string(), message: String::new() },
+        ];
+        problems.sort_by_key(|problem| std::cmp::Reverse(problem.severity));
+        assert_eq!(problems[0].check, "b");
+    }
+}
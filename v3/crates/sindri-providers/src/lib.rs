@@ -0,0 +1,27 @@
+//! Cloud provider adapters and the shared [`Provider`] abstraction.
+
+mod cloud;
+mod config_lint;
+mod docker;
+mod error;
+mod factory;
+mod kubernetes;
+mod kubernetes_doctor;
+mod local_cluster;
+mod retry;
+mod tunnel;
+mod utils;
+
+pub use cloud::{
+    validate_multi_cloud, wait_until_ready, CloudProvider, Provider, ValidationOutcome,
+    DEFAULT_VALIDATION_TIMEOUT,
+};
+pub use config_lint::lint as lint_config;
+pub use docker::DockerProvider;
+pub use error::ProviderError;
+pub use factory::{create_provider, create_provider_with, ProviderOptions, ProviderRegistry};
+pub use kubernetes::{KubernetesProvider, MANAGED_BY_LABEL};
+pub use kubernetes_doctor::{ClusterProblem, KubernetesDoctor, ResourceProfile, Severity};
+pub use local_cluster::{ClusterBackend, ClusterConfig, ClusterInfo};
+pub use retry::{retry_with_policy, retry_with_policy_stream, MessagePredicate, ResumableStream, RetryPolicy};
+pub use tunnel::{PortForward, TunnelHandle};
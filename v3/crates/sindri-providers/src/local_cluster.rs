@@ -0,0 +1,562 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sindri_core::PathResolver;
+
+use crate::retry::RetryPolicy;
+use crate::utils::run_command;
+use crate::ProviderError;
+
+const NGINX_INGRESS_KIND_MANIFEST: &str =
+    "https://raw.githubusercontent.com/kubernetes/ingress-nginx/main/deploy/static/provider/kind/deploy.yaml";
+const NGINX_INGRESS_CLOUD_MANIFEST: &str =
+    "https://raw.githubusercontent.com/kubernetes/ingress-nginx/main/deploy/static/provider/cloud/deploy.yaml";
+
+/// How long [`ClusterBackend::is_installed`]/[`ClusterConfig::exists`]
+/// trust a cached result before re-shelling out to `kind`/`k3d`. Short
+/// enough that a cluster this same process just created or destroyed is
+/// never missed by its own later check; long enough that a CLI command
+/// checking several things in sequence doesn't re-spawn the binary for
+/// each one.
+const CACHE_TTL: Duration = Duration::from_secs(5);
+
+struct CacheEntry<T> {
+    value: T,
+    cached_at: Instant,
+}
+
+impl<T> CacheEntry<T> {
+    fn is_fresh(&self) -> bool {
+        self.cached_at.elapsed() < CACHE_TTL
+    }
+}
+
+/// Process-wide cache backing [`ClusterBackend::is_installed`] and
+/// [`ClusterConfig::list_clusters`]. Deliberately process-local rather
+/// than persisted anywhere — it only ever needs to reflect what this
+/// run has already shelled out to discover.
+#[derive(Default)]
+struct DetectionCache {
+    installed: HashMap<ClusterBackend, CacheEntry<bool>>,
+    clusters: HashMap<ClusterBackend, CacheEntry<Vec<String>>>,
+}
+
+fn cache() -> &'static Mutex<DetectionCache> {
+    static CACHE: OnceLock<Mutex<DetectionCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(DetectionCache::default()))
+}
+
+/// Local cluster tooling [`ClusterConfig::create`] drives. Each maps to a
+/// different ingress wiring: Kind needs `extraPortMappings` baked into its
+/// cluster config at creation time, while k3d maps host ports straight to
+/// its built-in `servicelb` loadbalancer with `--port`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClusterBackend {
+    Kind,
+    K3d,
+}
+
+impl ClusterBackend {
+    fn binary(self) -> &'static str {
+        match self {
+            ClusterBackend::Kind => "kind",
+            ClusterBackend::K3d => "k3d",
+        }
+    }
+
+    /// The kubeconfig context name the backend's `cluster create` sets up
+    /// for a cluster named `name`.
+    fn context(self, name: &str) -> String {
+        match self {
+            ClusterBackend::Kind => format!("kind-{name}"),
+            ClusterBackend::K3d => format!("k3d-{name}"),
+        }
+    }
+
+    /// Whether this backend's CLI is on `PATH`, i.e. its `version`
+    /// subcommand runs successfully. Cached for [`CACHE_TTL`].
+    pub async fn is_installed(self) -> bool {
+        if let Some(entry) = cache().lock().unwrap().installed.get(&self) {
+            if entry.is_fresh() {
+                return entry.value;
+            }
+        }
+
+        let installed = tokio::process::Command::new(self.binary())
+            .arg("version")
+            .output()
+            .await
+            .is_ok_and(|output| output.status.success());
+
+        cache().lock().unwrap().installed.insert(self, CacheEntry { value: installed, cached_at: Instant::now() });
+        installed
+    }
+}
+
+/// Outcome of [`ClusterConfig::create`]: the cluster it made and, when an
+/// ingress controller was requested, where it's reachable from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterInfo {
+    pub backend: ClusterBackend,
+    pub name: String,
+    /// `http://localhost:<port>`, set only when [`ClusterConfig::with_ingress`]
+    /// was used.
+    pub ingress_address: Option<String>,
+    /// How many control-plane (server) nodes this cluster was created
+    /// with. Always `1` outside of [`ClusterConfig::with_servers`].
+    pub servers: u32,
+}
+
+/// A local Kind/k3d cluster to create, optionally with an nginx ingress
+/// controller installed and wired to host ports — so `http://localhost:<port>`
+/// reaches in-cluster Ingress resources without any manual loadbalancer
+/// setup. Ingress wiring is entirely skipped when not requested, keeping a
+/// plain `create()` as lean as running the backend's own `cluster create`.
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    pub backend: ClusterBackend,
+    pub name: String,
+    ingress_ports: Option<(u16, u16)>,
+    servers: u32,
+    retry_policy: RetryPolicy,
+}
+
+impl ClusterConfig {
+    pub fn new(backend: ClusterBackend, name: impl Into<String>) -> Self {
+        Self {
+            backend,
+            name: name.into(),
+            ingress_ports: None,
+            servers: 1,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Installs nginx ingress during [`Self::create`], mapping the
+    /// cluster's loadbalancer/hostPorts 80/443 to `http_port`/`https_port`
+    /// on the host.
+    pub fn with_ingress(mut self, http_port: u16, https_port: u16) -> Self {
+        self.ingress_ports = Some((http_port, https_port));
+        self
+    }
+
+    /// Runs `servers` control-plane nodes instead of one, for reproducing
+    /// HA-specific behavior (leader election, etcd quorum loss, ...)
+    /// locally. k3d-only — Kind's multi-control-plane story needs a
+    /// differently-shaped cluster config than the one [`Self::create`]
+    /// renders, so [`Self::create`] rejects this for [`ClusterBackend::Kind`].
+    /// An even count is rejected too: etcd needs an odd number of members
+    /// to keep quorum decidable.
+    pub fn with_servers(mut self, servers: u32) -> Self {
+        self.servers = servers;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    fn validate_servers(&self) -> Result<(), ProviderError> {
+        if self.servers == 0 {
+            return Err(ProviderError::CommandFailed("cluster must have at least 1 server".to_string()));
+        }
+        if self.servers > 1 {
+            if self.backend == ClusterBackend::Kind {
+                return Err(ProviderError::CommandFailed(
+                    "multiple control-plane nodes are only supported for k3d".to_string(),
+                ));
+            }
+            if self.servers.is_multiple_of(2) {
+                return Err(ProviderError::CommandFailed(format!(
+                    "{} servers would leave etcd without a decidable quorum; use an odd count",
+                    self.servers
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn command(&self) -> tokio::process::Command {
+        tokio::process::Command::new(self.backend.binary())
+    }
+
+    async fn run(&self, args: &[&str]) -> Result<String, ProviderError> {
+        run_command(
+            || {
+                let mut command = self.command();
+                command.args(args);
+                command
+            },
+            &self.retry_policy,
+        )
+        .await
+    }
+
+    pub async fn create(&self) -> Result<ClusterInfo, ProviderError> {
+        self.validate_servers()?;
+
+        let Some((http_port, https_port)) = self.ingress_ports else {
+            self.create_cluster().await?;
+            self.invalidate_cache();
+            return Ok(ClusterInfo {
+                backend: self.backend,
+                name: self.name.clone(),
+                ingress_address: None,
+                servers: self.servers,
+            });
+        };
+
+        match self.backend {
+            ClusterBackend::Kind => {
+                let config_path = std::env::temp_dir().join(format!("sindri-kind-{}.yaml", self.name));
+                tokio::fs::write(&config_path, kind_config(&self.name, http_port, https_port)).await?;
+                let config_path = config_path.to_string_lossy().into_owned();
+                self.run(&["create", "cluster", "--config", &config_path]).await?;
+                tokio::fs::remove_file(&config_path).await.ok();
+                self.install_ingress(NGINX_INGRESS_KIND_MANIFEST).await?;
+            }
+            ClusterBackend::K3d => {
+                let http_map = format!("{http_port}:80@loadbalancer");
+                let https_map = format!("{https_port}:443@loadbalancer");
+                let servers = self.servers.to_string();
+                let mut args = vec!["cluster", "create", &self.name, "--port", &http_map, "--port", &https_map];
+                if self.servers > 1 {
+                    args.push("--servers");
+                    args.push(&servers);
+                }
+                self.run(&args).await?;
+                self.install_ingress(NGINX_INGRESS_CLOUD_MANIFEST).await?;
+            }
+        }
+        self.invalidate_cache();
+
+        Ok(ClusterInfo {
+            backend: self.backend,
+            name: self.name.clone(),
+            ingress_address: Some(format!("http://localhost:{http_port}")),
+            servers: self.servers,
+        })
+    }
+
+    /// Destroys this cluster via the backend's own delete command.
+    pub async fn destroy(&self) -> Result<(), ProviderError> {
+        match self.backend {
+            ClusterBackend::Kind => self.run(&["delete", "cluster", "--name", &self.name]).await,
+            ClusterBackend::K3d => self.run(&["cluster", "delete", &self.name]).await,
+        }?;
+        self.invalidate_cache();
+        Ok(())
+    }
+
+    /// Whether a cluster named `self.name` already exists for
+    /// `self.backend`. Backed by the cached, per-backend cluster listing
+    /// — see [`Self::list_clusters`].
+    pub async fn exists(&self) -> Result<bool, ProviderError> {
+        Ok(self.list_clusters().await?.iter().any(|name| name == &self.name))
+    }
+
+    /// Lists cluster names known to `self.backend`, caching the result
+    /// for [`CACHE_TTL`] so repeated `exists` checks within one process
+    /// run don't re-spawn `kind`/`k3d` each time.
+    async fn list_clusters(&self) -> Result<Vec<String>, ProviderError> {
+        if let Some(entry) = cache().lock().unwrap().clusters.get(&self.backend) {
+            if entry.is_fresh() {
+                return Ok(entry.value.clone());
+            }
+        }
+
+        let output = match self.backend {
+            ClusterBackend::Kind => self.run(&["get", "clusters"]).await?,
+            ClusterBackend::K3d => self.run(&["cluster", "list", "--no-headers"]).await?,
+        };
+        let names = parse_cluster_names(&output);
+
+        cache()
+            .lock()
+            .unwrap()
+            .clusters
+            .insert(self.backend, CacheEntry { value: names.clone(), cached_at: Instant::now() });
+        Ok(names)
+    }
+
+    /// Drops the cached cluster listing for `self.backend`, so the next
+    /// `exists`/`list_clusters` call reflects a `create`/`destroy` this
+    /// process just made instead of a stale cache entry.
+    fn invalidate_cache(&self) {
+        cache().lock().unwrap().clusters.remove(&self.backend);
+    }
+
+    async fn create_cluster(&self) -> Result<(), ProviderError> {
+        match self.backend {
+            ClusterBackend::Kind => self.run(&["create", "cluster", "--name", &self.name]).await,
+            ClusterBackend::K3d => {
+                let servers = self.servers.to_string();
+                let mut args = vec!["cluster", "create", self.name.as_str()];
+                if self.servers > 1 {
+                    args.push("--servers");
+                    args.push(&servers);
+                }
+                self.run(&args).await
+            }
+        }
+        .map(|_| ())
+    }
+
+    async fn install_ingress(&self, manifest: &str) -> Result<(), ProviderError> {
+        let output = tokio::process::Command::new("kubectl")
+            .args(["--context", &self.backend.context(&self.name), "apply", "-f", manifest])
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(ProviderError::CommandFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Takes a k3s etcd snapshot of this cluster's state — the workloads
+    /// and cluster resources stored in its datastore — and copies it out
+    /// of the server node to local disk so [`Self::restore_snapshot`] can
+    /// bring the cluster back to this point later. This does **not**
+    /// capture mounted host volumes; anything a workload wrote there is
+    /// untouched by both `snapshot` and `restore_snapshot`.
+    ///
+    /// k3d-only, and only once the cluster is actually etcd-backed: a
+    /// single-server k3d cluster defaults to an embedded sqlite datastore,
+    /// which k3s's etcd-snapshot mechanism doesn't cover, so this errors
+    /// clearly instead of silently doing nothing useful. See
+    /// [`ClusterConfig::with_servers`].
+    pub async fn snapshot(&self, name: &str) -> Result<SnapshotId, ProviderError> {
+        self.ensure_etcd_backed()?;
+
+        let node = self.server_node();
+        self.docker(&["exec", &node, "k3s", "etcd-snapshot", "save", "--name", name]).await?;
+
+        let local_dir = snapshots_dir()?;
+        tokio::fs::create_dir_all(&local_dir).await?;
+        let local_path = local_dir.join(format!("{}-{name}", self.name));
+        let remote = format!("{node}:/var/lib/rancher/k3s/server/db/snapshots/{name}");
+        self.docker(&["cp", &remote, &local_path.to_string_lossy()]).await?;
+
+        Ok(SnapshotId { name: name.to_string(), local_path })
+    }
+
+    /// Restores this cluster's datastore from a snapshot taken by
+    /// [`Self::snapshot`], bringing its workloads back to that point.
+    /// Restarts the server node's `k3s` process to apply the restore, so
+    /// anything in flight against the cluster at the moment this runs is
+    /// disrupted.
+    pub async fn restore_snapshot(&self, id: &SnapshotId) -> Result<(), ProviderError> {
+        self.ensure_etcd_backed()?;
+
+        let node = self.server_node();
+        let remote_path = format!("/tmp/{}", id.name);
+        let remote = format!("{node}:{remote_path}");
+        self.docker(&["cp", &id.local_path.to_string_lossy(), &remote]).await?;
+        self.docker(&[
+            "exec",
+            &node,
+            "k3s",
+            "server",
+            "--cluster-reset",
+            &format!("--cluster-reset-restore-path={remote_path}"),
+        ])
+        .await?;
+        self.docker(&["restart", &node]).await?;
+        Ok(())
+    }
+
+    /// The container k3d names for this cluster's first control-plane
+    /// node, per k3d's own naming convention.
+    fn server_node(&self) -> String {
+        format!("k3d-{}-server-0", self.name)
+    }
+
+    /// Errors unless this is a k3d cluster built with more than one
+    /// server: that's the only configuration k3s actually runs etcd for
+    /// instead of its single-server sqlite datastore.
+    fn ensure_etcd_backed(&self) -> Result<(), ProviderError> {
+        if self.backend != ClusterBackend::K3d {
+            return Err(ProviderError::CommandFailed(
+                "etcd snapshots are only supported for k3d clusters".to_string(),
+            ));
+        }
+        if self.servers <= 1 {
+            return Err(ProviderError::CommandFailed(
+                "this cluster's datastore doesn't support snapshots: a single-server k3d cluster uses \
+                 embedded sqlite, not etcd; create it with with_servers(3) or higher"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn docker(&self, args: &[&str]) -> Result<String, ProviderError> {
+        let output = tokio::process::Command::new("docker").args(args).output().await?;
+        if !output.status.success() {
+            return Err(ProviderError::CommandFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+/// A k3s etcd snapshot taken by [`ClusterConfig::snapshot`], identifying
+/// both the name it was saved under inside the cluster and where its copy
+/// landed on local disk for [`ClusterConfig::restore_snapshot`] to read
+/// back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotId {
+    name: String,
+    local_path: PathBuf,
+}
+
+/// Where local k3d etcd snapshots are kept — alongside the rest of
+/// Sindri's on-disk state, resolved the same way every other on-disk path
+/// in the CLI is (`SINDRI_CONFIG_DIR`, then the platform default).
+fn snapshots_dir() -> Result<PathBuf, ProviderError> {
+    let resolver = PathResolver::resolve(None)
+        .map_err(|err| ProviderError::CommandFailed(format!("could not resolve snapshot directory: {err}")))?;
+    Ok(resolver.state_dir().join("k3d-snapshots"))
+}
+
+/// Extracts cluster names from `kind get clusters` (one name per line) or
+/// `k3d cluster list --no-headers` (name in the first whitespace-separated
+/// column) — both forms reduce to "first token of each line".
+fn parse_cluster_names(output: &str) -> Vec<String> {
+    output.lines().filter_map(|line| line.split_whitespace().next()).map(str::to_string).collect()
+}
+
+/// Minimal Kind cluster config mapping the ingress-ready control-plane
+/// node's 80/443 to `http_port`/`https_port` on the host, per Kind's own
+/// ingress guide.
+fn kind_config(name: &str, http_port: u16, https_port: u16) -> String {
+    format!(
+        "kind: Cluster\n\
+         apiVersion: kind.x-k8s.io/v1alpha4\n\
+         name: {name}\n\
+         nodes:\n\
+         - role: control-plane\n\
+         \x20\x20kubeadmConfigPatches:\n\
+         \x20\x20- |\n\
+         \x20\x20\x20\x20kind: InitConfiguration\n\
+         \x20\x20\x20\x20nodeRegistration:\n\
+         \x20\x20\x20\x20\x20\x20kubeletExtraArgs:\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20node-labels: \"ingress-ready=true\"\n\
+         \x20\x20extraPortMappings:\n\
+         \x20\x20- containerPort: 80\n\
+         \x20\x20\x20\x20hostPort: {http_port}\n\
+         \x20\x20\x20\x20protocol: TCP\n\
+         \x20\x20- containerPort: 443\n\
+         \x20\x20\x20\x20hostPort: {https_port}\n\
+         \x20\x20\x20\x20protocol: TCP\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_context_follows_the_kind_cli_convention() {
+        assert_eq!(ClusterBackend::Kind.context("dev"), "kind-dev");
+    }
+
+    #[test]
+    fn k3d_context_follows_the_k3d_cli_convention() {
+        assert_eq!(ClusterBackend::K3d.context("dev"), "k3d-dev");
+    }
+
+    #[test]
+    fn kind_config_maps_both_requested_host_ports() {
+        let config = kind_config("dev", 8080, 8443);
+        assert!(config.contains("hostPort: 8080"));
+        assert!(config.contains("hostPort: 8443"));
+        assert!(config.contains("name: dev"));
+    }
+
+    #[test]
+    fn parses_one_kind_cluster_name_per_line() {
+        assert_eq!(parse_cluster_names("dev\nstaging\n"), vec!["dev".to_string(), "staging".to_string()]);
+    }
+
+    #[test]
+    fn parses_the_name_column_from_k3d_list_output() {
+        let output = "dev       1/1       0/0      true\nstaging   1/1       0/0      true\n";
+        assert_eq!(parse_cluster_names(output), vec!["dev".to_string(), "staging".to_string()]);
+    }
+
+    #[test]
+    fn empty_output_has_no_clusters() {
+        assert!(parse_cluster_names("").is_empty());
+    }
+
+    #[test]
+    fn a_single_server_is_valid_for_either_backend() {
+        ClusterConfig::new(ClusterBackend::Kind, "dev").validate_servers().unwrap();
+        ClusterConfig::new(ClusterBackend::K3d, "dev").validate_servers().unwrap();
+    }
+
+    #[test]
+    fn zero_servers_is_rejected() {
+        let err = ClusterConfig::new(ClusterBackend::K3d, "dev").with_servers(0).validate_servers().unwrap_err();
+        assert!(err.to_string().contains("at least 1 server"));
+    }
+
+    #[test]
+    fn an_even_server_count_above_one_is_rejected_for_k3d() {
+        let err = ClusterConfig::new(ClusterBackend::K3d, "dev").with_servers(2).validate_servers().unwrap_err();
+        assert!(err.to_string().contains("quorum"));
+    }
+
+    #[test]
+    fn an_odd_server_count_above_one_is_accepted_for_k3d() {
+        ClusterConfig::new(ClusterBackend::K3d, "dev").with_servers(3).validate_servers().unwrap();
+    }
+
+    #[test]
+    fn multiple_servers_is_rejected_for_kind() {
+        let err = ClusterConfig::new(ClusterBackend::Kind, "dev").with_servers(3).validate_servers().unwrap_err();
+        assert!(err.to_string().contains("only supported for k3d"));
+    }
+
+    #[test]
+    fn server_node_follows_the_k3d_server_container_naming_convention() {
+        let config = ClusterConfig::new(ClusterBackend::K3d, "dev");
+        assert_eq!(config.server_node(), "k3d-dev-server-0");
+    }
+
+    #[test]
+    fn a_single_server_cluster_cannot_be_snapshotted() {
+        let err = ClusterConfig::new(ClusterBackend::K3d, "dev").ensure_etcd_backed().unwrap_err();
+        assert!(err.to_string().contains("sqlite"));
+    }
+
+    #[test]
+    fn kind_clusters_cannot_be_snapshotted() {
+        let err =
+            ClusterConfig::new(ClusterBackend::Kind, "dev").with_servers(3).ensure_etcd_backed().unwrap_err();
+        assert!(err.to_string().contains("only supported for k3d"));
+    }
+
+    #[test]
+    fn a_multi_server_k3d_cluster_can_be_snapshotted() {
+        ClusterConfig::new(ClusterBackend::K3d, "dev").with_servers(3).ensure_etcd_backed().unwrap();
+    }
+
+    #[test]
+    fn a_fresh_cache_entry_is_fresh_and_an_old_one_is_not() {
+        let fresh = CacheEntry { value: true, cached_at: Instant::now() };
+        assert!(fresh.is_fresh());
+
+        let stale = CacheEntry { value: true, cached_at: Instant::now() - CACHE_TTL - Duration::from_secs(1) };
+        assert!(!stale.is_fresh());
+    }
+}
@@ -0,0 +1,310 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::stream::{Stream, StreamExt};
+
+use crate::ProviderError;
+
+/// Matches provider error messages against known-transient substrings —
+/// a refused connection, a dropped TLS handshake, an HTTP 429 — so only
+/// those get retried. A bad argument or a "not found" fails immediately
+/// instead of being retried into a slower failure.
+#[derive(Debug, Clone)]
+pub struct MessagePredicate {
+    needles: Vec<String>,
+}
+
+impl MessagePredicate {
+    pub fn new(needles: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            needles: needles.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Substrings commonly seen in transient failures from `docker`/`fly`/
+    /// `kubectl`: a refused connection, a dropped TLS handshake, or a rate
+    /// limit response.
+    pub fn transient_defaults() -> Self {
+        Self::new(["connection refused", "TLS handshake", "429"])
+    }
+
+    pub fn matches(&self, message: &str) -> bool {
+        self.needles.iter().any(|needle| message.contains(needle.as_str()))
+    }
+}
+
+impl Default for MessagePredicate {
+    fn default() -> Self {
+        Self::transient_defaults()
+    }
+}
+
+/// Governs whether and how a provider command invocation is retried.
+/// [`Self::disabled`] runs the command exactly once, for CI environments
+/// that want fast failure over resilience.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub predicate: MessagePredicate,
+}
+
+impl RetryPolicy {
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            predicate: MessagePredicate::default(),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            predicate: MessagePredicate::default(),
+        }
+    }
+}
+
+/// Retries `operation` under `policy` as long as the error it returns
+/// matches `policy.predicate`, up to `policy.max_attempts` total tries,
+/// backing off by `policy.base_delay * attempt` between each.
+pub async fn retry_with_policy<T, F, Fut>(
+    policy: &RetryPolicy,
+    mut operation: F,
+) -> Result<T, ProviderError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ProviderError>>,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                let transient = policy.predicate.matches(&err.to_string());
+                if !transient || attempt >= policy.max_attempts {
+                    return Err(err);
+                }
+                tokio::time::sleep(policy.base_delay * attempt).await;
+            }
+        }
+    }
+}
+
+/// A boxed, type-erased stream produced by a [`retry_with_policy_stream`]
+/// connect factory, so callers don't need to name their concrete stream
+/// type (a download's response body, a log tail's line-by-line reader).
+pub type ResumableStream<T> = Pin<Box<dyn Stream<Item = Result<T, ProviderError>> + Send>>;
+
+/// Retries a streaming operation under `policy`, reconnecting from the
+/// last successfully-consumed position on a transient error instead of
+/// giving up on the whole stream. Would back a resumable download or a
+/// log-follow that can drop and reconnect mid-stream — this crate doesn't
+/// have either caller yet, so this is the retry primitive on its own,
+/// same as [`retry_with_policy`] was before anything in this crate used it.
+///
+/// `connect` is called with the cursor to resume from (`0` on the first
+/// attempt, then whatever `advance` has accumulated from every item
+/// consumed so far) and must produce a fresh stream starting there.
+/// `advance` turns each item into how far it moved the cursor (a byte
+/// count for a download chunk, `1` per line for a log tail). `on_item`
+/// receives each item as it's consumed; `on_reconnect` is called with the
+/// attempt number every time a transient error forces a reconnect — same
+/// role as [`retry_with_policy`]'s implicit retry, but observable since a
+/// reconnect can matter to a caller mid-stream (e.g. to print "reconnecting...").
+pub async fn retry_with_policy_stream<T, C, Fut>(
+    policy: &RetryPolicy,
+    mut connect: C,
+    advance: impl Fn(&T) -> u64,
+    mut on_item: impl FnMut(&T),
+    mut on_reconnect: impl FnMut(u32),
+) -> Result<(), ProviderError>
+where
+    C: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<ResumableStream<T>, ProviderError>>,
+{
+    let mut cursor = 0u64;
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+
+        let mut stream = match connect(cursor).await {
+            Ok(stream) => stream,
+            Err(err) => {
+                let transient = policy.predicate.matches(&err.to_string());
+                if !transient || attempt >= policy.max_attempts {
+                    return Err(err);
+                }
+                tokio::time::sleep(policy.base_delay * attempt).await;
+                on_reconnect(attempt);
+                continue;
+            }
+        };
+
+        let err = loop {
+            match stream.next().await {
+                None => return Ok(()),
+                Some(Ok(item)) => {
+                    cursor += advance(&item);
+                    on_item(&item);
+                }
+                Some(Err(err)) => break err,
+            }
+        };
+
+        let transient = policy.predicate.matches(&err.to_string());
+        if !transient || attempt >= policy.max_attempts {
+            return Err(err);
+        }
+        tokio::time::sleep(policy.base_delay * attempt).await;
+        on_reconnect(attempt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn retries_transient_failures_until_success() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            predicate: MessagePredicate::default(),
+        };
+        let mut calls = 0;
+        let result = retry_with_policy(&policy, || {
+            calls += 1;
+            async move {
+                if calls < 3 {
+                    Err(ProviderError::CommandFailed("connection refused".to_string()))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_transient_failures() {
+        let policy = RetryPolicy::default();
+        let mut calls = 0;
+        let result = retry_with_policy(&policy, || {
+            calls += 1;
+            async move { Err::<(), _>(ProviderError::CommandFailed("not found".to_string())) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_never_retries_even_transient_failures() {
+        let policy = RetryPolicy::disabled();
+        let mut calls = 0;
+        let result = retry_with_policy(&policy, || {
+            calls += 1;
+            async move { Err::<(), _>(ProviderError::CommandFailed("connection refused".to_string())) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    fn boxed<T: Send + 'static>(items: Vec<Result<T, ProviderError>>) -> ResumableStream<T> {
+        Box::pin(futures::stream::iter(items))
+    }
+
+    #[tokio::test]
+    async fn reconnects_from_the_last_successful_cursor_on_a_transient_mid_stream_error() {
+        let policy = RetryPolicy { max_attempts: 3, base_delay: Duration::from_millis(1), predicate: MessagePredicate::default() };
+        let mut connects = 0;
+        let mut received = Vec::new();
+
+        let result = retry_with_policy_stream(
+            &policy,
+            |cursor| {
+                connects += 1;
+                let attempt = connects;
+                async move {
+                    if attempt == 1 {
+                        Ok(boxed(vec![
+                            Ok(1),
+                            Ok(2),
+                            Err(ProviderError::CommandFailed("connection refused".to_string())),
+                        ]))
+                    } else {
+                        assert_eq!(cursor, 2, "should resume from the cursor the first attempt reached");
+                        Ok(boxed(vec![Ok(3)]))
+                    }
+                }
+            },
+            |_item: &i32| 1,
+            |item| received.push(*item),
+            |_attempt| {},
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(received, vec![1, 2, 3]);
+        assert_eq!(connects, 2);
+    }
+
+    #[tokio::test]
+    async fn a_non_transient_stream_error_is_not_retried() {
+        let policy = RetryPolicy::default();
+        let mut connects = 0;
+
+        let result = retry_with_policy_stream(
+            &policy,
+            |_cursor| {
+                connects += 1;
+                async move {
+                    Ok(boxed(vec![
+                        Ok(1),
+                        Err(ProviderError::CommandFailed("not found".to_string())),
+                    ]))
+                }
+            },
+            |_item: &i32| 1,
+            |_item| {},
+            |_attempt| {},
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(connects, 1);
+    }
+
+    #[tokio::test]
+    async fn a_transient_error_reconnecting_itself_is_retried_up_to_max_attempts() {
+        let policy = RetryPolicy { max_attempts: 2, base_delay: Duration::from_millis(1), predicate: MessagePredicate::default() };
+        let mut connects = 0;
+        let mut reconnects = 0;
+
+        let result: Result<(), _> = retry_with_policy_stream(
+            &policy,
+            |_cursor| {
+                connects += 1;
+                async move { Err(ProviderError::CommandFailed("connection refused".to_string())) }
+            },
+            |_item: &i32| 1,
+            |_item| {},
+            |_attempt| reconnects += 1,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(connects, 2);
+        assert_eq!(reconnects, 1);
+    }
+}
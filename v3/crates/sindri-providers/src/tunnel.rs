@@ -0,0 +1,102 @@
+use std::net::TcpListener;
+use std::str::FromStr;
+
+use crate::ProviderError;
+
+/// A single `local[:remote]` port forward requested via `sindri connect
+/// --tunnel`. A bare port (e.g. `9229`) forwards to the same port on the
+/// remote side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortForward {
+    pub local: u16,
+    pub remote: u16,
+}
+
+impl FromStr for PortForward {
+    type Err = ProviderError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || ProviderError::CommandFailed(format!("invalid tunnel spec {spec:?}, expected LOCAL[:REMOTE]"));
+
+        let (local, remote) = match spec.split_once(':') {
+            Some((local, remote)) => (local, remote),
+            None => (spec, spec),
+        };
+        let local: u16 = local.parse().map_err(|_| invalid())?;
+        let remote: u16 = remote.parse().map_err(|_| invalid())?;
+        if local == 0 || remote == 0 {
+            return Err(invalid());
+        }
+        Ok(Self { local, remote })
+    }
+}
+
+impl PortForward {
+    /// Checks the local port isn't already bound by something else, so a
+    /// conflicting tunnel fails fast with a clear message rather than
+    /// silently competing for the port later.
+    pub fn ensure_local_port_available(&self) -> Result<(), ProviderError> {
+        TcpListener::bind(("127.0.0.1", self.local))
+            .map(|_| ())
+            .map_err(|err| {
+                ProviderError::CommandFailed(format!(
+                    "local port {} is already in use: {err}",
+                    self.local
+                ))
+            })
+    }
+}
+
+/// An active set of port forwards. Tears the forwards down when dropped, so
+/// a tunnel never outlives the `connect` session that opened it.
+pub struct TunnelHandle {
+    children: Vec<tokio::process::Child>,
+}
+
+impl TunnelHandle {
+    pub fn new(children: Vec<tokio::process::Child>) -> Self {
+        Self { children }
+    }
+
+    /// A handle over no active forwarding processes — used when the
+    /// provider's native mechanism needs no extra process (e.g. ports
+    /// published at deploy time).
+    pub fn noop() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Drop for TunnelHandle {
+    fn drop(&mut self) {
+        for child in &mut self.children {
+            if let Some(id) = child.id() {
+                tracing::debug!(pid = id, "tearing down tunnel process");
+            }
+            let _ = child.start_kill();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_explicit_local_remote_pair() {
+        let forward: PortForward = "3000:3001".parse().unwrap();
+        assert_eq!(forward, PortForward { local: 3000, remote: 3001 });
+    }
+
+    #[test]
+    fn bare_port_forwards_to_itself() {
+        let forward: PortForward = "9229".parse().unwrap();
+        assert_eq!(forward, PortForward { local: 9229, remote: 9229 });
+    }
+
+    #[test]
+    fn rejects_non_numeric_or_zero_ports() {
+        assert!("abc".parse::<PortForward>().is_err());
+        assert!("0:100".parse::<PortForward>().is_err());
+        assert!("100:0".parse::<PortForward>().is_err());
+    }
+}
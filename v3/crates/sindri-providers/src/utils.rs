@@ -0,0 +1,135 @@
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::retry::{retry_with_policy, RetryPolicy};
+use crate::ProviderError;
+
+/// Runs the command freshly built by `build` on each attempt, retrying
+/// transient failures per `policy`. `build` is called again for every
+/// attempt rather than the command being reused, since a spawned
+/// [`Command`] can't be run twice.
+///
+/// Returns trimmed stdout on success, or the [`ProviderError`] variant
+/// [`classify_failure`] recognizes in stderr (falling back to
+/// [`ProviderError::CommandFailed`]) once retries are exhausted (or
+/// skipped, for a non-transient failure). A spawn failure because the
+/// provider's CLI isn't on `PATH` is reported as
+/// [`ProviderError::ToolNotInstalled`] rather than the generic
+/// [`ProviderError::Io`].
+pub(crate) async fn run_command(
+    mut build: impl FnMut() -> Command,
+    policy: &RetryPolicy,
+) -> Result<String, ProviderError> {
+    retry_with_policy(policy, || {
+        let mut command = build();
+        async move {
+            let program = command.as_std().get_program().to_string_lossy().into_owned();
+            let output = command.output().await.map_err(|source| classify_spawn_failure(program, source))?;
+            if !output.status.success() {
+                return Err(classify_failure(&output.stderr));
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+    })
+    .await
+}
+
+/// Spawns `command` with stdin/stdout piped, writes `stdin` to it, and
+/// returns its stdout verbatim — no UTF-8 decoding or trimming, since the
+/// payload may be a binary archive rather than text. Backs
+/// [`crate::Provider::exec`] implementations, which need the raw bytes
+/// [`run_command`] deliberately discards.
+///
+/// Writes `stdin` from a separate task so a large payload can't deadlock
+/// against a child that starts writing its own stdout before it has read
+/// all of its stdin.
+pub(crate) async fn run_piped(mut command: Command, stdin: Vec<u8>) -> Result<Vec<u8>, ProviderError> {
+    command.stdin(std::process::Stdio::piped());
+    command.stdout(std::process::Stdio::piped());
+    command.stderr(std::process::Stdio::piped());
+
+    let program = command.as_std().get_program().to_string_lossy().into_owned();
+    let mut child = command.spawn().map_err(|source| classify_spawn_failure(program, source))?;
+    let mut child_stdin = child.stdin.take().expect("stdin was requested as piped");
+    let write = tokio::spawn(async move { child_stdin.write_all(&stdin).await });
+
+    let output = child.wait_with_output().await?;
+    let _ = write.await;
+
+    if !output.status.success() {
+        return Err(classify_failure(&output.stderr));
+    }
+    Ok(output.stdout)
+}
+
+/// Reports a spawn failure as [`ProviderError::ToolNotInstalled`] when the
+/// OS couldn't find `program` at all, rather than the generic
+/// [`ProviderError::Io`] every other spawn failure (permissions, resource
+/// limits, ...) still gets.
+fn classify_spawn_failure(program: String, source: std::io::Error) -> ProviderError {
+    if source.kind() == std::io::ErrorKind::NotFound {
+        ProviderError::ToolNotInstalled { name: program, source }
+    } else {
+        ProviderError::Io(source)
+    }
+}
+
+/// Recognizes a handful of common failure shapes in a command's stderr —
+/// auth rejections, missing resources, quota/rate-limit errors — so
+/// callers can react to the failure kind instead of pattern-matching
+/// [`ProviderError::CommandFailed`]'s message. Anything that doesn't match
+/// falls back to [`ProviderError::CommandFailed`].
+///
+/// This is necessarily a heuristic: `docker`/`kubectl` don't give scripts a
+/// structured error code, only free-form text.
+fn classify_failure(stderr: &[u8]) -> ProviderError {
+    let message = String::from_utf8_lossy(stderr).trim().to_string();
+    let lower = message.to_lowercase();
+
+    if ["unauthorized", "authentication", "permission denied", "forbidden", "not logged in"]
+        .iter()
+        .any(|needle| lower.contains(needle))
+    {
+        ProviderError::AuthRequired(message)
+    } else if ["quota", "rate limit", "too many requests"].iter().any(|needle| lower.contains(needle)) {
+        ProviderError::QuotaExceeded(message)
+    } else if ["not found", "no such"].iter().any(|needle| lower.contains(needle)) {
+        ProviderError::ResourceNotFound(message)
+    } else {
+        ProviderError::CommandFailed(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_auth_failures() {
+        assert!(matches!(
+            classify_failure(b"Error: unauthorized: authentication required"),
+            ProviderError::AuthRequired(_)
+        ));
+    }
+
+    #[test]
+    fn classifies_missing_resources() {
+        assert!(matches!(
+            classify_failure(b"Error: No such container: dev-box"),
+            ProviderError::ResourceNotFound(_)
+        ));
+    }
+
+    #[test]
+    fn classifies_quota_errors() {
+        assert!(matches!(
+            classify_failure(b"Error: quota exceeded for this account"),
+            ProviderError::QuotaExceeded(_)
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_command_failed_for_unrecognized_stderr() {
+        assert!(matches!(classify_failure(b"boom"), ProviderError::CommandFailed(_)));
+    }
+}
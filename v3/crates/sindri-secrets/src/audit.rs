@@ -0,0 +1,250 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::SecretError;
+
+/// Default path a `sindri secrets rotate`/`audit` invocation logs to when
+/// the caller doesn't pass one explicitly.
+pub const DEFAULT_AUDIT_LOG_PATH: &str = "./sindri-secrets-audit.jsonl";
+
+/// One rotation attempt, appended to an [`AuditLog`] by
+/// [`crate::rotate_file`]/[`crate::rotate_vault`] regardless of whether it
+/// actually wrote a new value — a dry run is recorded too, so "what would
+/// rotate next" stays auditable.
+#[derive(Debug, Clone, Serialize)]
+pub struct RotationRecord {
+    pub secret: String,
+    pub source: String,
+    pub dry_run: bool,
+    pub verified: bool,
+    pub rotated_at_unix: u64,
+    /// [`crate::SecureString::fingerprint`] of the value rotated to, so
+    /// two entries for the same secret can be told apart without the
+    /// audit log ever holding the plaintext.
+    pub new_value_fingerprint: String,
+}
+
+/// When an [`AuditLog`] rolls its active file over, so a long-running
+/// deployment's log can't grow without bound. Rotated files are named
+/// `<path>.1` (most recent) through `<path>.<keep>` (oldest); once
+/// `keep` is reached the oldest is dropped.
+#[derive(Debug, Clone)]
+pub struct RotationPolicy {
+    /// Rotate once the active file reaches this size.
+    pub max_bytes: Option<u64>,
+    /// Rotate once the active file is older than this, measured from its
+    /// creation time. `None` on a platform/filesystem that doesn't
+    /// report file birth time simply disables age-based rotation rather
+    /// than erroring on every append.
+    pub max_age: Option<Duration>,
+    /// How many rotated files to retain. The active file doesn't count
+    /// toward this.
+    pub keep: usize,
+}
+
+impl RotationPolicy {
+    pub fn new(keep: usize) -> Self {
+        Self { max_bytes: None, max_age: None, keep }
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+}
+
+/// An append-only JSON-lines log of secret rotations, so "who rotated
+/// what and when" survives independent of whatever's currently
+/// configured. Optionally rolls its active file over under a
+/// [`RotationPolicy`] — rotation and append are serialized behind one
+/// lock, so a rotation can never land between a caller checking "should
+/// I rotate" and the in-flight entry it's about to write.
+pub struct AuditLog {
+    path: PathBuf,
+    rotation: Option<RotationPolicy>,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), rotation: None, lock: Mutex::new(()) }
+    }
+
+    pub fn with_rotation(mut self, policy: RotationPolicy) -> Self {
+        self.rotation = Some(policy);
+        self
+    }
+
+    /// Appends `record` as one JSON line, rotating the active file first
+    /// if the configured [`RotationPolicy`] says it's due.
+    pub fn append(&self, record: &RotationRecord) -> Result<(), SecretError> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.rotate_if_due()?;
+        self.append_locked(record)
+    }
+
+    /// Rotates the active file immediately, regardless of the configured
+    /// policy's thresholds — what `sindri secrets audit --rotate` forces.
+    /// Returns `false` without doing anything if there's no active file
+    /// yet to rotate.
+    pub fn force_rotate(&self) -> Result<bool, SecretError> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !self.path.exists() {
+            return Ok(false);
+        }
+        self.rotate()?;
+        Ok(true)
+    }
+
+    fn append_locked(&self, record: &RotationRecord) -> Result<(), SecretError> {
+        let mut line =
+            serde_json::to_string(record).map_err(|err| SecretError::Audit(err.to_string()))?;
+        line.push('\n');
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| SecretError::Audit(err.to_string()))?;
+        file.write_all(line.as_bytes()).map_err(|err| SecretError::Audit(err.to_string()))
+    }
+
+    fn rotate_if_due(&self) -> Result<(), SecretError> {
+        let Some(policy) = &self.rotation else { return Ok(()) };
+        let Ok(metadata) = std::fs::metadata(&self.path) else { return Ok(()) };
+
+        let due_by_size = policy.max_bytes.is_some_and(|max| metadata.len() >= max);
+        let due_by_age = policy
+            .max_age
+            .zip(metadata.created().ok())
+            .is_some_and(|(max, created)| created.elapsed().unwrap_or_default() >= max);
+
+        if due_by_size || due_by_age {
+            self.rotate()?;
+        }
+        Ok(())
+    }
+
+    /// Shifts `<path>.1..<path>.keep-1` up one slot (oldest first, so no
+    /// slot is ever overwritten before it's vacated), drops `<path>.keep`
+    /// if present, then renames the active file to `<path>.1`. Each step
+    /// is a single `rename`, atomic on the same filesystem, so a crash
+    /// mid-rotation leaves a consistent set of files rather than a
+    /// partially-truncated one.
+    fn rotate(&self) -> Result<(), SecretError> {
+        let keep = self.rotation.as_ref().map_or(1, |policy| policy.keep.max(1));
+
+        let oldest = self.rotated_path(keep);
+        if oldest.exists() {
+            std::fs::remove_file(&oldest).map_err(|err| SecretError::Audit(err.to_string()))?;
+        }
+        for generation in (1..keep).rev() {
+            let from = self.rotated_path(generation);
+            if from.exists() {
+                let to = self.rotated_path(generation + 1);
+                std::fs::rename(&from, &to).map_err(|err| SecretError::Audit(err.to_string()))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))
+            .map_err(|err| SecretError::Audit(err.to_string()))
+    }
+
+    fn rotated_path(&self, generation: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{generation}"));
+        PathBuf::from(name)
+    }
+}
+
+pub(crate) fn now_unix() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(rotated_at_unix: u64) -> RotationRecord {
+        RotationRecord {
+            secret: "PROD_DB".to_string(),
+            source: "vault".to_string(),
+            dry_run: false,
+            verified: true,
+            rotated_at_unix,
+            new_value_fingerprint: "deadbeef".to_string(),
+        }
+    }
+
+    #[test]
+    fn appends_one_json_line_per_record() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(tmp.path().join("audit.jsonl"));
+
+        log.append(&record(0)).unwrap();
+        log.append(&RotationRecord { dry_run: true, verified: false, rotated_at_unix: 1, ..record(1) })
+            .unwrap();
+
+        let contents = std::fs::read_to_string(tmp.path().join("audit.jsonl")).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+        assert!(contents.lines().next().unwrap().contains(r#""secret":"PROD_DB""#));
+    }
+
+    #[test]
+    fn rotates_once_the_size_threshold_is_crossed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("audit.jsonl");
+        let log = AuditLog::new(&path).with_rotation(RotationPolicy::new(2).with_max_bytes(1));
+
+        log.append(&record(0)).unwrap();
+        log.append(&record(1)).unwrap();
+
+        assert!(path.exists(), "a fresh active file should exist after rotating");
+        assert!(tmp.path().join("audit.jsonl.1").exists());
+        let active = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(active.lines().count(), 1);
+    }
+
+    #[test]
+    fn drops_the_oldest_retained_file_beyond_keep() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("audit.jsonl");
+        let log = AuditLog::new(&path).with_rotation(RotationPolicy::new(1).with_max_bytes(1));
+
+        log.append(&record(0)).unwrap(); // in active file
+        log.append(&record(1)).unwrap(); // rotates record 0 into .1
+        log.append(&record(2)).unwrap(); // rotates record 1 into .1, drops old .1
+
+        let first_generation = std::fs::read_to_string(tmp.path().join("audit.jsonl.1")).unwrap();
+        assert!(first_generation.contains(r#""rotated_at_unix":1"#));
+        assert!(!tmp.path().join("audit.jsonl.2").exists());
+    }
+
+    #[test]
+    fn force_rotate_returns_false_with_no_active_file_yet() {
+        let tmp = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(tmp.path().join("audit.jsonl"));
+        assert!(!log.force_rotate().unwrap());
+    }
+
+    #[test]
+    fn force_rotate_rolls_the_active_file_over_regardless_of_policy() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("audit.jsonl");
+        let log = AuditLog::new(&path);
+
+        log.append(&record(0)).unwrap();
+        assert!(log.force_rotate().unwrap());
+
+        assert!(!path.exists() || std::fs::read_to_string(&path).unwrap().is_empty());
+        assert!(tmp.path().join("audit.jsonl.1").exists());
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("secret {0} did not resolve from any configured source")]
+    Unresolved(String),
+
+    #[error("failed to read secret file {0}: {1}")]
+    SecretFile(PathBuf, String),
+
+    #[error("vault authentication failed: {0}")]
+    VaultAuth(String),
+
+    #[error("vault request failed: {0}")]
+    Vault(String),
+
+    #[error("failed to write rotation audit log: {0}")]
+    Audit(String),
+}
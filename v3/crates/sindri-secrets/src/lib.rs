@@ -0,0 +1,19 @@
+//! Secret resolution and safe-to-print previews.
+
+mod audit;
+mod error;
+mod interpolate;
+mod preview;
+mod resolver;
+mod rotate;
+mod secure_string;
+mod vault;
+
+pub use audit::{AuditLog, RotationPolicy, RotationRecord, DEFAULT_AUDIT_LOG_PATH};
+pub use error::SecretError;
+pub use interpolate::interpolate_secret_refs;
+pub use preview::{preview_secrets, SecretPreview};
+pub use resolver::{ResolvedFrom, ResolvedSecret, SecretResolver, SecretSource, SecretSpec};
+pub use rotate::{rotate_file, rotate_vault};
+pub use secure_string::SecureString;
+pub use vault::{VaultAuth, VaultSource};
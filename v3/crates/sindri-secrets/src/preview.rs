@@ -0,0 +1,68 @@
+use crate::{ResolvedFrom, SecretError, SecretResolver, SecretSpec};
+
+/// A resolved secret with its value replaced by a masked fingerprint —
+/// safe to print or log. See [`preview_secrets`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretPreview {
+    pub name: String,
+    pub resolved_from: ResolvedFrom,
+    /// First and last character of the value plus its length, e.g.
+    /// `"p***3 (len 12)"`. Never the value itself.
+    pub masked: String,
+}
+
+/// Resolves every `spec`, returning a [`SecretPreview`] for each rather
+/// than the raw value — so config can be verified (which source a
+/// secret actually came from) without ever printing it. Each resolved
+/// value is dropped (and so zeroized) as soon as its fingerprint is
+/// computed.
+pub fn preview_secrets(
+    resolver: &SecretResolver,
+    specs: &[SecretSpec],
+) -> Result<Vec<SecretPreview>, SecretError> {
+    specs
+        .iter()
+        .map(|spec| {
+            let resolved = resolver.resolve(spec)?;
+            let masked = mask(resolved.value.expose());
+            Ok(SecretPreview { name: spec.name.clone(), resolved_from: resolved.resolved_from, masked })
+        })
+        .collect()
+}
+
+fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    match chars.len() {
+        0 => "(empty, len 0)".to_string(),
+        1 => format!("{}*** (len 1)", chars[0]),
+        n => format!("{}***{} (len {n})", chars[0], chars[n - 1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSource;
+
+    #[test]
+    fn masks_to_first_and_last_character_plus_length() {
+        assert_eq!(mask("s3cr3t-value"), "s***e (len 12)");
+        assert_eq!(mask("x"), "x*** (len 1)");
+        assert_eq!(mask(""), "(empty, len 0)");
+    }
+
+    #[test]
+    fn never_surfaces_the_resolved_value() {
+        let spec = SecretSpec {
+            name: "PROD_DB".to_string(),
+            sources: vec![SecretSource::Literal("s3cr3t-value".to_string())],
+        };
+
+        let previews = preview_secrets(&SecretResolver::new(), &[spec]).unwrap();
+
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].name, "PROD_DB");
+        assert_eq!(previews[0].resolved_from, ResolvedFrom::Literal);
+        assert!(!previews[0].masked.contains("s3cr3t-value"));
+    }
+}
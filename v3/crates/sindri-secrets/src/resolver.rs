@@ -0,0 +1,213 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{SecretError, SecureString};
+
+/// A place a secret's value may come from, tried in the order listed on
+/// its [`SecretSpec`].
+#[derive(Debug, Clone)]
+pub enum SecretSource {
+    /// A value supplied directly, e.g. an explicit override.
+    Literal(String),
+    /// An environment variable, by name.
+    Env(String),
+    /// A single file's contents.
+    File(PathBuf),
+    /// Multiple files concatenated in order with `separator` between
+    /// each, e.g. a cert, key, and chain bundled into one PEM.
+    CompositeFile { paths: Vec<PathBuf>, separator: String },
+}
+
+/// Which source a [`SecretResolver`] actually pulled a secret's value
+/// from, reported back without the value itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedFrom {
+    Literal,
+    Env(String),
+    File(PathBuf),
+    CompositeFile(Vec<PathBuf>),
+}
+
+/// A named secret and the sources to try for it, in order — the first
+/// source that resolves wins.
+#[derive(Debug, Clone)]
+pub struct SecretSpec {
+    pub name: String,
+    pub sources: Vec<SecretSource>,
+}
+
+impl SecretSpec {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), sources: Vec::new() }
+    }
+}
+
+/// A secret's value and which source it came from. The value is a
+/// [`SecureString`], zeroized as soon as the caller is done with it.
+#[derive(Debug)]
+pub struct ResolvedSecret {
+    pub value: SecureString,
+    pub resolved_from: ResolvedFrom,
+}
+
+/// Resolves [`SecretSpec`]s against their configured sources.
+#[derive(Debug, Clone, Default)]
+pub struct SecretResolver;
+
+impl SecretResolver {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Resolves `spec` to its value and which source it came from. An
+    /// `Env` source whose variable isn't set is skipped rather than
+    /// treated as an error; a `File` or `CompositeFile` source whose
+    /// file is missing fails immediately, naming the file.
+    pub fn resolve(&self, spec: &SecretSpec) -> Result<ResolvedSecret, SecretError> {
+        for source in &spec.sources {
+            match source {
+                SecretSource::Literal(value) => {
+                    return Ok(ResolvedSecret {
+                        value: SecureString::new(value.clone()),
+                        resolved_from: ResolvedFrom::Literal,
+                    });
+                }
+                SecretSource::Env(var) => {
+                    if let Ok(value) = env::var(var) {
+                        return Ok(ResolvedSecret {
+                            value: SecureString::new(value),
+                            resolved_from: ResolvedFrom::Env(var.clone()),
+                        });
+                    }
+                }
+                SecretSource::File(path) => {
+                    let value = read_file(path)?;
+                    return Ok(ResolvedSecret {
+                        value: SecureString::new(value),
+                        resolved_from: ResolvedFrom::File(path.clone()),
+                    });
+                }
+                SecretSource::CompositeFile { paths, separator } => {
+                    let value = read_composite_file(paths, separator)?;
+                    return Ok(ResolvedSecret {
+                        value: SecureString::new(value),
+                        resolved_from: ResolvedFrom::CompositeFile(paths.clone()),
+                    });
+                }
+            }
+        }
+        Err(SecretError::Unresolved(spec.name.clone()))
+    }
+}
+
+fn read_file(path: &PathBuf) -> Result<String, SecretError> {
+    fs::read_to_string(path).map_err(|err| SecretError::SecretFile(path.clone(), err.to_string()))
+}
+
+fn read_composite_file(paths: &[PathBuf], separator: &str) -> Result<String, SecretError> {
+    let mut parts = Vec::with_capacity(paths.len());
+    for path in paths {
+        parts.push(read_file(path)?);
+    }
+    Ok(parts.join(separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_literal_source_wins_over_a_later_env_source() {
+        let spec = SecretSpec {
+            name: "PROD_DB".to_string(),
+            sources: vec![
+                SecretSource::Literal("s3cr3t".to_string()),
+                SecretSource::Env("PROD_DB_NEVER_READ".to_string()),
+            ],
+        };
+
+        let resolved = SecretResolver::new().resolve(&spec).unwrap();
+        assert_eq!(resolved.value.expose(), "s3cr3t");
+        assert_eq!(resolved.resolved_from, ResolvedFrom::Literal);
+    }
+
+    #[test]
+    fn falls_back_to_env_when_no_literal_is_configured() {
+        std::env::set_var("SINDRI_SECRETS_TEST_VAR", "from-env");
+        let spec = SecretSpec {
+            name: "PROD_DB".to_string(),
+            sources: vec![SecretSource::Env("SINDRI_SECRETS_TEST_VAR".to_string())],
+        };
+
+        let resolved = SecretResolver::new().resolve(&spec).unwrap();
+        assert_eq!(resolved.value.expose(), "from-env");
+        assert_eq!(
+            resolved.resolved_from,
+            ResolvedFrom::Env("SINDRI_SECRETS_TEST_VAR".to_string())
+        );
+        std::env::remove_var("SINDRI_SECRETS_TEST_VAR");
+    }
+
+    #[test]
+    fn reports_an_unresolved_secret_by_name() {
+        let spec = SecretSpec::new("PROD_DB");
+        let err = SecretResolver::new().resolve(&spec).unwrap_err();
+        assert!(matches!(err, SecretError::Unresolved(name) if name == "PROD_DB"));
+    }
+
+    #[test]
+    fn reads_a_single_file_source() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("token");
+        fs::write(&path, "file-contents").unwrap();
+
+        let spec = SecretSpec {
+            name: "TOKEN".to_string(),
+            sources: vec![SecretSource::File(path.clone())],
+        };
+
+        let resolved = SecretResolver::new().resolve(&spec).unwrap();
+        assert_eq!(resolved.value.expose(), "file-contents");
+        assert_eq!(resolved.resolved_from, ResolvedFrom::File(path));
+    }
+
+    #[test]
+    fn composite_file_concatenates_in_order_with_the_given_separator() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cert = tmp.path().join("cert.pem");
+        let key = tmp.path().join("key.pem");
+        fs::write(&cert, "CERT").unwrap();
+        fs::write(&key, "KEY").unwrap();
+
+        let spec = SecretSpec {
+            name: "TLS_BUNDLE".to_string(),
+            sources: vec![SecretSource::CompositeFile {
+                paths: vec![cert, key],
+                separator: "\n".to_string(),
+            }],
+        };
+
+        let resolved = SecretResolver::new().resolve(&spec).unwrap();
+        assert_eq!(resolved.value.expose(), "CERT\nKEY");
+    }
+
+    #[test]
+    fn composite_file_names_the_missing_component() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cert = tmp.path().join("cert.pem");
+        let missing = tmp.path().join("chain.pem");
+        fs::write(&cert, "CERT").unwrap();
+
+        let spec = SecretSpec {
+            name: "TLS_BUNDLE".to_string(),
+            sources: vec![SecretSource::CompositeFile {
+                paths: vec![cert, missing.clone()],
+                separator: "\n".to_string(),
+            }],
+        };
+
+        let err = SecretResolver::new().resolve(&spec).unwrap_err();
+        assert!(matches!(err, SecretError::SecretFile(path, _) if path == missing));
+    }
+}
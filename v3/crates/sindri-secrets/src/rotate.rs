@@ -0,0 +1,146 @@
+use crate::audit::now_unix;
+use crate::{
+    AuditLog, RotationRecord, SecretError, SecretResolver, SecretSpec, SecretSource, SecureString,
+    VaultSource,
+};
+
+/// Rotates a file-backed secret: overwrites `spec`'s first
+/// [`SecretSource::File`] with `new_value`, then re-resolves `spec` to
+/// confirm the new value reads back. Records the attempt in `audit`
+/// whether or not `dry_run` actually wrote anything.
+///
+/// This covers the file-backed half of rotation; there's no S3/AWS
+/// Secrets Manager source in this crate yet, so that backend isn't
+/// implemented. Restarting or redeploying whatever consumes the secret
+/// is deliberately left to the caller (e.g. `sindri deploy`) — this
+/// layer only owns the secret's value, not its consumers.
+pub fn rotate_file(
+    spec: &SecretSpec,
+    new_value: &str,
+    audit: &AuditLog,
+    dry_run: bool,
+) -> Result<RotationRecord, SecretError> {
+    let path = spec
+        .sources
+        .iter()
+        .find_map(|source| match source {
+            SecretSource::File(path) => Some(path.clone()),
+            _ => None,
+        })
+        .ok_or_else(|| SecretError::Unresolved(spec.name.clone()))?;
+
+    if !dry_run {
+        std::fs::write(&path, new_value)
+            .map_err(|err| SecretError::SecretFile(path.clone(), err.to_string()))?;
+    }
+
+    let verified = !dry_run
+        && SecretResolver::new()
+            .resolve(spec)
+            .map(|resolved| resolved.value.ct_eq(&SecureString::new(new_value)))
+            .unwrap_or(false);
+
+    let record = RotationRecord {
+        secret: spec.name.clone(),
+        source: format!("file:{}", path.display()),
+        dry_run,
+        verified,
+        rotated_at_unix: now_unix(),
+        new_value_fingerprint: SecureString::new(new_value).fingerprint(),
+    };
+    audit.append(&record)?;
+    Ok(record)
+}
+
+/// Rotates a Vault-backed secret: writes `new_value` for `key` into
+/// `path`'s KV v2 data as a new version, then re-reads it to confirm
+/// resolution returns the new value. Records the attempt in `audit`
+/// whether or not `dry_run` actually wrote anything. See [`rotate_file`]
+/// for what this rotation flow deliberately doesn't cover.
+pub async fn rotate_vault(
+    vault: &VaultSource,
+    path: &str,
+    key: &str,
+    new_value: &str,
+    audit: &AuditLog,
+    dry_run: bool,
+) -> Result<RotationRecord, SecretError> {
+    if !dry_run {
+        vault.write(path, key, new_value).await?;
+    }
+
+    let verified = if dry_run {
+        false
+    } else {
+        vault
+            .resolve(path, key)
+            .await
+            .map(|value| SecureString::new(value).ct_eq(&SecureString::new(new_value)))
+            .unwrap_or(false)
+    };
+
+    let record = RotationRecord {
+        secret: format!("{path}#{key}"),
+        source: "vault".to_string(),
+        dry_run,
+        verified,
+        rotated_at_unix: now_unix(),
+        new_value_fingerprint: SecureString::new(new_value).fingerprint(),
+    };
+    audit.append(&record)?;
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_a_file_secret_and_verifies_the_new_value_reads_back() {
+        let tmp = tempfile::tempdir().unwrap();
+        let secret_path = tmp.path().join("token");
+        std::fs::write(&secret_path, "old-value").unwrap();
+
+        let spec = SecretSpec {
+            name: "TOKEN".to_string(),
+            sources: vec![SecretSource::File(secret_path.clone())],
+        };
+        let audit = AuditLog::new(tmp.path().join("audit.jsonl"));
+
+        let record = rotate_file(&spec, "new-value", &audit, false).unwrap();
+        assert!(record.verified);
+        assert_eq!(std::fs::read_to_string(&secret_path).unwrap(), "new-value");
+    }
+
+    #[test]
+    fn a_dry_run_does_not_write_but_is_still_audited() {
+        let tmp = tempfile::tempdir().unwrap();
+        let secret_path = tmp.path().join("token");
+        std::fs::write(&secret_path, "old-value").unwrap();
+
+        let spec = SecretSpec {
+            name: "TOKEN".to_string(),
+            sources: vec![SecretSource::File(secret_path.clone())],
+        };
+        let audit_path = tmp.path().join("audit.jsonl");
+        let audit = AuditLog::new(&audit_path);
+
+        let record = rotate_file(&spec, "new-value", &audit, true).unwrap();
+        assert!(!record.verified);
+        assert!(record.dry_run);
+        assert_eq!(std::fs::read_to_string(&secret_path).unwrap(), "old-value");
+        assert!(audit_path.is_file());
+    }
+
+    #[test]
+    fn a_spec_with_no_file_source_cannot_be_rotated_as_a_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spec = SecretSpec {
+            name: "TOKEN".to_string(),
+            sources: vec![SecretSource::Env("TOKEN_VAR".to_string())],
+        };
+        let audit = AuditLog::new(tmp.path().join("audit.jsonl"));
+        let err = rotate_file(&spec, "new-value", &audit, false).unwrap_err();
+        assert!(matches!(err, SecretError::Unresolved(name) if name == "TOKEN"));
+    }
+}
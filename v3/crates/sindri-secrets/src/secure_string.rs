@@ -0,0 +1,107 @@
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// A string value that's zeroized on drop, so it doesn't linger in
+/// memory once a caller is done with it. Its `Debug` impl never prints
+/// the value, so an accidental `{:?}` in a log line can't leak it.
+pub struct SecureString(String);
+
+impl SecureString {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Compares two values without short-circuiting on the first byte
+    /// that differs, so an attacker timing repeated `ct_eq` calls can't
+    /// learn how many leading bytes matched. This is a narrower guarantee
+    /// than full constant-time comparison: the length check is a plain
+    /// `==` (not constant-time — lengths aren't usually secret), and the
+    /// byte-diffing loop only walks the shorter of the two lengths, which
+    /// is safe precisely because that length mismatch already fails the
+    /// comparison on its own. Use this instead of `==`/`expose() ==
+    /// expose()` whenever a secret's value is compared, e.g. to tell
+    /// whether a rotation actually changed it.
+    pub fn ct_eq(&self, other: &SecureString) -> bool {
+        let (a, b) = (self.0.as_bytes(), other.0.as_bytes());
+        let len_matches = a.len() == b.len();
+
+        // Always compare over the shorter length so this never indexes
+        // out of bounds; a length mismatch alone already fails below.
+        let mut diff = (a.len() != b.len()) as u8;
+        for i in 0..a.len().min(b.len()) {
+            diff |= a[i] ^ b[i];
+        }
+        len_matches && diff == 0
+    }
+
+    /// A short, non-reversible fingerprint of the value — the first 8
+    /// hex characters of its SHA-256 digest. Safe to log or display: it
+    /// identifies a value well enough to tell two rotations apart without
+    /// exposing anything that could be reversed back to the plaintext.
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::digest(self.0.as_bytes());
+        digest.iter().take(4).map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+impl std::fmt::Debug for SecureString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecureString(REDACTED)")
+    }
+}
+
+impl Drop for SecureString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_never_prints_the_value() {
+        let secret = SecureString::new("s3cr3t-value");
+        assert_eq!(format!("{secret:?}"), "SecureString(REDACTED)");
+    }
+
+    #[test]
+    fn expose_returns_the_underlying_value() {
+        let secret = SecureString::new("s3cr3t-value");
+        assert_eq!(secret.expose(), "s3cr3t-value");
+    }
+
+    #[test]
+    fn ct_eq_matches_equal_values_of_the_same_length() {
+        assert!(SecureString::new("s3cr3t-value").ct_eq(&SecureString::new("s3cr3t-value")));
+    }
+
+    #[test]
+    fn ct_eq_rejects_a_single_differing_byte() {
+        assert!(!SecureString::new("s3cr3t-value").ct_eq(&SecureString::new("s3cr3t-valup")));
+    }
+
+    #[test]
+    fn ct_eq_rejects_differing_lengths() {
+        assert!(!SecureString::new("short").ct_eq(&SecureString::new("much-longer-value")));
+    }
+
+    #[test]
+    fn fingerprint_is_stable_and_never_the_plaintext() {
+        let secret = SecureString::new("s3cr3t-value");
+        let fingerprint = secret.fingerprint();
+        assert_eq!(fingerprint.len(), 8);
+        assert_eq!(fingerprint, secret.fingerprint());
+        assert!(!fingerprint.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn fingerprint_differs_for_different_values() {
+        assert_ne!(SecureString::new("a").fingerprint(), SecureString::new("b").fingerprint());
+    }
+}
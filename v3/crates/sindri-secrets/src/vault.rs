@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::SecretError;
+
+/// How close to a cached token's expiry [`VaultSource`] renews it early,
+/// so a long `resolve_all` never hands out a token that expires mid-batch.
+const RENEWAL_SKEW: Duration = Duration::from_secs(30);
+
+/// How a [`VaultSource`] authenticates to Vault.
+#[derive(Debug, Clone)]
+pub enum VaultAuth {
+    /// A token read directly, e.g. from `VAULT_TOKEN`. Never renewed —
+    /// the caller owns its lifecycle.
+    Token(String),
+    /// AppRole credentials, exchanged for a token via
+    /// `auth/approle/login` and cached until near expiry.
+    AppRole { role_id: String, secret_id: String },
+}
+
+impl VaultAuth {
+    /// Builds a `VaultAuth` from the environment: AppRole via
+    /// `VAULT_ROLE_ID`/`VAULT_SECRET_ID` if both are set, falling back to
+    /// a plain `VAULT_TOKEN`.
+    pub fn from_env() -> Result<Self, SecretError> {
+        if let (Ok(role_id), Ok(secret_id)) =
+            (std::env::var("VAULT_ROLE_ID"), std::env::var("VAULT_SECRET_ID"))
+        {
+            return Ok(Self::AppRole { role_id, secret_id });
+        }
+        std::env::var("VAULT_TOKEN").map(Self::Token).map_err(|_| {
+            SecretError::VaultAuth(
+                "no VAULT_TOKEN or VAULT_ROLE_ID/VAULT_SECRET_ID set".to_string(),
+            )
+        })
+    }
+}
+
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginResponse {
+    auth: LoginAuth,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginAuth {
+    client_token: String,
+    lease_duration: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct KvV2Response {
+    data: KvV2Data,
+}
+
+#[derive(Debug, Deserialize)]
+struct KvV2Data {
+    data: BTreeMap<String, String>,
+}
+
+/// Resolves secrets from a HashiCorp Vault KV v2 mount, supporting Vault
+/// Enterprise namespaces and AppRole authentication, with a plain
+/// token-from-env path kept as a fallback. A token obtained via AppRole
+/// is cached and transparently renewed as it nears expiry, so a long
+/// [`resolve_all`](Self::resolve_all) re-authenticates at most once.
+pub struct VaultSource {
+    address: String,
+    namespace: Option<String>,
+    auth: VaultAuth,
+    http: reqwest::Client,
+    cached_token: Mutex<Option<CachedToken>>,
+}
+
+impl VaultSource {
+    pub fn new(address: impl Into<String>, namespace: Option<String>, auth: VaultAuth) -> Self {
+        Self {
+            address: address.into(),
+            namespace,
+            auth,
+            http: sindri_core::build_http_client(),
+            cached_token: Mutex::new(None),
+        }
+    }
+
+    fn namespaced(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.namespace {
+            Some(namespace) => builder.header("X-Vault-Namespace", namespace),
+            None => builder,
+        }
+    }
+
+    /// Returns a valid token, reusing the cached one unless it's missing
+    /// or within [`RENEWAL_SKEW`] of expiry.
+    async fn token(&self) -> Result<String, SecretError> {
+        let mut cached = self.cached_token.lock().await;
+        if let Some(existing) = cached.as_ref() {
+            if existing.expires_at > Instant::now() + RENEWAL_SKEW {
+                return Ok(existing.token.clone());
+            }
+        }
+
+        match &self.auth {
+            VaultAuth::Token(token) => Ok(token.clone()),
+            VaultAuth::AppRole { role_id, secret_id } => {
+                let fetched = self.login_with_approle(role_id, secret_id).await?;
+                let token = fetched.token.clone();
+                *cached = Some(fetched);
+                Ok(token)
+            }
+        }
+    }
+
+    async fn login_with_approle(
+        &self,
+        role_id: &str,
+        secret_id: &str,
+    ) -> Result<CachedToken, SecretError> {
+        let url = format!("{}/v1/auth/approle/login", self.address);
+        let response: LoginResponse = self
+            .namespaced(self.http.post(&url))
+            .json(&serde_json::json!({ "role_id": role_id, "secret_id": secret_id }))
+            .send()
+            .await
+            .map_err(|err| SecretError::VaultAuth(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SecretError::VaultAuth(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| SecretError::VaultAuth(err.to_string()))?;
+
+        Ok(CachedToken {
+            token: response.auth.client_token,
+            expires_at: Instant::now() + Duration::from_secs(response.auth.lease_duration),
+        })
+    }
+
+    /// Confirms Vault can be authenticated to before resolving any
+    /// secret, surfacing an AppRole or token failure up front rather
+    /// than on the first [`resolve`](Self::resolve).
+    pub async fn validate(&self) -> Result<(), SecretError> {
+        self.token().await.map(|_| ())
+    }
+
+    /// Reads `key` out of `path`'s KV v2 data, authenticating (and
+    /// renewing the cached token if it's near expiry) as needed.
+    pub async fn resolve(&self, path: &str, key: &str) -> Result<String, SecretError> {
+        let token = self.token().await?;
+        let url = format!("{}/v1/{path}", self.address);
+        let response: KvV2Response = self
+            .namespaced(self.http.get(&url))
+            .header("X-Vault-Token", token)
+            .send()
+            .await
+            .map_err(|err| SecretError::Vault(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SecretError::Vault(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| SecretError::Vault(err.to_string()))?;
+
+        response
+            .data
+            .data
+            .get(key)
+            .cloned()
+            .ok_or_else(|| SecretError::Unresolved(format!("{path}#{key}")))
+    }
+
+    /// Writes `key`/`value` into `path`'s KV v2 data as a new version.
+    /// KV v2 writes replace a version's whole `data` map, so this reads
+    /// the current version first and merges `key` into it — otherwise
+    /// rotating one field would silently drop every other field in the
+    /// secret.
+    pub async fn write(&self, path: &str, key: &str, value: &str) -> Result<(), SecretError> {
+        let token = self.token().await?;
+        let url = format!("{}/v1/{path}", self.address);
+
+        let mut data = match self
+            .namespaced(self.http.get(&url))
+            .header("X-Vault-Token", token.clone())
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => {
+                let body: KvV2Response = response
+                    .json()
+                    .await
+                    .map_err(|err| SecretError::Vault(err.to_string()))?;
+                body.data.data
+            }
+            _ => BTreeMap::new(),
+        };
+        data.insert(key.to_string(), value.to_string());
+
+        self.namespaced(self.http.post(&url))
+            .header("X-Vault-Token", token)
+            .json(&serde_json::json!({ "data": data }))
+            .send()
+            .await
+            .map_err(|err| SecretError::Vault(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SecretError::Vault(err.to_string()))?;
+        Ok(())
+    }
+
+    /// Resolves each `(path, key)` pair in order, reusing and renewing
+    /// one cached token across the whole batch instead of
+    /// re-authenticating per secret.
+    pub async fn resolve_all(
+        &self,
+        refs: &[(String, String)],
+    ) -> Result<Vec<String>, SecretError> {
+        let mut values = Vec::with_capacity(refs.len());
+        for (path, key) in refs {
+            values.push(self.resolve(path, key).await?);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These share process-wide env vars, so they run sequentially inside
+    // one test rather than as separate #[test] fns that `cargo test`
+    // could interleave.
+    #[test]
+    fn resolves_auth_from_the_environment_in_priority_order() {
+        std::env::remove_var("VAULT_ROLE_ID");
+        std::env::remove_var("VAULT_SECRET_ID");
+        std::env::remove_var("VAULT_TOKEN");
+        assert!(matches!(VaultAuth::from_env(), Err(SecretError::VaultAuth(_))));
+
+        std::env::set_var("VAULT_TOKEN", "s.plain-token");
+        assert!(matches!(VaultAuth::from_env(), Ok(VaultAuth::Token(token)) if token == "s.plain-token"));
+
+        std::env::set_var("VAULT_ROLE_ID", "role-123");
+        std::env::set_var("VAULT_SECRET_ID", "secret-456");
+        assert!(matches!(VaultAuth::from_env(), Ok(VaultAuth::AppRole { .. })));
+
+        std::env::remove_var("VAULT_ROLE_ID");
+        std::env::remove_var("VAULT_SECRET_ID");
+        std::env::remove_var("VAULT_TOKEN");
+    }
+}
@@ -0,0 +1,150 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use sindri_backup::{BackupRow, RetentionWindow};
+use sindri_core::OutputFormat;
+use sindri_providers::{CloudProvider, DockerProvider, KubernetesProvider, Provider};
+
+#[derive(Args)]
+pub struct BackupArgs {
+    #[command(subcommand)]
+    pub command: BackupCommand,
+}
+
+#[derive(Subcommand)]
+pub enum BackupCommand {
+    /// List backups under a directory, newest last.
+    List {
+        dir: PathBuf,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Delete backups outside a retention window or beyond a count.
+    Prune {
+        dir: PathBuf,
+
+        /// Delete backups created before this Unix timestamp (seconds).
+        #[arg(long)]
+        since: Option<u64>,
+
+        /// Delete backups created after this Unix timestamp (seconds).
+        #[arg(long)]
+        until: Option<u64>,
+
+        /// Always retain the N most recent backups, regardless of window.
+        #[arg(long)]
+        keep: Option<usize>,
+
+        /// Report what would be deleted without deleting anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Archive a directory on a running deployment without a manual
+    /// `scp`, by running `tar` on it via the provider's native exec and
+    /// streaming the result back.
+    ToProvider {
+        /// Cloud the deployment is running on (`docker`, `kubernetes`).
+        /// Other providers don't implement remote exec yet.
+        #[arg(long)]
+        provider: String,
+
+        /// Container name (docker) or pod name (kubernetes) to exec into.
+        #[arg(long)]
+        target: String,
+
+        /// Kubernetes namespace `target` lives in. Ignored for other
+        /// providers.
+        #[arg(long, default_value = "default")]
+        namespace: String,
+
+        /// Directory on the remote to archive.
+        #[arg(long)]
+        remote_dir: String,
+
+        /// Local path to write the resulting `.tar.gz` to.
+        out_file: PathBuf,
+    },
+}
+
+pub async fn run(args: BackupArgs) -> anyhow::Result<()> {
+    match args.command {
+        BackupCommand::List { dir, output } => list(&dir, output),
+        BackupCommand::Prune {
+            dir,
+            since,
+            until,
+            keep,
+            dry_run,
+        } => prune(&dir, since, until, keep, dry_run),
+        BackupCommand::ToProvider { provider, target, namespace, remote_dir, out_file } => {
+            to_provider(&provider, &target, &namespace, &remote_dir, &out_file).await
+        }
+    }
+}
+
+async fn to_provider(
+    provider: &str,
+    target: &str,
+    namespace: &str,
+    remote_dir: &str,
+    out_file: &std::path::Path,
+) -> anyhow::Result<()> {
+    let provider: Box<dyn Provider> = match parse_cloud(provider)? {
+        CloudProvider::Docker => Box::new(DockerProvider::new(None)),
+        CloudProvider::K3d => Box::new(KubernetesProvider::new(namespace, None)),
+        other => anyhow::bail!("{other} does not support remote exec yet"),
+    };
+
+    sindri_backup::backup_to_provider(provider.as_ref(), target, remote_dir, out_file).await?;
+    tracing::info!(target, remote_dir, out_file = %out_file.display(), "archived remote directory");
+    Ok(())
+}
+
+fn parse_cloud(provider: &str) -> anyhow::Result<CloudProvider> {
+    Ok(match provider {
+        "docker" => CloudProvider::Docker,
+        "kubernetes" | "k8s" => CloudProvider::K3d,
+        other => anyhow::bail!("unknown cloud provider {other:?}"),
+    })
+}
+
+fn list(dir: &std::path::Path, output: OutputFormat) -> anyhow::Result<()> {
+    let rows: Vec<BackupRow> = sindri_backup::list_backups(dir)?
+        .into_iter()
+        .map(|entry| BackupRow {
+            dir: entry.dir.display().to_string(),
+            created_at: entry.manifest.created_at,
+            extensions: entry.manifest.extensions.len(),
+            uncompressed_bytes: entry.manifest.uncompressed_bytes,
+        })
+        .collect();
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+fn prune(
+    dir: &std::path::Path,
+    since: Option<u64>,
+    until: Option<u64>,
+    keep: Option<usize>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let window = RetentionWindow {
+        since,
+        until,
+        keep_last: keep,
+    };
+    let report = sindri_backup::prune_backups(dir, &window, dry_run)?;
+
+    if report.removed.is_empty() {
+        tracing::info!("nothing to prune");
+        return Ok(());
+    }
+
+    let verb = if dry_run { "would remove" } else { "removed" };
+    for removed in &report.removed {
+        tracing::info!(backup = %removed.display(), "{verb}");
+    }
+    tracing::info!(freed_bytes = report.freed_bytes, count = report.removed.len(), "{verb} backups");
+    Ok(())
+}
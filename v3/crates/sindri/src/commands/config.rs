@@ -0,0 +1,317 @@
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+use sindri_core::{multi_target_config_json_schema, LintSeverity, MultiTargetConfig, TemplateContext};
+use sindri_providers::{create_provider, create_provider_with, CloudProvider, ProviderOptions};
+
+use super::{to_retry_policy, TargetSelectorArgs};
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Render a provider's native artifact (compose file, `fly.toml`, k8s
+    /// manifests, an API request payload, ...) without deploying, as an
+    /// escape hatch to the underlying tooling.
+    ///
+    /// Either pass `--provider` (and optionally `--name`) directly, or
+    /// pass `--config`/`--target` together to pick a named target out of
+    /// a multi-target [`MultiTargetConfig`] — the provider, name, and
+    /// resource settings then come from that file instead.
+    Export {
+        /// Cloud provider to render the artifact for. Mutually exclusive
+        /// with `--config`/`--target`.
+        #[arg(long, conflicts_with_all = ["config", "target"])]
+        provider: Option<String>,
+
+        #[command(flatten)]
+        target_selector: TargetSelectorArgs,
+
+        /// Directory to write the rendered artifact(s) into. Created if
+        /// missing.
+        #[arg(long, default_value = ".")]
+        out_dir: PathBuf,
+
+        /// Deployment name, threaded into the rendered template as `name`.
+        /// Ignored when `--config`/`--target` supply one instead.
+        #[arg(long, default_value = "sindri-dev")]
+        name: String,
+    },
+
+    /// Walk through the fields `export` renders from — provider, name,
+    /// profile, memory, CPUs — and render the result, same as `export`.
+    ///
+    /// This is a friendlier front end onto plain `--provider` `export`,
+    /// not onto a persisted `sindri.yaml`; to author a multi-target
+    /// `sindri.yaml` by hand instead, see `schema` for its shape and
+    /// `export --config`/`--target` to render from one. Any field passed
+    /// on the command line is used as-is and never prompted for. When
+    /// stdin isn't a terminal (e.g. piped or run from CI), prompting is
+    /// skipped entirely and every field falls back to the same default
+    /// `export` already uses on its own.
+    Init {
+        /// Cloud provider to render the artifact for. Prompted for when
+        /// omitted and stdin is a terminal.
+        #[arg(long)]
+        provider: Option<String>,
+
+        /// Deployment name, threaded into the rendered template as `name`.
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Profile, threaded into the rendered template as `profile`.
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Memory limit, threaded into the rendered template as `memory`.
+        #[arg(long)]
+        memory: Option<String>,
+
+        /// CPU count, threaded into the rendered template as `cpus`.
+        #[arg(long)]
+        cpus: Option<u32>,
+
+        /// Directory to write the rendered artifact(s) into. Created if
+        /// missing.
+        #[arg(long, default_value = ".")]
+        out_dir: PathBuf,
+    },
+
+    /// Print the JSON Schema for a multi-target `sindri.yaml`
+    /// ([`MultiTargetConfig`]: `name`, `common`, `targets`), for pointing
+    /// an editor's YAML LSP at. The `retry` bounds match exactly what
+    /// [`MultiTargetConfig::resolve`] enforces at load time, so an
+    /// editor catches an invalid override before a `deploy`/`export
+    /// --config` would reject it.
+    Schema,
+
+    /// Check a `sindri.yaml` for semantic foot-guns a schema can't catch
+    /// (no targets declared, a target naming a provider this build can't
+    /// deploy to, a retry override that would fail
+    /// [`MultiTargetConfig::resolve`]), at author time rather than deep
+    /// into a `deploy`.
+    ///
+    /// This is scoped to what a [`MultiTargetConfig`] alone can say
+    /// something about; for semantic rules over an extension `Profile`
+    /// instead, see `sindri profile lint`.
+    Lint {
+        /// Path to the multi-target config file (YAML: `name`, `common`,
+        /// `targets`).
+        config: PathBuf,
+
+        /// Exit non-zero if any finding is an error (not just a
+        /// warning).
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+pub async fn run(args: ConfigArgs) -> anyhow::Result<()> {
+    match args.command {
+        ConfigCommand::Export { provider, target_selector, out_dir, name } => {
+            match (target_selector.config, target_selector.target) {
+                (Some(config), Some(target)) => export_from_multi_target(&config, &target, &out_dir).await,
+                _ => {
+                    let provider = provider
+                        .ok_or_else(|| anyhow::anyhow!("pass either --provider or --config/--target"))?;
+                    export(&provider, &out_dir, &name).await
+                }
+            }
+        }
+        ConfigCommand::Init { provider, name, profile, memory, cpus, out_dir } => {
+            init(provider, name, profile, memory, cpus, &out_dir).await
+        }
+        ConfigCommand::Schema => schema(),
+        ConfigCommand::Lint { config, strict } => lint(&config, strict),
+    }
+}
+
+fn schema() -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(&multi_target_config_json_schema())?);
+    Ok(())
+}
+
+fn lint(path: &Path, strict: bool) -> anyhow::Result<()> {
+    let config = MultiTargetConfig::load(path)?;
+    let findings = sindri_providers::lint_config(&config);
+
+    if findings.is_empty() {
+        println!("no lint findings");
+        return Ok(());
+    }
+
+    let mut has_error = false;
+    for finding in &findings {
+        has_error |= finding.severity == LintSeverity::Error;
+        let label = match finding.severity {
+            LintSeverity::Warning => "warning",
+            LintSeverity::Error => "error",
+        };
+        println!("{label}: {} ({})", finding.message, finding.path);
+    }
+
+    if strict && has_error {
+        anyhow::bail!("lint found {} finding(s), including at least one error", findings.len());
+    }
+    Ok(())
+}
+
+/// Resolves `target` within `config` to a provider and [`TemplateContext`],
+/// then renders that provider's artifact exactly like `export` does. A
+/// `retry` override declared on `common` or the target itself is applied
+/// to the provider's command execution.
+async fn export_from_multi_target(config: &Path, target: &str, out_dir: &Path) -> anyhow::Result<()> {
+    let config = MultiTargetConfig::load(config)?;
+    let (provider, context, retry) = config.resolve(target)?;
+
+    let cloud = parse_cloud(&provider)?;
+    let adapter = match retry {
+        Some(retry) => create_provider_with(cloud, ProviderOptions {
+            retry_policy: to_retry_policy(&retry),
+            ..ProviderOptions::default()
+        })?,
+        None => create_provider(cloud)?,
+    };
+    let written = adapter
+        .export_config(&context, out_dir)
+        .await
+        .map_err(|err| anyhow::anyhow!("failed to export config for provider {provider}: {err}"))?;
+
+    for path in &written {
+        tracing::info!(path = %path.display(), "wrote config");
+    }
+    Ok(())
+}
+
+/// Real providers known to [`parse_cloud`], offered as the wizard's
+/// numbered choices so a new user doesn't have to guess the right
+/// `--provider` spelling.
+const KNOWN_PROVIDERS: [&str; 8] =
+    ["docker", "fly", "devpod", "k3d", "northflank", "packer", "runpod", "e2b"];
+
+async fn init(
+    provider: Option<String>,
+    name: Option<String>,
+    profile: Option<String>,
+    memory: Option<String>,
+    cpus: Option<u32>,
+    out_dir: &Path,
+) -> anyhow::Result<()> {
+    let interactive = provider.is_none() && std::io::stdin().is_terminal();
+
+    let provider = match provider {
+        Some(provider) => provider,
+        None if interactive => {
+            println!("Providers: {}", KNOWN_PROVIDERS.join(", "));
+            prompt("Provider", "docker")?
+        }
+        None => "docker".to_string(),
+    };
+    let name = match name {
+        Some(name) => name,
+        None if interactive => prompt("Deployment name", "sindri-dev")?,
+        None => "sindri-dev".to_string(),
+    };
+    let profile = match profile {
+        Some(profile) => Some(profile),
+        None if interactive => {
+            let profile = prompt("Profile (blank for provider default)", "")?;
+            (!profile.is_empty()).then_some(profile)
+        }
+        None => None,
+    };
+    let memory = match memory {
+        Some(memory) => Some(memory),
+        None if interactive => {
+            let memory = prompt("Memory (blank for provider default)", "")?;
+            (!memory.is_empty()).then_some(memory)
+        }
+        None => None,
+    };
+    let cpus = match cpus {
+        Some(cpus) => Some(cpus),
+        None if interactive => {
+            let cpus = prompt("CPUs (blank for provider default)", "")?;
+            if cpus.is_empty() {
+                None
+            } else {
+                Some(cpus.parse().map_err(|_| anyhow::anyhow!("{cpus:?} is not a valid CPU count"))?)
+            }
+        }
+        None => None,
+    };
+
+    export_with(&provider, out_dir, &name, profile, memory, cpus).await
+}
+
+/// Prints `label` with its default and reads one line from stdin,
+/// falling back to `default` on an empty line.
+fn prompt(label: &str, default: &str) -> anyhow::Result<String> {
+    if default.is_empty() {
+        print!("{label}: ");
+    } else {
+        print!("{label} [{default}]: ");
+    }
+    std::io::stdout().flush()?;
+
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    let trimmed = line.trim();
+    Ok(if trimmed.is_empty() { default.to_string() } else { trimmed.to_string() })
+}
+
+async fn export(provider: &str, out_dir: &Path, name: &str) -> anyhow::Result<()> {
+    export_with(provider, out_dir, name, None, None, None).await
+}
+
+async fn export_with(
+    provider: &str,
+    out_dir: &Path,
+    name: &str,
+    profile: Option<String>,
+    memory: Option<String>,
+    cpus: Option<u32>,
+) -> anyhow::Result<()> {
+    let cloud = parse_cloud(provider)?;
+    let adapter = create_provider(cloud)?;
+
+    let mut builder = TemplateContext::builder().name(name);
+    if let Some(profile) = profile {
+        builder = builder.profile(profile);
+    }
+    if let Some(memory) = memory {
+        builder = builder.memory(memory);
+    }
+    if let Some(cpus) = cpus {
+        builder = builder.cpus(cpus);
+    }
+    let context = builder.build()?;
+
+    let written = adapter.export_config(&context, out_dir).await.map_err(|err| {
+        anyhow::anyhow!("failed to export config for provider {provider}: {err}")
+    })?;
+
+    for path in &written {
+        tracing::info!(path = %path.display(), "wrote config");
+    }
+    Ok(())
+}
+
+fn parse_cloud(provider: &str) -> anyhow::Result<CloudProvider> {
+    Ok(match provider {
+        "docker" => CloudProvider::Docker,
+        "fly" => CloudProvider::Fly,
+        "devpod" => CloudProvider::DevPod,
+        "k3d" => CloudProvider::K3d,
+        "northflank" => CloudProvider::Northflank,
+        "packer" => CloudProvider::Packer,
+        "runpod" => CloudProvider::Runpod,
+        "e2b" => CloudProvider::E2b,
+        other => anyhow::bail!("unknown cloud provider {other:?}"),
+    })
+}
@@ -0,0 +1,120 @@
+use clap::Args;
+use sindri_core::{DeploymentStatus, PathResolver, SessionRecorder};
+use sindri_providers::{DockerProvider, KubernetesProvider, PortForward, Provider};
+
+use super::parse_provider_label;
+
+#[derive(Args)]
+pub struct ConnectArgs {
+    /// Instance id to connect to, as returned by `sindri deploy`.
+    pub instance_id: String,
+
+    /// Forward an additional port through the provider's native tunneling
+    /// mechanism, as `LOCAL:REMOTE` or a bare `PORT` (forwards to itself).
+    /// Repeatable, e.g. `--tunnel 3000:3000 --tunnel 9229`.
+    #[arg(long = "tunnel")]
+    pub tunnels: Vec<String>,
+
+    /// Record the session to a timestamped transcript under
+    /// `<config-dir>/sessions/`, like `script(1)`. Nothing is redacted —
+    /// the transcript may contain secrets typed or echoed during the
+    /// session.
+    #[arg(long)]
+    pub record: bool,
+
+    /// Emit a single DeploymentStatus JSON object to stdout instead of
+    /// human-readable text.
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub async fn run(args: ConnectArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    tracing::info!(instance_id = %args.instance_id, "connecting");
+
+    let mut recorder = if args.record {
+        tracing::warn!(
+            "recording this session to a transcript; nothing is redacted, so avoid typing \
+             secrets or review the transcript before sharing it"
+        );
+        let recorder = SessionRecorder::start(&paths.sessions_dir(), &args.instance_id)?;
+        tracing::info!(path = %recorder.path().display(), "recording session transcript");
+        Some(recorder)
+    } else {
+        None
+    };
+
+    let forwards = args
+        .tunnels
+        .iter()
+        .map(|spec| spec.parse::<PortForward>())
+        .collect::<Result<Vec<_>, _>>()?;
+    for forward in &forwards {
+        forward.ensure_local_port_available()?;
+    }
+
+    let (provider, context) = parse_provider_label(&args.instance_id);
+
+    let mut image_source = None;
+    if provider == "docker" {
+        let docker = DockerProvider::new(context.clone());
+        docker.validate().await.map_err(|err| {
+            anyhow::anyhow!("docker context this deployment used is unreachable: {err}")
+        })?;
+        image_source = docker.image_source(&args.instance_id).await.unwrap_or_else(|err| {
+            tracing::debug!(%err, "could not determine whether this deployment's image was built or pulled");
+            None
+        });
+    }
+
+    if provider == "k3d" {
+        let namespace = context.clone().unwrap_or_default();
+        KubernetesProvider::new(namespace, None).validate().await.map_err(|err| {
+            anyhow::anyhow!("k3d cluster this deployment used is unreachable: {err}")
+        })?;
+    }
+
+    let status = DeploymentStatus {
+        instance_id: args.instance_id.clone(),
+        provider: provider.clone(),
+        state: "running".to_string(),
+        uptime: None,
+        restart_count: None,
+        replicas: None,
+        image_source,
+    };
+
+    sindri_core::emit(&status, args.json, DeploymentStatus::render_human)?;
+
+    if let Some(recorder) = &mut recorder {
+        recorder.record(&format!("connected to {} ({})", args.instance_id, status.state))?;
+    }
+
+    if forwards.is_empty() {
+        if let Some(recorder) = &mut recorder {
+            recorder.record("session ended (no tunnels requested)")?;
+        }
+        return Ok(());
+    }
+
+    let tunnel = match provider.as_str() {
+        "docker" => {
+            DockerProvider::new(context)
+                .open_tunnel(&forwards)
+                .await
+                .map_err(|err| anyhow::anyhow!("failed to open tunnel: {err}"))?
+        }
+        other => anyhow::bail!("tunneling isn't supported for provider {other:?} yet"),
+    };
+
+    tracing::info!(count = forwards.len(), "tunnel(s) active, press Ctrl+C to disconnect");
+    if let Some(recorder) = &mut recorder {
+        recorder.record(&format!("{} tunnel(s) active", forwards.len()))?;
+    }
+    tokio::signal::ctrl_c().await?;
+    tracing::info!("disconnecting, tearing down tunnel(s)");
+    if let Some(recorder) = &mut recorder {
+        recorder.record("disconnected, tunnel(s) torn down")?;
+    }
+    drop(tunnel);
+    Ok(())
+}
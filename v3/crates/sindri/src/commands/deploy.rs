@@ -0,0 +1,275 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use sindri_core::{send_webhook_event, DeployEvent, DeployOutcome, DeployResult, ShutdownSignal};
+use sindri_providers::{wait_until_ready, DockerProvider, KubernetesProvider, Provider, ProviderError};
+
+use super::{provider_label, TargetSelectorArgs};
+
+/// How long to sleep between readiness polls under `--wait`.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Args)]
+pub struct DeployArgs {
+    /// Cloud provider to deploy to (docker, fly, devpod, k3d, ...).
+    #[arg(long)]
+    pub provider: String,
+
+    /// Named `docker context` to deploy into. Docker-only; ignored by
+    /// other providers. Recorded in the instance id so `connect`/`status`/
+    /// `destroy` target the same daemon.
+    #[arg(long)]
+    pub context: Option<String>,
+
+    /// OCI runtime to run the container under, e.g. `runsc` for gVisor.
+    /// Docker-only; validated against the daemon's installed runtimes.
+    #[arg(long)]
+    pub runtime: Option<String>,
+
+    /// Build the image from a local Dockerfile instead of pulling one,
+    /// using this directory as the build context. Docker-only; skipped
+    /// (not rebuilt) if an image already exists under the content hash of
+    /// this directory. Requires `--dockerfile` to live outside the
+    /// context root, optional otherwise (defaults to `<context>/Dockerfile`
+    /// the same way `docker build` does).
+    #[arg(long)]
+    pub build_context: Option<PathBuf>,
+
+    /// Dockerfile to build with, when it isn't `<build-context>/Dockerfile`.
+    /// Requires `--build-context`.
+    #[arg(long, requires = "build_context")]
+    pub dockerfile: Option<PathBuf>,
+
+    /// Kubernetes namespace to deploy into. k3d-only; created automatically
+    /// if missing unless `--no-create-namespace` is set. Recorded in the
+    /// instance id so `connect`/`status`/`destroy` target the same
+    /// namespace.
+    #[arg(long)]
+    pub namespace: Option<String>,
+
+    /// Fail instead of auto-creating `--namespace` when it doesn't already
+    /// exist. k3d-only.
+    #[arg(long)]
+    pub no_create_namespace: bool,
+
+    /// Block until the deployment is actually reachable, regardless of
+    /// provider, instead of returning as soon as it's accepted. Exits
+    /// non-zero with the last observed state if it isn't ready within
+    /// `--wait-timeout-secs`.
+    #[arg(long)]
+    pub wait: bool,
+
+    /// Overall timeout in seconds for `--wait`.
+    #[arg(long, default_value_t = 120)]
+    pub wait_timeout_secs: u64,
+
+    /// Emit a single DeployResult JSON object to stdout instead of
+    /// human-readable text. Logs still go to stderr.
+    #[arg(long)]
+    pub json: bool,
+
+    /// POST a JSON event to this URL on deploy start and on success/
+    /// failure (provider, instance id, duration, outcome). A slow or
+    /// unreachable endpoint only logs a warning — it never fails the
+    /// deploy.
+    #[arg(long)]
+    pub webhook_url: Option<String>,
+
+    /// A multi-target `sindri.yaml`-style config to read a retry policy
+    /// override from, same `--config`/`--target` pair `sindri config
+    /// export` resolves against. `--provider`/`--context`/`--namespace`
+    /// still come from their own flags above; only `common.retry`/the
+    /// target's own `retry` is pulled from here.
+    #[command(flatten)]
+    pub target_selector: TargetSelectorArgs,
+}
+
+pub async fn run(args: DeployArgs, shutdown: ShutdownSignal) -> anyhow::Result<()> {
+    let webhook_url = args.webhook_url.clone();
+    let provider = args.provider.clone();
+    let started = Instant::now();
+
+    if let Some(url) = &webhook_url {
+        send_webhook_event(
+            url,
+            &DeployEvent {
+                provider: provider.clone(),
+                instance_id: String::new(),
+                outcome: DeployOutcome::Start,
+                duration: Duration::ZERO,
+            },
+        )
+        .await;
+    }
+
+    let result = execute(args, shutdown).await;
+
+    if let Some(url) = &webhook_url {
+        let (outcome, instance_id) = match &result {
+            Ok(deploy_result) => (DeployOutcome::Success, deploy_result.instance_id.clone()),
+            Err(_) => (DeployOutcome::Failure, String::new()),
+        };
+        send_webhook_event(
+            url,
+            &DeployEvent { provider, instance_id, outcome, duration: started.elapsed() },
+        )
+        .await;
+    }
+
+    result.map(|_| ())
+}
+
+async fn execute(args: DeployArgs, shutdown: ShutdownSignal) -> anyhow::Result<DeployResult> {
+    tracing::info!(
+        provider = %args.provider,
+        context = ?args.context,
+        runtime = ?args.runtime,
+        "starting deploy"
+    );
+
+    let retry_policy = args.target_selector.resolve_retry_policy()?;
+
+    if args.provider == "docker" {
+        let mut docker = DockerProvider::new(args.context.clone())
+            .with_runtime(args.runtime.clone())
+            .with_retry_policy(retry_policy.clone());
+        if let Some(build_context) = &args.build_context {
+            docker = docker.with_build(build_context.clone(), args.dockerfile.clone());
+        }
+        tokio::select! {
+            result = docker.validate() => {
+                result.map_err(|err| target_not_usable_error("docker", &err))?;
+            }
+            _ = shutdown.cancelled() => {
+                // Nothing has been provisioned yet at this point in the
+                // deploy flow, so there's nothing to tear down; bail out
+                // cleanly instead of leaving an unusable partial deploy.
+                anyhow::bail!("deploy cancelled by signal before any resources were created");
+            }
+        }
+
+        if args.build_context.is_some() {
+            tokio::select! {
+                result = docker.build() => {
+                    let tag = result.map_err(|err| target_not_usable_error("docker", &err))?;
+                    tracing::info!(tag = %tag, "built image from local Dockerfile");
+                }
+                _ = shutdown.cancelled() => {
+                    anyhow::bail!("deploy cancelled by signal before any resources were created");
+                }
+            }
+        }
+    }
+
+    if args.provider == "k3d" {
+        let namespace = args
+            .namespace
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("--namespace is required for provider k3d"))?;
+        let k8s = KubernetesProvider::new(namespace, None)
+            .with_create_namespace_if_missing(!args.no_create_namespace)
+            .with_retry_policy(retry_policy.clone());
+        tokio::select! {
+            result = async {
+                k8s.validate().await?;
+                k8s.ensure_namespace().await
+            } => {
+                result.map_err(|err| target_not_usable_error("k3d", &err))?;
+            }
+            _ = shutdown.cancelled() => {
+                anyhow::bail!("deploy cancelled by signal before any resources were created");
+            }
+        }
+    }
+
+    let context_slot = if args.provider == "k3d" { args.namespace.as_deref() } else { args.context.as_deref() };
+    let label = provider_label(&args.provider, context_slot);
+
+    // Provisioning itself is handled by the provider-specific adapters in
+    // sindri-providers; this wires the CLI surface and output contract.
+    let result = DeployResult {
+        instance_id: format!("{label}_{}", uuid::Uuid::new_v4()),
+        provider: label,
+        ssh_command: Some(format!("sindri connect --provider {}", args.provider)),
+        warnings: Vec::new(),
+    };
+
+    if args.wait {
+        if args.provider == "docker" {
+            let docker = DockerProvider::new(args.context.clone())
+                .with_runtime(args.runtime.clone())
+                .with_retry_policy(retry_policy.clone());
+            tokio::select! {
+                outcome = wait_until_ready(
+                    &docker,
+                    Duration::from_secs(args.wait_timeout_secs),
+                    WAIT_POLL_INTERVAL,
+                    |attempt| match attempt {
+                        Ok(()) => tracing::info!("deployment is ready"),
+                        Err(err) => tracing::info!(%err, "deployment not ready yet, still waiting"),
+                    },
+                ) => {
+                    outcome.map_err(|err| {
+                        anyhow::anyhow!(
+                            "deployment did not become ready within {}s, last observed state: {err}",
+                            args.wait_timeout_secs
+                        )
+                    })?;
+                }
+                _ = shutdown.cancelled() => {
+                    anyhow::bail!("deploy cancelled by signal while waiting for readiness");
+                }
+            }
+        } else if args.provider == "k3d" {
+            let namespace = args.namespace.clone().unwrap_or_default();
+            let k8s = KubernetesProvider::new(namespace, None).with_retry_policy(retry_policy.clone());
+            tokio::select! {
+                outcome = wait_until_ready(
+                    &k8s,
+                    Duration::from_secs(args.wait_timeout_secs),
+                    WAIT_POLL_INTERVAL,
+                    |attempt| match attempt {
+                        Ok(()) => tracing::info!("deployment is ready"),
+                        Err(err) => tracing::info!(%err, "deployment not ready yet, still waiting"),
+                    },
+                ) => {
+                    outcome.map_err(|err| {
+                        anyhow::anyhow!(
+                            "deployment did not become ready within {}s, last observed state: {err}",
+                            args.wait_timeout_secs
+                        )
+                    })?;
+                }
+                _ = shutdown.cancelled() => {
+                    anyhow::bail!("deploy cancelled by signal while waiting for readiness");
+                }
+            }
+        } else {
+            tracing::warn!(
+                provider = %args.provider,
+                "--wait has no readiness check for this provider yet; not waiting"
+            );
+        }
+    }
+
+    sindri_core::emit(&result, args.json, DeployResult::render_human)?;
+    Ok(result)
+}
+
+/// Builds the error `deploy` surfaces when a provider's target isn't
+/// usable, appending a next-step suggestion for failure kinds that have
+/// one instead of just repeating the raw provider error.
+fn target_not_usable_error(provider: &str, err: &ProviderError) -> anyhow::Error {
+    match err {
+        ProviderError::AuthRequired(_) => anyhow::anyhow!(
+            "{provider} deployment target is not usable, aborting deploy: {err} \
+             (run `sindri doctor` to check your local tooling and credentials)"
+        ),
+        ProviderError::QuotaExceeded(_) => anyhow::anyhow!(
+            "{provider} deployment target is not usable, aborting deploy: {err} \
+             (this looks like a provider quota or rate limit, not a configuration problem)"
+        ),
+        other => anyhow::anyhow!("{provider} deployment target is not usable, aborting deploy: {other}"),
+    }
+}
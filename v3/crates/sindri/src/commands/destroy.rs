@@ -0,0 +1,72 @@
+use std::time::Instant;
+
+use clap::Args;
+use sindri_core::{send_webhook_event, DeployEvent, DeployOutcome, DeploymentStatus};
+use sindri_providers::{DockerProvider, KubernetesProvider, Provider};
+
+use super::parse_provider_label;
+
+#[derive(Args)]
+pub struct DestroyArgs {
+    /// Instance id to tear down, as returned by `sindri deploy`.
+    pub instance_id: String,
+
+    /// Emit a single DeploymentStatus JSON object to stdout instead of
+    /// human-readable text.
+    #[arg(long)]
+    pub json: bool,
+
+    /// POST a JSON event to this URL once the instance is destroyed
+    /// (provider, instance id, duration, outcome). A slow or unreachable
+    /// endpoint only logs a warning — it never fails the destroy.
+    #[arg(long)]
+    pub webhook_url: Option<String>,
+}
+
+pub async fn run(args: DestroyArgs) -> anyhow::Result<()> {
+    let started = Instant::now();
+    tracing::info!(instance_id = %args.instance_id, "destroying");
+
+    let (provider, context) = parse_provider_label(&args.instance_id);
+
+    if provider == "docker" {
+        let docker = DockerProvider::new(context.clone());
+        docker.validate().await.map_err(|err| {
+            anyhow::anyhow!("docker context this deployment used is unreachable: {err}")
+        })?;
+    }
+
+    if provider == "k3d" {
+        let namespace = context.clone().unwrap_or_default();
+        let k8s = KubernetesProvider::new(namespace, None);
+        k8s.destroy().await.map_err(|err| {
+            anyhow::anyhow!("failed to destroy k3d deployment: {err}")
+        })?;
+    }
+
+    let status = DeploymentStatus {
+        instance_id: args.instance_id.clone(),
+        provider: provider.clone(),
+        state: "destroyed".to_string(),
+        uptime: None,
+        restart_count: None,
+        replicas: None,
+        image_source: None,
+    };
+
+    if let Some(url) = &args.webhook_url {
+        send_webhook_event(
+            url,
+            &DeployEvent {
+                provider,
+                instance_id: args.instance_id.clone(),
+                outcome: DeployOutcome::Destroyed,
+                duration: started.elapsed(),
+            },
+        )
+        .await;
+    }
+
+    sindri_core::emit(&status, args.json, DeploymentStatus::render_human)?;
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::Args;
+use sindri_core::PathResolver;
+use sindri_doctor::{
+    select_install_command, Doctor, InstallInstruction, PackageManager, ToolDefinition,
+    ToolStatus, DEFAULT_NETWORK_TIMEOUT, MIN_WATCH_INTERVAL,
+};
+use sindri_extensions::{ExtensionDistributor, ExtensionRegistry};
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// Keep re-running the diagnostic and redraw, highlighting what changed
+    /// since the last run. Press Ctrl+C to stop.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Seconds between refreshes in `--watch` mode. Clamped to a minimum
+    /// of 2 seconds to keep the cost of repeated checks low.
+    #[arg(long, default_value_t = 5)]
+    pub interval_secs: u64,
+
+    /// Print a "slowest checks" summary after the report, useful for
+    /// tracking down which tool's `--version` is slow (often a cloud CLI
+    /// making a network call).
+    #[arg(long)]
+    pub verbose: bool,
+
+    /// Print the report as JSON, including per-tool timings, instead of
+    /// the human-readable list.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Also probe reachability of the domains required by installed
+    /// extensions. Opt-in since it makes network calls; each probe is
+    /// time-boxed so a blocked host doesn't hang the run.
+    #[arg(long)]
+    pub network: bool,
+
+    /// Run just this tool's check and print the exact command, its exit
+    /// code, and its raw stdout/stderr, instead of the summary table.
+    /// Useful for working out why a tool shows as missing.
+    #[arg(long)]
+    pub explain: Option<String>,
+}
+
+pub async fn run(args: DoctorArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    let doctor = Doctor::new(default_tools());
+
+    if let Some(name) = &args.explain {
+        return explain_tool(&doctor, name).await;
+    }
+
+    if !args.watch {
+        let report = doctor.run().await;
+        let network = if args.network {
+            let domains = required_domains(paths)?;
+            Some(sindri_doctor::check_domains(&domains, DEFAULT_NETWORK_TIMEOUT).await)
+        } else {
+            None
+        };
+
+        if args.json {
+            match &network {
+                Some(network) => println!("{}", serde_json::json!({ "tools": report, "network": network })),
+                None => println!("{}", serde_json::to_string(&report)?),
+            }
+            return Ok(());
+        }
+
+        let preferred = sindri_doctor::preferred(&sindri_doctor::detect_package_managers().await);
+
+        for (result, tool) in report.results.iter().zip(doctor.tools()) {
+            println!("{:<24} {}  ({:.3}s)", result.name, result.status, result.elapsed.as_secs_f64());
+            if result.status == ToolStatus::Missing {
+                if let Some(command) = select_install_command(tool.install(), preferred) {
+                    println!("  try: {command}");
+                }
+            }
+        }
+
+        if args.verbose {
+            println!("\nslowest checks:");
+            for result in report.slowest_first() {
+                println!("  {:<24} {:.3}s", result.name, result.elapsed.as_secs_f64());
+            }
+        }
+
+        if let Some(network) = network {
+            println!("\nnetwork:");
+            if network.is_empty() {
+                println!("  no domains required by installed extensions");
+            }
+            for check in network {
+                match check.reachable {
+                    true => println!("  {:<24} reachable", check.domain),
+                    false => println!(
+                        "  {:<24} blocked ({})",
+                        check.domain,
+                        check.reason.unwrap_or_default()
+                    ),
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let interval = Duration::from_secs(args.interval_secs).max(MIN_WATCH_INTERVAL);
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_on_signal = stop.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            stop_on_signal.store(true, Ordering::SeqCst);
+        }
+    });
+
+    let mut stdout = std::io::stdout();
+    sindri_doctor::watch(&doctor, interval, &mut stdout, || stop.load(Ordering::SeqCst)).await?;
+    Ok(())
+}
+
+/// Runs one tool's check in isolation and prints everything about it:
+/// the exact command, its exit code, and its raw stdout/stderr. Errors
+/// out listing the valid tool ids if `name` isn't one of them.
+async fn explain_tool(doctor: &Doctor, name: &str) -> anyhow::Result<()> {
+    let Some(tool) = doctor.tools().iter().find(|tool| tool.name == name) else {
+        let known: Vec<&str> = doctor.tools().iter().map(|tool| tool.name.as_str()).collect();
+        anyhow::bail!("unknown tool {name:?}; known tools: {}", known.join(", "));
+    };
+
+    let explanation = tool.explain().await;
+    println!("command:    {} {}", explanation.binary, explanation.args.join(" "));
+    println!("status:     {}", explanation.status);
+    match explanation.exit_code {
+        Some(code) => println!("exit code:  {code}"),
+        None => println!("exit code:  (binary could not be spawned)"),
+    }
+    println!("stdout:");
+    println!("{}", indent(&explanation.stdout));
+    println!("stderr:");
+    println!("{}", indent(&explanation.stderr));
+    Ok(())
+}
+
+fn indent(text: &str) -> String {
+    if text.is_empty() {
+        return "  (empty)".to_string();
+    }
+    text.lines().map(|line| format!("  {line}")).collect::<Vec<_>>().join("\n")
+}
+
+/// Domains required by every currently installed extension, per the
+/// registry's `requirements.domains`, deduplicated and sorted. Returns an
+/// empty list (rather than erroring) when nothing is installed or the
+/// registry hasn't been cached yet, since `--network` shouldn't fail a
+/// doctor run over a catalog that just hasn't been fetched.
+fn required_domains(paths: &PathResolver) -> anyhow::Result<Vec<String>> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let installed: BTreeSet<String> = distributor.installed_extensions()?.into_iter().collect();
+    if installed.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let registry_path = paths.registry_file();
+    if !registry_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let registry = ExtensionRegistry::load(&registry_path)?;
+
+    let mut domains: BTreeSet<String> = BTreeSet::new();
+    for entry in registry.entries() {
+        if installed.contains(&entry.name) {
+            domains.extend(entry.requirements.domains.iter().cloned());
+        }
+    }
+    Ok(domains.into_iter().collect())
+}
+
+fn default_tools() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition::new("docker", "docker")
+            .with_check_args(vec!["info".to_string()])
+            .with_install(vec![
+                InstallInstruction::package_manager(PackageManager::Brew, "brew install --cask docker"),
+                InstallInstruction::package_manager(PackageManager::Apt, "sudo apt-get install docker.io"),
+                InstallInstruction::package_manager(PackageManager::Dnf, "sudo dnf install docker"),
+                InstallInstruction::package_manager(PackageManager::Winget, "winget install Docker.DockerDesktop"),
+                InstallInstruction::generic("https://docs.docker.com/get-docker/"),
+            ]),
+        ToolDefinition::new("git", "git").with_install(vec![
+            InstallInstruction::package_manager(PackageManager::Brew, "brew install git"),
+            InstallInstruction::package_manager(PackageManager::Apt, "sudo apt-get install git"),
+            InstallInstruction::package_manager(PackageManager::Dnf, "sudo dnf install git"),
+            InstallInstruction::package_manager(PackageManager::Winget, "winget install Git.Git"),
+            InstallInstruction::generic("https://git-scm.com/downloads"),
+        ]),
+        ToolDefinition::new("packer", "packer").with_install(vec![
+            InstallInstruction::package_manager(PackageManager::Brew, "brew install hashicorp/tap/packer"),
+            InstallInstruction::generic("https://developer.hashicorp.com/packer/install"),
+        ]),
+        // Needed by extensions installed via `InstallMethod::Cargo`.
+        ToolDefinition::new("cargo", "cargo").with_install(vec![
+            InstallInstruction::generic("https://www.rust-lang.org/tools/install"),
+        ]),
+    ]
+}
@@ -0,0 +1,430 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+use sindri_core::{OutputFormat, PathResolver};
+use sindri_extensions::{
+    format_event_summary, AvailableExtensionRow, CompatibilityMatrix, EventFilter,
+    ExtensionDistributor, ExtensionError, ExtensionLedger, ExtensionRegistry, HistoryRow,
+    Lockfile, Platform, PlatformMatrix, SearchResultRow, StatusRow, VersionExplainRow, VersionRow,
+};
+
+/// Default path for the lockfile written/checked by `sindri extension lock`.
+const DEFAULT_LOCKFILE: &str = "sindri.lock";
+
+/// Poll interval used by `sindri extension events --follow`.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Args)]
+pub struct ExtensionArgs {
+    #[command(subcommand)]
+    pub command: ExtensionCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ExtensionCommand {
+    /// List installed extensions.
+    List {
+        /// Also include extensions from the catalog that aren't installed
+        /// yet, so the full set of what's available is visible alongside
+        /// what's actually on disk.
+        #[arg(long)]
+        all: bool,
+
+        /// Only render these columns, in this order (e.g.
+        /// `name,installed`). Matched case-insensitively against the
+        /// table's headers. Defaults to every column. Has no effect on
+        /// `--output json`/`yaml`, which always serialize full rows.
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<String>,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Show the active version and state of each installed extension.
+    Status {
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Show details for a single extension.
+    Info {
+        name: String,
+
+        /// Also check availability on this platform (e.g. `linux/arm64`),
+        /// per the platform matrix.
+        #[arg(long)]
+        platform: Option<Platform>,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// List every installed version of an extension.
+    Versions {
+        name: String,
+
+        /// Explain why each version is (or isn't) compatible with this
+        /// CLI, per the compatibility matrix, instead of just listing
+        /// versions.
+        #[arg(long)]
+        explain: bool,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Delete stale, inactive extension versions beyond the retention window.
+    Prune {
+        /// Name of the extension to prune. Prunes every installed extension
+        /// when omitted.
+        name: Option<String>,
+
+        /// Number of most-recent non-active versions to retain per extension.
+        #[arg(long, default_value_t = 2)]
+        keep: usize,
+    },
+    /// Show the most recent install log for an extension.
+    Log { name: String },
+    /// Show the recorded lifecycle event history for an extension
+    /// (currently the configure phase; other phases join as they gain
+    /// ledger support).
+    History {
+        name: String,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Export the recorded lifecycle events across every extension as a
+    /// JSON array, or tail them as newline-delimited JSON with `--follow`.
+    Events {
+        /// Only include events recorded at or after this unix timestamp
+        /// (seconds).
+        #[arg(long)]
+        since: Option<u64>,
+
+        /// Only include this many of the most recent matching events.
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Keep running, printing newline-delimited JSON as new events are
+        /// recorded, instead of exporting the current history and exiting.
+        #[arg(long)]
+        follow: bool,
+    },
+    /// Search the cached extension catalog by name, description, or tool.
+    Search {
+        /// Text to match against name, description, and tools. Omit to
+        /// browse by `--tag` alone.
+        query: Option<String>,
+
+        /// Only include entries carrying this tag. Repeatable; multiple
+        /// tags are ANDed together.
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+    /// Inspect the extension/CLI compatibility matrix.
+    Compat {
+        #[command(subcommand)]
+        command: CompatCommand,
+    },
+    /// Snapshot or check the lockfile pinning each installed extension's
+    /// version and content hash, for reproducible environments.
+    ///
+    /// Without `--check`, (re)writes the lockfile from what's currently
+    /// installed. With `--check`, verifies the current install still
+    /// matches it, the piece a future `install --frozen` mode would rely
+    /// on.
+    Lock {
+        #[arg(long, default_value = DEFAULT_LOCKFILE)]
+        path: PathBuf,
+
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CompatCommand {
+    /// Validate the fetched compatibility matrix against the extension
+    /// catalog, reporting unknown extensions, unparseable version
+    /// requirements, and CLI versions no entry covers.
+    Lint,
+}
+
+pub fn run(args: ExtensionArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    match args.command {
+        ExtensionCommand::List { all, columns, output } => list(paths, all, &columns, output),
+        ExtensionCommand::Status { output } => status(paths, output),
+        ExtensionCommand::Info { name, platform, output } => info(paths, name, platform, output),
+        ExtensionCommand::Versions { name, explain, output } => {
+            versions(paths, name, explain, output)
+        }
+        ExtensionCommand::Prune { name, keep } => prune(paths, name, keep),
+        ExtensionCommand::Log { name } => log(paths, name),
+        ExtensionCommand::History { name, output } => history(paths, name, output),
+        ExtensionCommand::Events { since, limit, follow } => events(paths, since, limit, follow),
+        ExtensionCommand::Search { query, tags, output } => search(paths, query, tags, output),
+        ExtensionCommand::Compat { command } => match command {
+            CompatCommand::Lint => compat_lint(paths),
+        },
+        ExtensionCommand::Lock { path, check } => lock(paths, path, check),
+    }
+}
+
+fn list(paths: &PathResolver, all: bool, columns: &[String], output: OutputFormat) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let installed = distributor.installed_extensions()?;
+    let mut names: HashSet<String> = installed.iter().cloned().collect();
+
+    let mut rows: Vec<AvailableExtensionRow> = installed
+        .into_iter()
+        .map(|name| AvailableExtensionRow {
+            current_version: distributor.current_version(&name),
+            name,
+            installed: true,
+        })
+        .collect();
+
+    if all {
+        let registry_path = paths.registry_file();
+        if registry_path.is_file() {
+            let registry = ExtensionRegistry::load(&registry_path)?;
+            for entry in registry.entries() {
+                if names.insert(entry.name.clone()) {
+                    rows.push(AvailableExtensionRow {
+                        name: entry.name.clone(),
+                        current_version: None,
+                        installed: false,
+                    });
+                }
+            }
+        }
+    }
+
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+    println!("{}", sindri_core::render_rows_selected(&rows, output, Some(columns))?);
+    Ok(())
+}
+
+fn status(paths: &PathResolver, output: OutputFormat) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let mut rows = Vec::new();
+    for name in distributor.installed_extensions()? {
+        let Some(version) = distributor.current_version(&name) else {
+            continue;
+        };
+        rows.push(StatusRow {
+            name: name.clone(),
+            version,
+            state: "installed".to_string(),
+        });
+    }
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+fn info(
+    paths: &PathResolver,
+    name: String,
+    platform: Option<Platform>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let version = distributor
+        .current_version(&name)
+        .ok_or_else(|| sindri_extensions::ExtensionError::NotInstalled(name.clone()))?;
+    let rows = vec![StatusRow {
+        name: name.clone(),
+        version,
+        state: "installed".to_string(),
+    }];
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+
+    if let Some(platform) = platform {
+        let matrix = PlatformMatrix::load(&paths.platform_matrix_file())?;
+        let availability = matrix.availability(&name, &platform);
+        match (availability.available, availability.reason) {
+            (true, _) => println!("available on {platform}"),
+            (false, Some(reason)) => println!("not available on {platform}: {reason}"),
+            (false, None) => println!("not available on {platform}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn versions(
+    paths: &PathResolver,
+    name: String,
+    explain: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let active = distributor.current_version(&name);
+    let installed_versions = distributor.list_versions(&name)?;
+
+    if explain {
+        let matrix = CompatibilityMatrix::load(&paths.compat_matrix_file())?;
+        let cli_version = semver::Version::parse(env!("CARGO_PKG_VERSION"))?;
+        let rows: Vec<VersionExplainRow> = installed_versions
+            .into_iter()
+            .map(|version| {
+                let explanation = matrix.explain_version(&name, &version, &cli_version);
+                VersionExplainRow {
+                    active: Some(&version) == active.as_ref(),
+                    compatible: explanation.compatible,
+                    reason: explanation.to_string(),
+                    version,
+                }
+            })
+            .collect();
+        println!("{}", sindri_core::render_rows(&rows, output)?);
+        return Ok(());
+    }
+
+    let rows: Vec<VersionRow> = installed_versions
+        .into_iter()
+        .map(|version| VersionRow {
+            active: Some(&version) == active.as_ref(),
+            version,
+        })
+        .collect();
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+fn prune(paths: &PathResolver, name: Option<String>, keep: usize) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+
+    let names = match name {
+        Some(name) => vec![name],
+        None => distributor.installed_extensions()?,
+    };
+
+    for name in names {
+        let report = distributor.prune(&name, keep)?;
+        if report.removed_versions.is_empty() {
+            tracing::info!(extension = %name, "nothing to prune");
+        } else {
+            tracing::info!(
+                extension = %name,
+                removed = ?report.removed_versions,
+                freed_bytes = report.freed_bytes,
+                "pruned old versions"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn log(paths: &PathResolver, name: String) -> anyhow::Result<()> {
+    let path = sindri_extensions::latest_log(&paths.logs_dir(), &name)?
+        .ok_or_else(|| ExtensionError::NoLogs(name.clone()))?;
+    print!("{}", std::fs::read_to_string(path)?);
+    Ok(())
+}
+
+fn history(paths: &PathResolver, name: String, output: OutputFormat) -> anyhow::Result<()> {
+    let ledger = ExtensionLedger::new(paths.ledger_dir());
+    let rows: Vec<HistoryRow> = ledger
+        .history(&name)?
+        .iter()
+        .map(|event| HistoryRow { summary: format_event_summary(event) })
+        .collect();
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+fn events(
+    paths: &PathResolver,
+    since: Option<u64>,
+    limit: Option<usize>,
+    follow: bool,
+) -> anyhow::Result<()> {
+    let ledger = ExtensionLedger::new(paths.ledger_dir());
+    let filter = EventFilter { since, limit };
+    let mut stdout = std::io::stdout();
+
+    if follow {
+        ledger.follow(&filter, FOLLOW_POLL_INTERVAL, &mut stdout)?;
+    } else {
+        ledger.export(&filter, &mut stdout)?;
+    }
+
+    Ok(())
+}
+
+fn search(
+    paths: &PathResolver,
+    query: Option<String>,
+    tags: Vec<String>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let registry = ExtensionRegistry::load(&paths.registry_file())?;
+    let tagged: HashSet<&str> = registry
+        .filter_by_tags(&tags)
+        .into_iter()
+        .map(|entry| entry.name.as_str())
+        .collect();
+
+    let rows: Vec<SearchResultRow> = match query.filter(|q| !q.is_empty()) {
+        Some(query) => registry
+            .search(&query)
+            .into_iter()
+            .filter(|(name, _)| tagged.contains(name.as_str()))
+            .map(|(name, score)| SearchResultRow { name, score })
+            .collect(),
+        None => {
+            let mut names: Vec<&str> = tagged.into_iter().collect();
+            names.sort_unstable();
+            names
+                .into_iter()
+                .map(|name| SearchResultRow { name: name.to_string(), score: 0 })
+                .collect()
+        }
+    };
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+fn compat_lint(paths: &PathResolver) -> anyhow::Result<()> {
+    let matrix = CompatibilityMatrix::load(&paths.compat_matrix_file())?;
+    let registry = ExtensionRegistry::load(&paths.registry_file())?;
+    let problems = matrix.validate(&registry);
+
+    if problems.is_empty() {
+        println!("compatibility matrix is valid");
+        return Ok(());
+    }
+
+    for problem in &problems {
+        println!("{problem}");
+    }
+    anyhow::bail!("{} problem(s) found in the compatibility matrix", problems.len());
+}
+
+fn lock(paths: &PathResolver, path: PathBuf, check: bool) -> anyhow::Result<()> {
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+
+    if check {
+        let lockfile = Lockfile::load(&path)?;
+        let drift = lockfile.verify(&distributor)?;
+        if drift.is_empty() {
+            println!("{} matches what's installed", path.display());
+            return Ok(());
+        }
+        for entry in &drift {
+            println!("{entry}");
+        }
+        anyhow::bail!("{} extension(s) drifted from {}", drift.len(), path.display());
+    }
+
+    let lockfile = Lockfile::capture(&distributor)?;
+    lockfile.write(&path)?;
+    println!("wrote {} ({} extension(s))", path.display(), lockfile.extensions.len());
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use sindri_core::{OutputFormat, TableRow};
+use sindri_image::{OsvDatabase, RegistryClient};
+
+#[derive(Args)]
+pub struct ImageArgs {
+    #[command(subcommand)]
+    pub command: ImageCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ImageCommand {
+    /// Fetch an image's SBOM and report packages matched against a local
+    /// OSV advisory snapshot. Matching is exact name and exact
+    /// version-in-list only — this is not a semver-range scanner.
+    Scan {
+        /// Image reference, e.g. `ghcr.io/pacphi/sindri:v3@sha256:...`.
+        reference: String,
+
+        /// Directory of OSV advisory JSON files to match against.
+        #[arg(long)]
+        advisories: PathBuf,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+}
+
+pub async fn run(args: ImageArgs) -> anyhow::Result<()> {
+    match args.command {
+        ImageCommand::Scan { reference, advisories, output } => {
+            scan(&reference, &advisories, output).await
+        }
+    }
+}
+
+async fn scan(reference: &str, advisories: &std::path::Path, output: OutputFormat) -> anyhow::Result<()> {
+    let reference = reference.parse()?;
+    let database = OsvDatabase::load(advisories)?;
+    let client = RegistryClient::new();
+    let findings = sindri_image::scan(&client, &reference, &database).await?;
+
+    if findings.is_empty() {
+        tracing::info!("no known vulnerabilities found");
+        return Ok(());
+    }
+
+    let rows: Vec<FindingRow> = findings
+        .iter()
+        .flat_map(|finding| {
+            finding.advisories.iter().map(move |advisory| FindingRow {
+                package: finding.package.name.clone(),
+                version: finding.package.version.clone().unwrap_or_default(),
+                advisory: advisory.id.clone(),
+                summary: advisory.summary.clone(),
+            })
+        })
+        .collect();
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct FindingRow {
+    package: String,
+    version: String,
+    advisory: String,
+    summary: String,
+}
+
+impl TableRow for FindingRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["PACKAGE", "VERSION", "ADVISORY", "SUMMARY"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.package.clone(), self.version.clone(), self.advisory.clone(), self.summary.clone()]
+    }
+}
@@ -0,0 +1,83 @@
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use sindri_providers::{ClusterProblem, KubernetesDoctor, ResourceProfile};
+
+#[derive(Args)]
+pub struct K8sArgs {
+    #[command(subcommand)]
+    pub command: K8sCommand,
+}
+
+#[derive(Subcommand)]
+pub enum K8sCommand {
+    /// Deep cluster diagnostics beyond tool presence: API server
+    /// reachability, default storage class, DNS, allocatable resources,
+    /// and pending pods with their events. Works against Sindri-created
+    /// clusters and arbitrary kubeconfig contexts alike.
+    Doctor {
+        /// Namespace to check for pending pods in.
+        namespace: String,
+
+        /// Kubeconfig context to use. Defaults to the current context.
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Minimum allocatable memory (bytes) your deployment profile
+        /// needs. Omit to skip the resource check.
+        #[arg(long)]
+        min_memory_bytes: Option<u64>,
+
+        /// Minimum allocatable CPU (millicores) your deployment profile
+        /// needs. Omit to skip the resource check.
+        #[arg(long)]
+        min_cpu_millis: Option<u64>,
+
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub async fn run(args: K8sArgs) -> anyhow::Result<()> {
+    match args.command {
+        K8sCommand::Doctor { namespace, context, min_memory_bytes, min_cpu_millis, json } => {
+            doctor(namespace, context, min_memory_bytes, min_cpu_millis, json).await
+        }
+    }
+}
+
+async fn doctor(
+    namespace: String,
+    context: Option<String>,
+    min_memory_bytes: Option<u64>,
+    min_cpu_millis: Option<u64>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let doctor = KubernetesDoctor::new(namespace, context);
+    let profile = ResourceProfile {
+        min_memory_bytes: min_memory_bytes.unwrap_or(0),
+        min_cpu_millis: min_cpu_millis.unwrap_or(0),
+    };
+    let problems = doctor.diagnose(&profile).await;
+    let report = DoctorReport { healthy: problems.is_empty(), problems };
+    sindri_core::emit(&report, json, DoctorReport::render_human)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DoctorReport {
+    healthy: bool,
+    problems: Vec<ClusterProblem>,
+}
+
+impl DoctorReport {
+    fn render_human(&self) -> String {
+        if self.problems.is_empty() {
+            return "cluster looks healthy".to_string();
+        }
+        self.problems
+            .iter()
+            .map(|problem| format!("[{:?}] {}: {}", problem.severity, problem.check, problem.message))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
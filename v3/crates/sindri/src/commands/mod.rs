@@ -0,0 +1,203 @@
+pub mod backup;
+pub mod completions;
+pub mod config;
+pub mod connect;
+pub mod deploy;
+pub mod destroy;
+pub mod doctor;
+pub mod extension;
+pub mod image;
+pub mod k8s;
+pub mod profile;
+pub mod project;
+pub mod restore;
+pub mod secrets;
+pub mod selfcheck;
+pub mod vm;
+
+use clap::{Args, Parser, Subcommand};
+use sindri_core::{MultiTargetConfig, RetryPolicyConfig};
+use sindri_providers::RetryPolicy;
+
+use backup::BackupArgs;
+use completions::CompletionsArgs;
+use config::ConfigArgs;
+use connect::ConnectArgs;
+use deploy::DeployArgs;
+use destroy::DestroyArgs;
+use doctor::DoctorArgs;
+use extension::ExtensionArgs;
+use image::ImageArgs;
+use k8s::K8sArgs;
+use profile::ProfileArgs;
+use project::ProjectArgs;
+use restore::RestoreArgs;
+use secrets::SecretsArgs;
+use selfcheck::SelfArgs;
+use vm::VmArgs;
+
+#[derive(Parser)]
+#[command(name = "sindri", about = "Declarative cloud development environments")]
+pub struct Cli {
+    /// Override the config directory (cache, extensions, ledger, state all
+    /// live under it). Falls back to `SINDRI_CONFIG_DIR`, then the platform
+    /// XDG config directory.
+    #[arg(long, global = true)]
+    pub config_dir: Option<std::path::PathBuf>,
+
+    /// Extra PEM bundle of trusted CA certificates, added on top of the
+    /// system roots for every HTTP client (registry, Vault, extension
+    /// downloads, ...) — for networks that terminate TLS with a private
+    /// CA. Falls back to `SINDRI_CA_BUNDLE`. Never disables verification.
+    #[arg(long, global = true)]
+    pub ca_bundle: Option<std::path::PathBuf>,
+
+    /// OTLP/HTTP endpoint to export traces to (e.g.
+    /// `http://localhost:4318`), for following a deploy's spans (provider
+    /// calls, retries) across crates in a tracing backend instead of
+    /// reading logs. Falls back to `OTEL_EXPORTER_OTLP_ENDPOINT`. Off by
+    /// default — when unset, no exporter is installed and tracing behaves
+    /// exactly as before.
+    #[arg(long, global = true)]
+    pub otlp_endpoint: Option<String>,
+
+    /// Kill the selected command after this many seconds, triggering the
+    /// same graceful-shutdown/cleanup path as Ctrl-C before giving up and
+    /// exiting with 124 — mainly for CI, so a hung provider CLI or
+    /// registry can't hang the job forever.
+    #[arg(long = "timeout", global = true, value_name = "SECONDS")]
+    pub timeout_secs: Option<u64>,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Provision a new development environment.
+    Deploy(DeployArgs),
+    /// Connect to a deployed environment.
+    Connect(ConnectArgs),
+    /// Tear down a deployed environment.
+    Destroy(DestroyArgs),
+    /// Manage extensions (install, upgrade, prune, verify, ...)
+    Extension(ExtensionArgs),
+    /// Clone and scaffold projects.
+    Project(ProjectArgs),
+    /// Restore a backup, optionally reinstalling its extension snapshot.
+    Restore(RestoreArgs),
+    /// List and prune backup archives.
+    Backup(BackupArgs),
+    /// Generate shell completion scripts.
+    Completions(CompletionsArgs),
+    /// Check the local environment for missing or misconfigured tools.
+    Doctor(DoctorArgs),
+    /// Manage Packer-built images (list, delete, enforce retention).
+    Vm(VmArgs),
+    /// Inspect or render provider configuration.
+    Config(ConfigArgs),
+    /// Resolve and preview secrets without printing their values.
+    Secrets(SecretsArgs),
+    /// Inspect container images (SBOM-based vulnerability scanning, ...)
+    Image(ImageArgs),
+    /// Kubernetes cluster diagnostics.
+    K8s(K8sArgs),
+    /// Compare extension profiles against what's installed.
+    Profile(ProfileArgs),
+    /// Diagnose the CLI's own environment (not a deployed instance).
+    #[command(name = "self")]
+    SelfDiagnose(SelfArgs),
+}
+
+/// A `--config`/`--target` pair for pointing a command at a named target
+/// within a multi-target `sindri.yaml`-style config, established by
+/// `sindri config export` (synth-1444). Flatten this into any command that
+/// needs the same per-target overrides instead of redeclaring the flags —
+/// `deploy`'s retry policy override is the first reuse.
+#[derive(Args, Default)]
+pub struct TargetSelectorArgs {
+    /// A multi-target `sindri.yaml`-style config to resolve `--target`
+    /// against. Requires `--target`.
+    #[arg(long, requires = "target")]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Which named target within `--config` to resolve. Requires
+    /// `--config`.
+    #[arg(long, requires = "config")]
+    pub target: Option<String>,
+}
+
+impl TargetSelectorArgs {
+    /// Resolves `--target` within `--config` to a retry policy override,
+    /// if both were given, and converts it to the retry engine's own
+    /// type. Falls back to [`RetryPolicy::default`] when neither was
+    /// given, the same default every provider constructor already uses
+    /// on its own.
+    pub fn resolve_retry_policy(&self) -> anyhow::Result<RetryPolicy> {
+        let (Some(config), Some(target)) = (&self.config, &self.target) else {
+            return Ok(RetryPolicy::default());
+        };
+        let config = MultiTargetConfig::load(config)?;
+        let (_, _, retry) = config.resolve(target)?;
+        Ok(retry.map(|retry| to_retry_policy(&retry)).unwrap_or_default())
+    }
+}
+
+/// Converts a validated config-file retry override into the retry
+/// engine's own type, keeping everything else ([`RetryPolicy::default`]'s
+/// predicate) as the built-in default. Shared by every command that
+/// resolves a [`TargetSelectorArgs`].
+pub fn to_retry_policy(config: &RetryPolicyConfig) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: config.max_attempts,
+        base_delay: std::time::Duration::from_millis(config.base_delay_ms),
+        ..RetryPolicy::default()
+    }
+}
+
+/// Builds the `provider` label recorded on a `DeployResult`/`DeploymentStatus`,
+/// folding in a provider-specific context (currently just Docker's
+/// `--context`) so later commands against the same instance id can recover
+/// which daemon/context to target. See [`parse_provider_label`] for the
+/// inverse.
+pub fn provider_label(provider: &str, context: Option<&str>) -> String {
+    match context {
+        Some(context) => format!("{provider}@{context}"),
+        None => provider.to_string(),
+    }
+}
+
+/// Recovers `(provider, context)` from either a provider label (as stored
+/// in `DeployResult::provider`) or a full instance id (`<label>_<uuid>`).
+pub fn parse_provider_label(value: &str) -> (String, Option<String>) {
+    let label = value.rsplit_once('_').map_or(value, |(label, _)| label);
+    match label.split_once('@') {
+        Some((provider, context)) => (provider.to_string(), Some(context.to_string())),
+        None => (label.to_string(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_provider_and_context_through_an_instance_id() {
+        let label = provider_label("docker", Some("remote-build-host"));
+        let instance_id = format!("{label}_{}", uuid::Uuid::new_v4());
+        assert_eq!(
+            parse_provider_label(&instance_id),
+            ("docker".to_string(), Some("remote-build-host".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_plain_provider_without_context() {
+        let label = provider_label("fly", None);
+        let instance_id = format!("{label}_{}", uuid::Uuid::new_v4());
+        assert_eq!(
+            parse_provider_label(&instance_id),
+            ("fly".to_string(), None)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use sindri_core::PathResolver;
+use sindri_extensions::{ExtensionDistributor, ExtensionRegistry, LintSeverity, Profile, ProfileDiff};
+
+#[derive(Args)]
+pub struct ProfileArgs {
+    #[command(subcommand)]
+    pub command: ProfileCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ProfileCommand {
+    /// Compare a profile's extension set against what's currently
+    /// installed, before installing it or switching to it.
+    Diff {
+        /// Path to the profile file (YAML: `name`, `extensions`).
+        path: PathBuf,
+
+        /// Print the diff as JSON instead of a human-readable summary.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the JSON Schema for a profile YAML file, for pointing an
+    /// editor's YAML LSP at.
+    ///
+    /// This is the extension [`Profile`] file's own schema, unrelated to
+    /// a `sindri.yaml` deploy config — for that, see `sindri config
+    /// schema`.
+    Schema,
+    /// Check a profile for semantic foot-guns a schema can't catch (an
+    /// empty profile, a duplicated extension, an extension the registry
+    /// doesn't have), at author time rather than deep into an install.
+    ///
+    /// This is scoped to what a [`Profile`] alone can say something
+    /// about (an extension set, not a deployment); for semantic rules
+    /// over the deploy config itself, see `sindri config lint`.
+    Lint {
+        /// Path to the profile file (YAML: `name`, `extensions`).
+        path: PathBuf,
+
+        /// Exit non-zero if any finding is an error (not just a
+        /// warning).
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+pub fn run(args: ProfileArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    match args.command {
+        ProfileCommand::Diff { path, json } => diff(paths, &path, json),
+        ProfileCommand::Schema => schema(),
+        ProfileCommand::Lint { path, strict } => lint(paths, &path, strict),
+    }
+}
+
+fn lint(paths: &PathResolver, path: &std::path::Path, strict: bool) -> anyhow::Result<()> {
+    let profile = Profile::load(path)?;
+
+    let registry_path = paths.registry_file();
+    let known_extensions: Option<Vec<String>> = registry_path
+        .is_file()
+        .then(|| ExtensionRegistry::load(&registry_path))
+        .transpose()?
+        .map(|registry| registry.entries().iter().map(|entry| entry.name.clone()).collect());
+
+    let findings = sindri_extensions::lint(&profile, known_extensions.as_deref());
+
+    if findings.is_empty() {
+        println!("no lint findings");
+        return Ok(());
+    }
+
+    let mut has_error = false;
+    for finding in &findings {
+        has_error |= finding.severity == LintSeverity::Error;
+        let label = match finding.severity {
+            LintSeverity::Warning => "warning",
+            LintSeverity::Error => "error",
+        };
+        println!("{label}: {} ({})", finding.message, finding.path);
+    }
+
+    if strict && has_error {
+        anyhow::bail!("lint found {} finding(s), including at least one error", findings.len());
+    }
+    Ok(())
+}
+
+fn schema() -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(&sindri_extensions::profile_json_schema())?);
+    Ok(())
+}
+
+fn diff(paths: &PathResolver, path: &std::path::Path, json: bool) -> anyhow::Result<()> {
+    let profile = Profile::load(path)?;
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let installed = distributor.installed_extensions()?;
+    let diff = ProfileDiff::compute(&profile, &installed);
+
+    if json {
+        println!("{}", serde_json::to_string(&diff)?);
+        return Ok(());
+    }
+
+    print_list("will install", &diff.will_install);
+    print_list("already have", &diff.already_have);
+    print_list("would remove (if switching)", &diff.would_remove);
+    Ok(())
+}
+
+fn print_list(label: &str, names: &[String]) {
+    if names.is_empty() {
+        println!("{label}: (none)");
+    } else {
+        println!("{label}: {}", names.join(", "));
+    }
+}
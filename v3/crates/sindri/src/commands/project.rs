@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+use sindri_core::PathResolver;
+use sindri_extensions::ExtensionDistributor;
+use sindri_projects::{EnhancementManager, EnhancementOptions, RepositoryOptions};
+
+#[derive(Args)]
+pub struct ProjectArgs {
+    #[command(subcommand)]
+    pub command: ProjectCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ProjectCommand {
+    /// Clone a repository.
+    Clone {
+        url: String,
+        dest: PathBuf,
+
+        /// Detect the project type, create CLAUDE.md if absent, and
+        /// activate the matching installed extension right after cloning.
+        #[arg(long)]
+        enhance: bool,
+
+        /// With --enhance, only fetch dependencies instead of running a
+        /// full build.
+        #[arg(long)]
+        skip_build: bool,
+
+        /// With --enhance, kill the dependency-install command if it
+        /// hasn't finished within this many seconds.
+        #[arg(long, default_value_t = 300)]
+        timeout_secs: u64,
+    },
+}
+
+pub fn run(args: ProjectArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    match args.command {
+        ProjectCommand::Clone {
+            url,
+            dest,
+            enhance,
+            skip_build,
+            timeout_secs,
+        } => clone(&url, &dest, enhance, skip_build, timeout_secs, paths),
+    }
+}
+
+fn clone(
+    url: &str,
+    dest: &std::path::Path,
+    enhance: bool,
+    skip_build: bool,
+    timeout_secs: u64,
+    paths: &PathResolver,
+) -> anyhow::Result<()> {
+    sindri_projects::clone_repo(url, dest, &RepositoryOptions::default())?;
+    tracing::info!(url, dest = %dest.display(), "cloned repository");
+
+    if !enhance {
+        return Ok(());
+    }
+
+    let distributor = ExtensionDistributor::new(paths.extensions_dir());
+    let options = EnhancementOptions {
+        skip_build,
+        timeout: Duration::from_secs(timeout_secs),
+    };
+    let report = EnhancementManager::new(&distributor).enhance(dest, &options)?;
+
+    tracing::info!(
+        project_type = %report.project_type,
+        claude_md_created = report.claude_md_created,
+        dependencies_installed = report.dependencies_installed,
+        activated_extensions = report.activated_extensions.len(),
+        "enhanced project"
+    );
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::Args;
+use sindri_backup::{
+    BackupError, ExtensionInfo, ExtensionReinstall, Hook, RestoreHooks, RestoreManager,
+    RestoreOptions, TracingRestoreProgress,
+};
+use sindri_core::PathResolver;
+use sindri_providers::{CloudProvider, DockerProvider, KubernetesProvider, Provider};
+
+#[derive(Args)]
+pub struct RestoreArgs {
+    /// Directory holding the backup's `backup-manifest.json` and contents.
+    pub backup_dir: PathBuf,
+
+    /// Reinstall every extension recorded in the backup manifest. Versions
+    /// the backup doesn't have are reported rather than failing the
+    /// restore.
+    #[arg(long)]
+    pub reinstall_extensions: bool,
+
+    /// Reinstall up to this many extensions at once. Extensions in a
+    /// backup manifest are independent of each other, so this is safe to
+    /// raise for a manifest with many of them; the default reinstalls one
+    /// at a time, matching past behavior.
+    #[arg(long, default_value_t = 1)]
+    pub parallel: usize,
+
+    /// Reinstall every extension even if it's already at the version
+    /// recorded in the manifest. Without this, a restore that's already
+    /// succeeded for some extensions (e.g. a retry after a previous run
+    /// partially failed) only reinstalls the ones that didn't.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Path to the age identity file (an `AGE-SECRET-KEY-1...` private
+    /// key) to decrypt the backup with. Required if the backup was taken
+    /// with encryption; ignored otherwise.
+    #[arg(long)]
+    pub identity_file: Option<PathBuf>,
+
+    /// Command to run after a successful restore (rebuild shims, `mise
+    /// install`, reindex, ...). Repeatable; each runs in order inside
+    /// `--hooks-dir`. Parsed as a whitespace-separated command line.
+    #[arg(long = "post-restore-hook")]
+    pub post_restore_hooks: Vec<String>,
+
+    /// Directory post-restore hooks run in. Defaults to the current
+    /// directory.
+    #[arg(long)]
+    pub hooks_dir: Option<PathBuf>,
+
+    /// Extra environment variable passed to every post-restore hook, as
+    /// `KEY=VALUE`. Repeatable.
+    #[arg(long = "hook-env")]
+    pub hook_env: Vec<String>,
+
+    /// List the post-restore hooks that would run without running them.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Push `backup_dir` (here, a `.tar.gz` archive rather than a backup
+    /// manifest directory) to this cloud and extract it remotely instead
+    /// of restoring locally. Other providers don't implement remote exec
+    /// yet. When set, every other restore flag (extension reinstall,
+    /// hooks, encryption) is ignored — this is a separate code path.
+    #[arg(long)]
+    pub from_provider: Option<String>,
+
+    /// Container name (docker) or pod name (kubernetes) to exec into.
+    /// Required with `--from-provider`.
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Kubernetes namespace `target` lives in. Ignored for other
+    /// providers.
+    #[arg(long, default_value = "default")]
+    pub namespace: String,
+
+    /// Directory on the remote to extract the archive into. Required
+    /// with `--from-provider`.
+    #[arg(long)]
+    pub remote_dir: Option<String>,
+}
+
+pub async fn run(args: RestoreArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    if let Some(provider) = &args.from_provider {
+        return restore_from_provider(provider, &args).await;
+    }
+
+    let identity = args
+        .identity_file
+        .as_ref()
+        .map(std::fs::read_to_string)
+        .transpose()?
+        .map(|identity| identity.trim().to_string());
+
+    let hooks = args
+        .post_restore_hooks
+        .iter()
+        .map(|spec| parse_hook(spec))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let env = args
+        .hook_env
+        .iter()
+        .map(|entry| parse_env(entry))
+        .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+    let hooks_dir = args.hooks_dir.clone().unwrap_or(std::env::current_dir()?);
+    let hook_options = RestoreOptions { hooks, env };
+
+    let manager = RestoreManager::new(&args.backup_dir);
+    let mut progress = TracingRestoreProgress;
+    let extensions_dir = paths.extensions_dir();
+    let extensions = args.reinstall_extensions.then(|| ExtensionReinstall {
+        extensions_dir: &extensions_dir,
+        parallelism: args.parallel,
+        force: args.force,
+    });
+    let outcome = manager
+        .restore(
+            extensions,
+            identity.as_deref(),
+            Some(RestoreHooks { dir: &hooks_dir, options: &hook_options, dry_run: args.dry_run }),
+            &mut progress,
+        )
+        .map_err(|err| match err {
+            BackupError::ManifestEncrypted(_) if identity.is_none() => anyhow::anyhow!(
+                "this backup is encrypted; pass --identity-file with the matching age identity"
+            ),
+            err => err.into(),
+        })?;
+
+    if let Some(report) = &outcome.extensions {
+        if !report.unmatched.is_empty() {
+            let names: Vec<String> = report
+                .unmatched
+                .iter()
+                .map(|ExtensionInfo { name, version }| format!("{name}@{version}"))
+                .collect();
+            println!("restore finished with extensions that could not be matched: {}", names.join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+async fn restore_from_provider(provider: &str, args: &RestoreArgs) -> anyhow::Result<()> {
+    let target = args
+        .target
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--from-provider requires --target"))?;
+    let remote_dir = args
+        .remote_dir
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--from-provider requires --remote-dir"))?;
+
+    let provider: Box<dyn Provider> = match parse_cloud(provider)? {
+        CloudProvider::Docker => Box::new(DockerProvider::new(None)),
+        CloudProvider::K3d => Box::new(KubernetesProvider::new(args.namespace.clone(), None)),
+        other => anyhow::bail!("{other} does not support remote exec yet"),
+    };
+
+    sindri_backup::restore_from_provider(provider.as_ref(), target, &args.backup_dir, remote_dir).await?;
+    tracing::info!(target, remote_dir, "extracted archive on remote");
+    Ok(())
+}
+
+fn parse_cloud(provider: &str) -> anyhow::Result<CloudProvider> {
+    Ok(match provider {
+        "docker" => CloudProvider::Docker,
+        "kubernetes" | "k8s" => CloudProvider::K3d,
+        other => anyhow::bail!("unknown cloud provider {other:?}"),
+    })
+}
+
+/// Parses a post-restore hook spec (`"mise install"`) into a command and
+/// its arguments, split on whitespace.
+fn parse_hook(spec: &str) -> anyhow::Result<Hook> {
+    let mut parts = spec.split_whitespace();
+    let command = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("post-restore hook {spec:?} is empty"))?
+        .to_string();
+    Ok(Hook { command, args: parts.map(str::to_string).collect() })
+}
+
+/// Parses a `KEY=VALUE` hook environment entry.
+fn parse_env(entry: &str) -> anyhow::Result<(String, String)> {
+    entry
+        .split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("hook env {entry:?} is not in KEY=VALUE form"))
+}
@@ -0,0 +1,302 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use sindri_core::{OutputFormat, TableRow};
+use sindri_secrets::{
+    AuditLog, ResolvedFrom, RotationPolicy, SecretResolver, SecretSource, SecretSpec, VaultAuth,
+    VaultSource, DEFAULT_AUDIT_LOG_PATH,
+};
+
+#[derive(Args)]
+pub struct SecretsArgs {
+    #[command(subcommand)]
+    pub command: SecretsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SecretsCommand {
+    /// Resolve secrets and print which source each came from, with
+    /// values replaced by a masked fingerprint.
+    Preview {
+        /// Secret with an explicit literal value, as `NAME=VALUE`. Tried
+        /// before any `--env` source for the same name.
+        #[arg(long = "literal")]
+        literal: Vec<String>,
+
+        /// Secret resolved from an environment variable, as `NAME` or
+        /// `NAME=VAR` if the variable has a different name. Repeatable.
+        #[arg(long = "env")]
+        env: Vec<String>,
+
+        /// Secret read from a single file's contents, as `NAME=PATH`.
+        #[arg(long = "file")]
+        file: Vec<String>,
+
+        /// Secret assembled by concatenating multiple files in order, as
+        /// `NAME=PATH1,PATH2,...` — a cert, key, and chain bundled into
+        /// one PEM, for example. Files are joined with
+        /// `--bundle-separator`.
+        #[arg(long = "bundle")]
+        bundle: Vec<String>,
+
+        /// Separator inserted between each file in a `--bundle`.
+        #[arg(long = "bundle-separator", default_value = "\n")]
+        bundle_separator: String,
+
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        output: OutputFormat,
+    },
+
+    /// Rotate a secret: write a new value to its backing source, verify
+    /// resolution returns it, and record the attempt in an audit log.
+    ///
+    /// Only file-backed and Vault-backed secrets can be rotated this way
+    /// today — there's no S3/AWS Secrets Manager source in this crate
+    /// yet. Rotation doesn't restart or redeploy whatever consumes the
+    /// secret; re-run `sindri deploy` for that once the new value has
+    /// landed.
+    Rotate {
+        /// Name to record for this secret in the audit log.
+        name: String,
+
+        /// New value to write.
+        #[arg(long)]
+        value: String,
+
+        /// Rotate the file at this path. Mutually exclusive with
+        /// `--vault-addr`.
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Vault address to rotate a KV v2 secret at. Mutually exclusive
+        /// with `--file`.
+        #[arg(long)]
+        vault_addr: Option<String>,
+
+        /// Vault KV v2 path, e.g. `secret/data/prod/db`. Required with
+        /// `--vault-addr`.
+        #[arg(long)]
+        vault_path: Option<String>,
+
+        /// Key within the Vault secret's data to rotate.
+        #[arg(long, default_value = "value")]
+        vault_key: String,
+
+        /// Path to the rotation audit log (JSON lines, appended to).
+        #[arg(long, default_value = DEFAULT_AUDIT_LOG_PATH)]
+        audit_log: PathBuf,
+
+        #[command(flatten)]
+        audit_rotation: AuditRotationArgs,
+
+        /// Report what would be rotated without writing the new value.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Inspect or roll over the rotation audit log itself.
+    Audit {
+        /// Path to the rotation audit log (JSON lines, appended to).
+        #[arg(long, default_value = DEFAULT_AUDIT_LOG_PATH)]
+        log: PathBuf,
+
+        #[command(flatten)]
+        audit_rotation: AuditRotationArgs,
+
+        /// Roll the active log file over immediately, regardless of
+        /// whether it's crossed `--audit-max-bytes`/`--audit-max-age-secs`.
+        #[arg(long)]
+        rotate: bool,
+    },
+}
+
+/// Shared rotation thresholds for the audit log itself, flattened into
+/// both [`SecretsCommand::Rotate`] (so ordinary rotations roll the log
+/// over in passing) and [`SecretsCommand::Audit`] (so a forced rotation
+/// can use the same thresholds for everything after it).
+#[derive(Args)]
+pub struct AuditRotationArgs {
+    /// Roll the audit log over once its active file reaches this size,
+    /// in bytes. Unset disables size-based rotation.
+    #[arg(long)]
+    audit_max_bytes: Option<u64>,
+
+    /// Roll the audit log over once its active file is older than this
+    /// many seconds. Unset disables age-based rotation.
+    #[arg(long)]
+    audit_max_age_secs: Option<u64>,
+
+    /// How many rolled-over audit log files to retain.
+    #[arg(long, default_value_t = 5)]
+    audit_keep: usize,
+}
+
+impl AuditRotationArgs {
+    fn into_policy(self) -> RotationPolicy {
+        let mut policy = RotationPolicy::new(self.audit_keep);
+        if let Some(max_bytes) = self.audit_max_bytes {
+            policy = policy.with_max_bytes(max_bytes);
+        }
+        if let Some(max_age_secs) = self.audit_max_age_secs {
+            policy = policy.with_max_age(std::time::Duration::from_secs(max_age_secs));
+        }
+        policy
+    }
+}
+
+pub async fn run(args: SecretsArgs) -> anyhow::Result<()> {
+    match args.command {
+        SecretsCommand::Preview { literal, env, file, bundle, bundle_separator, output } => {
+            preview(&literal, &env, &file, &bundle, &bundle_separator, output)
+        }
+        SecretsCommand::Rotate { name, value, file, vault_addr, vault_path, vault_key, audit_log, audit_rotation, dry_run } => {
+            let target = match (file, vault_addr) {
+                (Some(path), None) => RotateTarget::File(path),
+                (None, Some(addr)) => {
+                    let vault_path = vault_path
+                        .ok_or_else(|| anyhow::anyhow!("--vault-path is required with --vault-addr"))?;
+                    RotateTarget::Vault { addr, path: vault_path, key: vault_key }
+                }
+                (None, None) => anyhow::bail!("pass either --file or --vault-addr"),
+                (Some(_), Some(_)) => anyhow::bail!("pass either --file or --vault-addr, not both"),
+            };
+            let audit = AuditLog::new(audit_log).with_rotation(audit_rotation.into_policy());
+            rotate(&name, &value, target, &audit, dry_run).await
+        }
+        SecretsCommand::Audit { log, audit_rotation, rotate } => {
+            let audit = AuditLog::new(log.clone()).with_rotation(audit_rotation.into_policy());
+            if rotate {
+                if audit.force_rotate()? {
+                    println!("rotated {}", log.display());
+                } else {
+                    println!("nothing to rotate: {} does not exist yet", log.display());
+                }
+            } else {
+                println!("audit log: {}", log.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Where [`rotate`] writes a new secret value.
+enum RotateTarget {
+    File(PathBuf),
+    Vault { addr: String, path: String, key: String },
+}
+
+async fn rotate(
+    name: &str,
+    value: &str,
+    target: RotateTarget,
+    audit: &AuditLog,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let record = match target {
+        RotateTarget::File(path) => {
+            let spec = SecretSpec { name: name.to_string(), sources: vec![SecretSource::File(path)] };
+            sindri_secrets::rotate_file(&spec, value, audit, dry_run)?
+        }
+        RotateTarget::Vault { addr, path, key } => {
+            let auth = VaultAuth::from_env()?;
+            let vault = VaultSource::new(addr, None, auth);
+            sindri_secrets::rotate_vault(&vault, &path, &key, value, audit, dry_run).await?
+        }
+    };
+
+    tracing::info!(
+        secret = %record.secret,
+        dry_run = record.dry_run,
+        verified = record.verified,
+        fingerprint = %record.new_value_fingerprint,
+        "rotation recorded"
+    );
+    Ok(())
+}
+
+fn preview(
+    literal: &[String],
+    env: &[String],
+    file: &[String],
+    bundle: &[String],
+    bundle_separator: &str,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let mut specs: Vec<SecretSpec> = Vec::new();
+
+    for entry in literal {
+        let (name, value) = entry
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("--literal {entry:?} is not in NAME=VALUE form"))?;
+        spec_for(&mut specs, name).sources.push(SecretSource::Literal(value.to_string()));
+    }
+
+    for entry in env {
+        let (name, var) = entry.split_once('=').unwrap_or((entry.as_str(), entry.as_str()));
+        spec_for(&mut specs, name).sources.push(SecretSource::Env(var.to_string()));
+    }
+
+    for entry in file {
+        let (name, path) = entry
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("--file {entry:?} is not in NAME=PATH form"))?;
+        spec_for(&mut specs, name).sources.push(SecretSource::File(PathBuf::from(path)));
+    }
+
+    for entry in bundle {
+        let (name, paths) = entry
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("--bundle {entry:?} is not in NAME=PATH1,PATH2,... form"))?;
+        let paths = paths.split(',').map(PathBuf::from).collect();
+        spec_for(&mut specs, name).sources.push(SecretSource::CompositeFile {
+            paths,
+            separator: bundle_separator.to_string(),
+        });
+    }
+
+    let resolver = SecretResolver::new();
+    let rows: Vec<PreviewRow> = sindri_secrets::preview_secrets(&resolver, &specs)?
+        .into_iter()
+        .map(|preview| PreviewRow {
+            name: preview.name,
+            source: match preview.resolved_from {
+                ResolvedFrom::Literal => "literal".to_string(),
+                ResolvedFrom::Env(var) => format!("env:{var}"),
+                ResolvedFrom::File(path) => format!("file:{}", path.display()),
+                ResolvedFrom::CompositeFile(paths) => format!(
+                    "bundle:{}",
+                    paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(",")
+                ),
+            },
+            masked: preview.masked,
+        })
+        .collect();
+    println!("{}", sindri_core::render_rows(&rows, output)?);
+    Ok(())
+}
+
+/// Finds or creates the in-order [`SecretSpec`] for `name` within `specs`.
+fn spec_for<'a>(specs: &'a mut Vec<SecretSpec>, name: &str) -> &'a mut SecretSpec {
+    if let Some(index) = specs.iter().position(|spec| spec.name == name) {
+        return &mut specs[index];
+    }
+    specs.push(SecretSpec::new(name));
+    specs.last_mut().expect("just pushed")
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct PreviewRow {
+    name: String,
+    source: String,
+    masked: String,
+}
+
+impl TableRow for PreviewRow {
+    fn headers() -> Vec<&'static str> {
+        vec!["NAME", "SOURCE", "MASKED"]
+    }
+
+    fn columns(&self) -> Vec<String> {
+        vec![self.name.clone(), self.source.clone(), self.masked.clone()]
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use sindri_core::PathResolver;
+
+#[derive(Args)]
+pub struct SelfArgs {
+    #[command(subcommand)]
+    pub command: SelfCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SelfCommand {
+    /// Dump a redacted report of the CLI's own environment (version,
+    /// platform, config-dir layout and sizes) suitable for pasting into a
+    /// bug report.
+    Doctor {
+        /// Print the report as JSON instead of the human-readable list.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// A redacted snapshot of the CLI's own environment, for bug reports. Only
+/// paths, sizes, and version/platform strings — never config contents,
+/// secrets, or extension data.
+///
+/// There's deliberately no "recent errors" field: `tracing` writes
+/// exclusively to stderr (see `telemetry::init`), and Sindri doesn't persist
+/// a log file anywhere under the config directory, so there's nothing on
+/// disk to read back.
+#[derive(Debug, Serialize)]
+pub struct SelfReport {
+    pub cli_version: String,
+    pub os: String,
+    pub arch: String,
+    pub tls_backend: String,
+    pub config_dir: DirReport,
+    pub cache_dir: DirReport,
+    pub extensions_dir: DirReport,
+    pub ledger_dir: DirReport,
+    pub state_dir: DirReport,
+    pub logs_dir: DirReport,
+    pub sessions_dir: DirReport,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DirReport {
+    pub path: String,
+    pub exists: bool,
+    pub bytes: u64,
+}
+
+pub fn run(args: SelfArgs, paths: &PathResolver) -> anyhow::Result<()> {
+    match args.command {
+        SelfCommand::Doctor { json } => doctor(paths, json),
+    }
+}
+
+fn doctor(paths: &PathResolver, json: bool) -> anyhow::Result<()> {
+    let report = build_report(paths)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("cli version:  {}", report.cli_version);
+    println!("platform:     {}/{}", report.os, report.arch);
+    println!("tls backend:  {}", report.tls_backend);
+    println!();
+    print_dir("config", &report.config_dir);
+    print_dir("cache", &report.cache_dir);
+    print_dir("extensions", &report.extensions_dir);
+    print_dir("ledger", &report.ledger_dir);
+    print_dir("state", &report.state_dir);
+    print_dir("logs", &report.logs_dir);
+    print_dir("sessions", &report.sessions_dir);
+    Ok(())
+}
+
+fn print_dir(label: &str, report: &DirReport) {
+    if report.exists {
+        println!("{label:<12} {} ({} bytes)", report.path, report.bytes);
+    } else {
+        println!("{label:<12} {} (missing)", report.path);
+    }
+}
+
+fn build_report(paths: &PathResolver) -> std::io::Result<SelfReport> {
+    Ok(SelfReport {
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        tls_backend: "rustls".to_string(),
+        config_dir: dir_report(paths.config_dir())?,
+        cache_dir: dir_report(&paths.cache_dir())?,
+        extensions_dir: dir_report(&paths.extensions_dir())?,
+        ledger_dir: dir_report(&paths.ledger_dir())?,
+        state_dir: dir_report(&paths.state_dir())?,
+        logs_dir: dir_report(&paths.logs_dir())?,
+        sessions_dir: dir_report(&paths.sessions_dir())?,
+    })
+}
+
+fn dir_report(path: &Path) -> std::io::Result<DirReport> {
+    let exists = path.exists();
+    let bytes = dir_size(path)?;
+    Ok(DirReport {
+        path: path.display().to_string(),
+        exists,
+        bytes,
+    })
+}
+
+/// Total size in bytes of everything under `path`. A missing path has
+/// nothing to report.
+fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(source) => return Err(source),
+    };
+
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+
+    let mut total = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        total += dir_size(&entry.path())?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_tmp_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("sindri-selfcheck-test-{label}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn a_missing_directory_reports_zero_bytes_and_exists_false() {
+        let missing = unique_tmp_dir("missing");
+        let report = dir_report(&missing).unwrap();
+        assert!(!report.exists);
+        assert_eq!(report.bytes, 0);
+    }
+
+    #[test]
+    fn dir_size_sums_nested_files() {
+        let tmp = unique_tmp_dir("nested");
+        let nested = tmp.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(tmp.join("a.txt"), b"hello").unwrap();
+        std::fs::write(nested.join("b.txt"), b"world!").unwrap();
+
+        assert_eq!(dir_size(&tmp).unwrap(), 5 + 6);
+        std::fs::remove_dir_all(&tmp).unwrap();
+    }
+
+    #[test]
+    fn build_report_fills_in_version_and_platform() {
+        let tmp = unique_tmp_dir("report");
+        let paths = PathResolver::resolve(Some(tmp.clone())).unwrap();
+        let report = build_report(&paths).unwrap();
+        assert_eq!(report.cli_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(report.os, std::env::consts::OS);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
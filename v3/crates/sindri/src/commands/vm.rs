@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, UNIX_EPOCH};
+
+use clap::{Args, Subcommand};
+use sindri_providers::CloudProvider;
+
+#[derive(Args)]
+pub struct VmArgs {
+    #[command(subcommand)]
+    pub command: VmCommand,
+}
+
+#[derive(Subcommand)]
+pub enum VmCommand {
+    /// Delete a Packer-built image (AMI, disk image, ...) to reclaim
+    /// storage, or every image past a retention cutoff in one pass.
+    Delete {
+        /// Id of the image to delete. Required unless `--older-than` is
+        /// given, which deletes every matching image instead.
+        id: Option<String>,
+
+        /// Delete every image created before this Unix timestamp (seconds)
+        /// instead of a single `id`.
+        #[arg(long)]
+        older_than: Option<u64>,
+
+        /// Cloud the image(s) belong to.
+        #[arg(long)]
+        provider: String,
+
+        /// Delete even if the image isn't tagged `ManagedBy=sindri`.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// SSH into an instance launched from a Packer-built image.
+    ///
+    /// No backend currently implements [`sindri_packer::PackerProvider::connect_target`]
+    /// — there's no deploy-from-image path yet, only image lifecycle
+    /// management — so this fails with a clear error until one does.
+    Connect {
+        /// Id of the running instance to connect to.
+        instance_id: String,
+
+        /// Cloud the instance was launched on.
+        #[arg(long)]
+        provider: String,
+    },
+
+    /// Render the HCL2 Packer template (and provisioning scripts) a cloud
+    /// backend would feed `packer build`, without building anything.
+    ///
+    /// No backend currently implements
+    /// [`sindri_packer::PackerProvider::render_template`] — no cloud has
+    /// an image backend wired up yet — so this fails with a clear error
+    /// until one does.
+    Render {
+        /// Cloud to render the template for.
+        #[arg(long)]
+        provider: String,
+
+        /// Directory to write the rendered template and scripts into.
+        #[arg(long, default_value = "./rendered")]
+        out: PathBuf,
+    },
+}
+
+pub async fn run(args: VmArgs) -> anyhow::Result<()> {
+    match args.command {
+        VmCommand::Delete { id, older_than, provider, force } => {
+            delete(id, older_than, &provider, force).await
+        }
+        VmCommand::Connect { instance_id, provider } => connect(&instance_id, &provider).await,
+        VmCommand::Render { provider, out } => render(&provider, &out).await,
+    }
+}
+
+async fn delete(
+    id: Option<String>,
+    older_than: Option<u64>,
+    provider: &str,
+    force: bool,
+) -> anyhow::Result<()> {
+    let cloud = parse_cloud(provider)?;
+    let backend = sindri_packer::provider_for(cloud)?;
+
+    let deleted = match (id, older_than) {
+        (Some(id), None) => vec![backend.delete_image(&id, force).await?],
+        (None, Some(older_than)) => {
+            let cutoff = UNIX_EPOCH + Duration::from_secs(older_than);
+            sindri_packer::delete_older_than(backend.as_ref(), cutoff, force).await?
+        }
+        (Some(_), Some(_)) => {
+            anyhow::bail!("pass either an image id or --older-than, not both")
+        }
+        (None, None) => anyhow::bail!("pass an image id or --older-than"),
+    };
+
+    if deleted.is_empty() {
+        tracing::info!("nothing to delete");
+        return Ok(());
+    }
+
+    let freed_bytes: u64 = deleted.iter().map(|image| image.freed_bytes).sum();
+    for image in &deleted {
+        tracing::info!(id = %image.id, freed_bytes = image.freed_bytes, "deleted image");
+    }
+    tracing::info!(count = deleted.len(), freed_bytes, "deleted image(s)");
+    Ok(())
+}
+
+async fn connect(instance_id: &str, provider: &str) -> anyhow::Result<()> {
+    let cloud = parse_cloud(provider)?;
+    let backend = sindri_packer::provider_for(cloud)?;
+    let target = backend.connect_target(instance_id).await?;
+
+    tracing::info!(host = %target.host, port = target.port, user = %target.user, "connecting");
+    let status = Command::new("ssh")
+        .arg("-p")
+        .arg(target.port.to_string())
+        .arg(format!("{}@{}", target.user, target.host))
+        .status()
+        .map_err(|err| anyhow::anyhow!("failed to run ssh: {err}"))?;
+
+    if !status.success() {
+        anyhow::bail!("ssh exited with {status}");
+    }
+    Ok(())
+}
+
+async fn render(provider: &str, out: &std::path::Path) -> anyhow::Result<()> {
+    let cloud = parse_cloud(provider)?;
+    let backend = sindri_packer::provider_for(cloud)?;
+
+    let template = backend.render_template().await?;
+    let scripts = backend.render_scripts().await?;
+
+    tokio::fs::create_dir_all(out).await?;
+    let template_path = out.join(format!("{}.pkr.hcl", template.template_name));
+    tokio::fs::write(&template_path, &template.hcl2).await?;
+    tracing::info!(
+        template = %template.template_name,
+        path = %template_path.display(),
+        "rendered template"
+    );
+
+    for script in &scripts {
+        let script_path = out.join(&script.name);
+        tokio::fs::write(&script_path, &script.contents).await?;
+        tracing::info!(path = %script_path.display(), "rendered provisioning script");
+    }
+    Ok(())
+}
+
+fn parse_cloud(provider: &str) -> anyhow::Result<CloudProvider> {
+    Ok(match provider {
+        "docker" => CloudProvider::Docker,
+        "fly" => CloudProvider::Fly,
+        "devpod" => CloudProvider::DevPod,
+        "k3d" => CloudProvider::K3d,
+        "northflank" => CloudProvider::Northflank,
+        "packer" => CloudProvider::Packer,
+        "runpod" => CloudProvider::Runpod,
+        "e2b" => CloudProvider::E2b,
+        other => anyhow::bail!("unknown cloud provider {other:?}"),
+    })
+}
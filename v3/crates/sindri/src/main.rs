@@ -0,0 +1,106 @@
+mod commands;
+mod telemetry;
+
+use std::time::Duration;
+
+use clap::Parser;
+use sindri_core::{PathResolver, ShutdownSignal};
+
+use commands::{Cli, Commands};
+
+/// Exit code for a command killed by `--timeout`, matching the
+/// conventional exit code of the `timeout(1)` shell command so CI scripts
+/// can recognize it the same way.
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// How long a timed-out command gets to finish its own cleanup (the same
+/// cleanup it would run on SIGINT/SIGTERM) before it's abandoned outright.
+const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let otlp_endpoint = cli
+        .otlp_endpoint
+        .clone()
+        .or_else(|| std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok());
+    let tracer_provider = telemetry::init(otlp_endpoint.as_deref());
+
+    if let Some(ca_bundle) = &cli.ca_bundle {
+        std::env::set_var(sindri_core::CA_BUNDLE_ENV, ca_bundle);
+    }
+    let paths = PathResolver::resolve(cli.config_dir.clone())?;
+    paths.ensure_writable()?;
+
+    let timeout_secs = cli.timeout_secs;
+    let shutdown = ShutdownSignal::install();
+    let task = tokio::spawn(run(cli, paths, shutdown.clone()));
+
+    let result = match timeout_secs {
+        None => task.await.map_err(|err| anyhow::anyhow!("command task panicked: {err}"))?,
+        Some(secs) => run_with_timeout(task, &shutdown, Duration::from_secs(secs)).await,
+    };
+
+    if let Some(provider) = tracer_provider {
+        if let Err(err) = provider.shutdown() {
+            tracing::warn!(%err, "failed to flush OTLP spans on exit");
+        }
+    }
+
+    result
+}
+
+/// Races `task` against `timeout`. On expiry, triggers `shutdown` — the
+/// same cancellation path a command already cooperates with for Ctrl-C —
+/// and gives it [`TIMEOUT_GRACE_PERIOD`] to wind down before abandoning it
+/// and exiting with [`TIMEOUT_EXIT_CODE`].
+async fn run_with_timeout(
+    mut task: tokio::task::JoinHandle<anyhow::Result<()>>,
+    shutdown: &ShutdownSignal,
+    timeout: Duration,
+) -> anyhow::Result<()> {
+    tokio::select! {
+        result = &mut task => {
+            return flatten_join_result(result);
+        }
+        _ = tokio::time::sleep(timeout) => {}
+    }
+
+    tracing::warn!(timeout_secs = timeout.as_secs(), "command timed out, triggering graceful shutdown");
+    shutdown.trigger();
+
+    match tokio::time::timeout(TIMEOUT_GRACE_PERIOD, task).await {
+        Ok(result) => flatten_join_result(result),
+        Err(_) => {
+            tracing::warn!("command did not wind down within the grace period, abandoning it");
+            std::process::exit(TIMEOUT_EXIT_CODE);
+        }
+    }
+}
+
+fn flatten_join_result(
+    result: Result<anyhow::Result<()>, tokio::task::JoinError>,
+) -> anyhow::Result<()> {
+    result.map_err(|err| anyhow::anyhow!("command task panicked: {err}"))?
+}
+
+async fn run(cli: Cli, paths: PathResolver, shutdown: ShutdownSignal) -> anyhow::Result<()> {
+    match cli.command {
+        Commands::Deploy(args) => commands::deploy::run(args, shutdown).await,
+        Commands::Connect(args) => commands::connect::run(args, &paths).await,
+        Commands::Destroy(args) => commands::destroy::run(args).await,
+        Commands::Extension(args) => commands::extension::run(args, &paths),
+        Commands::Project(args) => commands::project::run(args, &paths),
+        Commands::Restore(args) => commands::restore::run(args, &paths).await,
+        Commands::Backup(args) => commands::backup::run(args).await,
+        Commands::Completions(args) => commands::completions::run(args),
+        Commands::Doctor(args) => commands::doctor::run(args, &paths).await,
+        Commands::Vm(args) => commands::vm::run(args).await,
+        Commands::Config(args) => commands::config::run(args).await,
+        Commands::Secrets(args) => commands::secrets::run(args).await,
+        Commands::Image(args) => commands::image::run(args).await,
+        Commands::K8s(args) => commands::k8s::run(args).await,
+        Commands::Profile(args) => commands::profile::run(args, &paths),
+        Commands::SelfDiagnose(args) => commands::selfcheck::run(args, &paths),
+    }
+}
@@ -0,0 +1,48 @@
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+/// Installs the fmt logging layer, plus an OTLP/HTTP span exporter layer
+/// when `otlp_endpoint` is set, so spans for deploy stages, provider
+/// calls, and retries can be followed across crates in a tracing backend
+/// instead of read back out of logs.
+///
+/// Returns the [`SdkTracerProvider`] so the caller can flush it on exit —
+/// dropping it without flushing can lose whatever spans hadn't been
+/// batched out yet.
+pub fn init(otlp_endpoint: Option<&str>) -> Option<SdkTracerProvider> {
+    let fmt_layer = tracing_subscriber::fmt::layer().with_writer(std::io::stderr);
+    let filter = EnvFilter::from_default_env();
+
+    let Some(endpoint) = otlp_endpoint else {
+        tracing_subscriber::registry().with(filter).with(fmt_layer).init();
+        return None;
+    };
+
+    let exporter = match opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(err) => {
+            tracing_subscriber::registry().with(filter).with(fmt_layer).init();
+            tracing::warn!(%err, endpoint, "failed to build OTLP exporter, tracing only to stderr");
+            return None;
+        }
+    };
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_attribute(KeyValue::new("service.name", "sindri")).build())
+        .build();
+    let otel_layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("sindri"));
+
+    tracing_subscriber::registry().with(filter).with(fmt_layer).with(otel_layer).init();
+    Some(provider)
+}